@@ -0,0 +1,202 @@
+//! Solving many puzzle files at once, spread over every CPU core, with reports for other tools to read
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::difficulty::{self, Difficulty};
+use crate::json::Json;
+use crate::Puzzle;
+
+/// What solving a puzzle file came to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchStatus {
+  Solved,
+  Unsolvable,
+  MultipleSolutions,
+  /// The file could not be read, parsed or failed validation, along with why
+  Error(String)
+}
+
+/// Shows the status the way it is written in the table and the reports, i.e. solved or multiple
+impl fmt::Display for BatchStatus {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      BatchStatus::Solved => write!(f, "solved"),
+      BatchStatus::Unsolvable => write!(f, "unsolved"),
+      BatchStatus::MultipleSolutions => write!(f, "multiple"),
+      BatchStatus::Error(_) => write!(f, "error")
+    }
+  }
+}
+
+/// The result of solving one puzzle file
+/// * width, height, runs and cells are the size of the grid and its number of Parents and Children, all 0 on an error
+/// * time is how long reading, setting up and solving the puzzle took, including checking that the solution is unique
+/// * difficulty is only worked out for a puzzle with exactly one solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchResult {
+  pub file: PathBuf,
+  pub width: usize,
+  pub height: usize,
+  pub runs: usize,
+  pub cells: usize,
+  pub status: BatchStatus,
+  pub time: Duration,
+  pub difficulty: Option<Difficulty>
+}
+
+/// Parameters:
+/// - file: The puzzle file being solved
+///
+/// Description:
+/// - Reads and solves the puzzle, telling a unique solution apart from none or several, and grades it if it is unique
+fn solve_file(file: &Path) -> BatchResult {
+  let start = Instant::now();
+
+  let puzzle = match Puzzle::load(file) {
+    Ok(puzzle) => puzzle,
+    Err(error) => {
+      let status = BatchStatus::Error(error.to_string());
+      return BatchResult { file: file.to_path_buf(), width: 0, height: 0, runs: 0, cells: 0, status, time: start.elapsed(), difficulty: None };
+    }
+  };
+
+  let solutions = puzzle.find_solutions(2);
+
+  let status = match solutions.len() {
+    0 => BatchStatus::Unsolvable,
+    1 => BatchStatus::Solved,
+    _ => BatchStatus::MultipleSolutions
+  };
+
+  let time = start.elapsed();
+
+  // the unique solution is already known, so rating it does not search for it again
+  let difficulty = if status == BatchStatus::Solved { difficulty::rate_with(&puzzle, &solutions[0]) } else { None };
+
+  BatchResult {
+    file: file.to_path_buf(),
+    width: puzzle.width,
+    height: puzzle.height,
+    runs: puzzle.parents.len(),
+    cells: puzzle.children.len(),
+    status,
+    time,
+    difficulty
+  }
+}
+
+/// Parameters:
+/// - files: The puzzle files being solved
+/// - threads: The number of files solved at the same time, at least 1 is used
+///
+/// Description:
+/// - Solves every file, each thread taking the next file nobody has started yet so a slow puzzle does not hold up the rest
+/// - Returns the results in the same order as the files
+pub fn solve_all(files: &[PathBuf], threads: usize) -> Vec<BatchResult> {
+  let next = AtomicUsize::new(0);
+  let threads = threads.clamp(1, files.len().max(1));
+
+  let mut results: Vec<(usize, BatchResult)> = thread::scope(|scope| {
+    let workers: Vec<_> = (0..threads).map(|_| scope.spawn(|| {
+      let mut solved = Vec::new();
+
+      loop {
+        let index = next.fetch_add(1, Ordering::Relaxed);
+
+        let Some(file) = files.get(index) else {
+          return solved;
+        };
+
+        solved.push((index, solve_file(file)));
+      }
+    })).collect();
+
+    workers.into_iter().flat_map(|worker| worker.join().expect("a solver thread panicked")).collect()
+  });
+
+  results.sort_by_key(|&(index, _)| index);
+  results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Returns the name of the file without its folder, which is how it is shown in the table and the reports
+pub fn file_name(file: &Path) -> String {
+  file.file_name().map_or(file.display().to_string(), |name| name.to_string_lossy().to_string())
+}
+
+/// Returns the time in milliseconds, rounded to a hundredth of a millisecond
+fn milliseconds(time: Duration) -> f64 {
+  (time.as_secs_f64() * 100_000.0).round() / 100.0
+}
+
+/// Writes the field of a CSV line, quoting it if it holds a comma, a quote or a line break
+fn csv_field(value: &str) -> String {
+  if value.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", value.replace('"', "\"\""))
+  } else {
+    value.to_string()
+  }
+}
+
+/// Parameters:
+/// - results: The results of solving the files
+///
+/// Description:
+/// - Writes the results as CSV with a header line, one line for each file in the same order as the results
+/// - The size, run and cell columns are left empty for a file that could not be read, whose error is in the last column
+pub fn write_csv(results: &[BatchResult]) -> String {
+  let mut csv = String::from("file,width,height,runs,cells,status,milliseconds,grade,score,error\n");
+
+  for result in results {
+    let counts = if let BatchStatus::Error(_) = result.status {
+      vec![String::new(); 4]
+    } else {
+      [result.width, result.height, result.runs, result.cells].iter().map(|count| count.to_string()).collect()
+    };
+
+    let (grade, score) = result.difficulty.as_ref().map_or((String::new(), String::new()), |difficulty| (difficulty.grade.to_string(), difficulty.score.to_string()));
+    let error = if let BatchStatus::Error(error) = &result.status { error.as_str() } else { "" };
+
+    let fields = [vec![file_name(&result.file)], counts, vec![result.status.to_string(), milliseconds(result.time).to_string(), grade, score, error.to_string()]].concat();
+    let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+
+    csv.push_str(&fields.join(","));
+    csv.push('\n');
+  }
+
+  csv
+}
+
+/// Parameters:
+/// - results: The results of solving the files
+///
+/// Description:
+/// - Writes the results as a JSON object whose files key lists an object for each file, in the same order as the results,
+///   with null for the values a file does not have, i.e. the grade of an unsolvable puzzle
+pub fn write_json(results: &[BatchResult]) -> String {
+  let number = |value: f64| Json::Number(value);
+  let files = results.iter().map(|result| {
+    let error = matches!(result.status, BatchStatus::Error(_));
+    let count = |count: usize| if error { Json::Null } else { number(count as f64) };
+
+    Json::Object(vec![
+      ("file".to_string(), Json::String(file_name(&result.file))),
+      ("width".to_string(), count(result.width)),
+      ("height".to_string(), count(result.height)),
+      ("runs".to_string(), count(result.runs)),
+      ("cells".to_string(), count(result.cells)),
+      ("status".to_string(), Json::String(result.status.to_string())),
+      ("milliseconds".to_string(), number(milliseconds(result.time))),
+      ("grade".to_string(), result.difficulty.as_ref().map_or(Json::Null, |difficulty| Json::String(difficulty.grade.to_string()))),
+      ("score".to_string(), result.difficulty.as_ref().map_or(Json::Null, |difficulty| number(difficulty.score as f64))),
+      ("error".to_string(), if let BatchStatus::Error(error) = &result.status { Json::String(error.clone()) } else { Json::Null })
+    ])
+  }).collect();
+
+  let mut text = Json::Object(vec![("files".to_string(), Json::Array(files))]).to_pretty_string();
+  text.push('\n');
+  text
+}
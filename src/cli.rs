@@ -0,0 +1,661 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use kakuro::batch::{self, BatchStatus};
+use kakuro::generator::{self, GenerateOptions};
+use kakuro::solver::TraceStep;
+use kakuro::svg::SvgOptions;
+use kakuro::Puzzle;
+
+use crate::tui;
+
+/// The command finished successfully
+pub const EXIT_SUCCESS: u8 = 0;
+/// The arguments were not understood, the output could not be written or no puzzle could be generated
+pub const EXIT_ERROR: u8 = 1;
+/// The puzzle file could not be read, parsed or failed validation
+pub const EXIT_PARSE_ERROR: u8 = 2;
+/// The puzzle has no solution
+pub const EXIT_UNSOLVABLE: u8 = 3;
+/// The puzzle has more than one solution
+pub const EXIT_MULTIPLE_SOLUTIONS: u8 = 4;
+
+const USAGE: &str = "\
+usage: kakuro <command> <file> [options]
+       kakuro generate [options]
+
+commands:
+  solve      solve the puzzle and print the value of each white cell in reading order
+               --grid         draw the solved grid instead
+               --trace        print every step of the solver first, each guess and backtrack indented by how deep it is,
+                              with --grid the grid and its possible values are drawn after each step
+  show       draw the unsolved grid
+               --candidates   list the possible values of each white cell
+  validate   check that the puzzle file can be read and is a valid Kakuro
+  info       print the number of runs and cells, the run lengths and the combinations per run
+  count      count the solutions and show two of them along with where they differ
+               --limit <n>    stop counting after n solutions, at least 2, defaults to 2
+               --grid         draw the example solutions instead of listing their values
+  steps      solve the puzzle with human techniques, printing each step taken
+  hint       explain the easiest deduction to make first
+  svg        draw the puzzle as an SVG image
+               --cell-size <n>  the width and height of each cell in pixels, defaults to 40
+               --solution     fill in the solution
+               --candidates   pencil in the possible values of each empty white cell
+  play       solve the puzzle by hand in the terminal, with pencil marks, undo and redo, hints and revealing cells
+  export-html
+             print a page that plays the puzzle in a browser, with pencil marks and checking against the solution
+  rate       grade how hard the puzzle is, given a folder every puzzle in it is graded, defaults to puzzles
+  solve-all  solve every puzzle in the folder, several at once, and print a table of the results, defaults to puzzles
+               --threads <n>  the number of puzzles solved at once, defaults to the number of CPU cores
+               --csv <file>   also write the results to the file as CSV
+               --json <file>  also write the results to the file as JSON
+  generate   print a new puzzle with exactly one solution, no file is given
+               --width <n>    the width of the grid including the clue column, defaults to 10
+               --height <n>   the height of the grid including the clue row, defaults to 10
+               --seed <n>     the seed of the random choices, defaults to the current time
+               --symmetric    keep the black cells the same when the grid is turned half way around";
+
+/// Every command along with the options it accepts
+const COMMANDS: [(&str, &[&str]); 13] = [
+  ("solve", &["--grid", "--trace"]),
+  ("show", &["--candidates"]),
+  ("validate", &[]),
+  ("info", &[]),
+  ("count", &["--limit", "--grid"]),
+  ("steps", &[]),
+  ("hint", &[]),
+  ("svg", &["--cell-size", "--solution", "--candidates"]),
+  ("play", &[]),
+  ("export-html", &[]),
+  ("rate", &[]),
+  ("solve-all", &["--threads", "--csv", "--json"]),
+  ("generate", &["--width", "--height", "--seed", "--symmetric"])
+];
+
+/// The folder rate and solve-all read when they are not given a file or folder
+const DEFAULT_PUZZLE_FOLDER: &str = "puzzles";
+
+/// The options that are followed by a value
+const VALUE_OPTIONS: [&str; 8] = ["--limit", "--width", "--height", "--seed", "--cell-size", "--threads", "--csv", "--json"];
+
+/// The width and height of a generated grid when they are not given
+const DEFAULT_GENERATE_SIZE: usize = 10;
+
+/// The most differences between two example solutions that are listed
+const MAX_DIFFERENCES: usize = 5;
+
+/// The options given to a command
+/// * flags are the options given on their own, i.e. --grid
+/// * values are the options given along with a value, i.e. --limit 10
+#[derive(Default)]
+struct Options<'a> {
+  flags: Vec<&'a str>,
+  values: Vec<(&'a str, &'a str)>
+}
+
+impl<'a> Options<'a> {
+  /// Returns true if the flag was given
+  fn has(&self, flag: &str) -> bool {
+    self.flags.contains(&flag)
+  }
+
+  /// Returns the value given for the option, if it was given
+  fn value(&self, option: &str) -> Option<&'a str> {
+    self.values.iter().find(|(name, _)| *name == option).map(|(_, value)| *value)
+  }
+
+  /// Returns the value given for the option as a number, the default if it was not given
+  /// or an error message if the value is not a number
+  fn number<T: std::str::FromStr>(&self, option: &str, default: T) -> Result<T, String> {
+    match self.value(option) {
+      None => Ok(default),
+      Some(value) => value.parse().map_err(|_| format!("{} has to be a number", option))
+    }
+  }
+
+  /// Returns the name of every option given, in the order they were given
+  fn names(&self) -> impl Iterator<Item = &'a str> + '_ {
+    self.flags.iter().copied().chain(self.values.iter().map(|(name, _)| *name))
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+/// - draw_grid: If true the solved grid is drawn instead of listing the values
+/// - trace: If true every step of the solver is printed before the solution
+/// - out: Where the solution is written
+///
+/// Description:
+/// - Solves the puzzle and prints the values of the white cells, as long as the puzzle has exactly one solution
+fn solve(puzzle: &Puzzle, draw_grid: bool, trace: bool, out: &mut dyn Write) -> io::Result<u8> {
+  if trace {
+    write_trace(puzzle, draw_grid, out)?;
+  }
+
+  let solution = match unique_solution(puzzle) {
+    Ok(solution) => solution,
+    Err(code) => return Ok(code)
+  };
+
+  writeln!(out, "solved")?;
+  write_solution(&solution, draw_grid, out)?;
+
+  Ok(EXIT_SUCCESS)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+/// - draw_grid: If true the grid is drawn with its possible values after each step
+/// - out: Where the steps are written
+///
+/// Description:
+/// - Prints each step the solver takes to its first solution, numbered and indented by the number of guesses
+///   it is made under so the steps of a guess that is taken back are easy to pick out
+fn write_trace(puzzle: &Puzzle, draw_grid: bool, out: &mut dyn Write) -> io::Result<()> {
+  let mut written = Ok(());
+  let mut number = 0;
+  let mut depth = 0;
+
+  puzzle.clone().solve_traced(&mut |step, state| {
+    // a backtrack is shown level with the guess it takes back
+    if let TraceStep::Backtrack { .. } = step {
+      depth -= 1;
+    }
+
+    number += 1;
+
+    if written.is_ok() {
+      written = writeln!(out, "{}{}. {}", "  ".repeat(depth), number, step.describe(state));
+    }
+
+    if written.is_ok() && draw_grid {
+      written = write!(out, "{}", state.render(true));
+    }
+
+    if let TraceStep::Guess { .. } = step {
+      depth += 1;
+    }
+  });
+
+  written
+}
+
+/// Returns the solution of the puzzle, or the exit code if it does not have exactly one solution after reporting why
+fn unique_solution(puzzle: &Puzzle) -> Result<Puzzle, u8> {
+  // a single search up to two solutions both finds the solution and shows whether it is the only one
+  let mut count = puzzle.count_solutions(2);
+
+  match count.count {
+    0 => {
+      eprintln!("the puzzle has no solution");
+      Err(EXIT_UNSOLVABLE)
+    },
+    1 => Ok(count.examples.remove(0)),
+    _ => {
+      eprintln!("the puzzle has more than one solution");
+      Err(EXIT_MULTIPLE_SOLUTIONS)
+    }
+  }
+}
+
+/// Parameters:
+/// - solution: A solved puzzle
+/// - draw_grid: If true the solved grid is drawn instead of listing the values
+/// - out: Where the solution is written
+///
+/// Description:
+/// - Prints the value of each white cell in reading order, or the solved grid
+fn write_solution(solution: &Puzzle, draw_grid: bool, out: &mut dyn Write) -> io::Result<()> {
+  if draw_grid {
+    write!(out, "{}", solution.render(false))
+  } else {
+    let values: Vec<String> = solution.children.iter().map(|child| child.value.to_string()).collect();
+    writeln!(out, "{}", values.join(" "))
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being counted
+/// - limit: The number of solutions after which the counting stops
+/// - draw_grid: If true the example solutions are drawn instead of listing their values
+/// - out: Where the count is written
+///
+/// Description:
+/// - Prints the number of solutions as 0, 1 or 2 or more, followed by up to two example solutions
+///   and the first few white cells where the two examples differ
+fn count(puzzle: &Puzzle, limit: usize, draw_grid: bool, out: &mut dyn Write) -> io::Result<u8> {
+  let solutions = puzzle.count_solutions(limit);
+
+  writeln!(out, "solutions: {}", solutions)?;
+
+  for (index, example) in solutions.examples.iter().enumerate() {
+    writeln!(out, "solution {}:", index + 1)?;
+    write_solution(example, draw_grid, out)?;
+  }
+
+  if let [first, second, ..] = solutions.examples.as_slice() {
+    writeln!(out, "first differences:")?;
+
+    for id in solutions.differences().into_iter().take(MAX_DIFFERENCES) {
+      let (row, column) = first[id].position;
+      writeln!(out, "  row {}, column {}: {} or {}", row + 1, column + 1, first[id].value, second[id].value)?;
+    }
+  }
+
+  Ok(match solutions.count {
+    0 => EXIT_UNSOLVABLE,
+    1 => EXIT_SUCCESS,
+    _ => EXIT_MULTIPLE_SOLUTIONS
+  })
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+/// - out: Where the steps are written
+///
+/// Description:
+/// - Solves the puzzle with human techniques and prints each step, followed by whether the techniques solved it
+fn steps(puzzle: &Puzzle, out: &mut dyn Write) -> io::Result<u8> {
+  let mut solution = puzzle.clone();
+  let steps = solution.solve_with_techniques();
+
+  for (index, step) in steps.iter().enumerate() {
+    writeln!(out, "{}. {}", index + 1, step.describe(&solution))?;
+  }
+
+  if solution.is_solved() {
+    writeln!(out, "solved in {} steps", steps.len())?;
+  } else {
+    writeln!(out, "stuck after {} steps, no technique can make any more progress", steps.len())?;
+  }
+
+  Ok(EXIT_SUCCESS)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being drawn
+/// - options: The size of the cells and whether the candidates are drawn
+/// - show_solution: If true the puzzle is drawn with its solution filled in
+/// - out: Where the image is written
+///
+/// Description:
+/// - Prints the puzzle as an SVG image, as long as it has exactly one solution when the solution is asked for
+fn svg(puzzle: &Puzzle, options: &SvgOptions, show_solution: bool, out: &mut dyn Write) -> io::Result<u8> {
+  let puzzle = if show_solution {
+    match unique_solution(puzzle) {
+      Ok(solution) => solution,
+      Err(code) => return Ok(code)
+    }
+  } else {
+    puzzle.clone()
+  };
+
+  write!(out, "{}", puzzle.to_svg(options))?;
+
+  Ok(EXIT_SUCCESS)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being played
+/// - out: Where the screen is drawn
+///
+/// Description:
+/// - Lets the player solve the puzzle by hand in the terminal, see the keys listed under the grid
+fn play(puzzle: Puzzle, out: &mut dyn Write) -> io::Result<u8> {
+  tui::run(puzzle, out).map(|_| EXIT_SUCCESS)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being exported
+/// - out: Where the page is written
+///
+/// Description:
+/// - Prints the puzzle as an HTML page that can be played in a browser, as long as it has exactly one solution
+///   for the player's answers to be checked against
+fn export_html(puzzle: &Puzzle, out: &mut dyn Write) -> io::Result<u8> {
+  match unique_solution(puzzle) {
+    Ok(solution) => write!(out, "{}", puzzle.to_html(&solution)).map(|_| EXIT_SUCCESS),
+    Err(code) => Ok(code)
+  }
+}
+
+/// Returns every file in the folder in order of their names, or just the path if it is a file
+fn list_files(path: &Path) -> io::Result<Vec<PathBuf>> {
+  // a path that does not exist is an error here rather than a file that fails to load later
+  if !std::fs::metadata(path)?.is_dir() {
+    return Ok(vec![path.to_path_buf()]);
+  }
+
+  let mut files = Vec::new();
+
+  for entry in std::fs::read_dir(path)? {
+    let file = entry?.path();
+
+    if file.is_file() {
+      files.push(file);
+    }
+  }
+
+  files.sort();
+  Ok(files)
+}
+
+/// Returns the puzzle files at the path, see [`list_files`], or the exit code if they could not be listed after reporting why
+fn puzzle_files(path: &Path) -> Result<Vec<PathBuf>, u8> {
+  list_files(path).map_err(|error| {
+    eprintln!("{}: {}", path.display(), error);
+    EXIT_PARSE_ERROR
+  })
+}
+
+/// Parameters:
+/// - path: A puzzle file, or a folder whose files are all graded in order of their names
+/// - out: Where the grades are written, errors are written to stderr
+///
+/// Description:
+/// - Prints the grade of each puzzle along with its score, the number of steps, the hardest technique
+///   and the number of guesses, a puzzle that cannot be read is reported and skipped
+fn rate(path: &Path, out: &mut dyn Write) -> io::Result<u8> {
+  let files = match puzzle_files(path) {
+    Ok(files) => files,
+    Err(code) => return Ok(code)
+  };
+
+  let mut code = EXIT_SUCCESS;
+
+  for file in files {
+    let name = batch::file_name(&file);
+
+    let puzzle = match Puzzle::load(&file) {
+      Ok(puzzle) => puzzle,
+      Err(error) => {
+        eprintln!("{}: {}", file.display(), error);
+        code = EXIT_PARSE_ERROR;
+        continue;
+      }
+    };
+
+    let Some(difficulty) = puzzle.rate() else {
+      writeln!(out, "{}: no solution", name)?;
+      continue;
+    };
+
+    let hardest = difficulty.hardest.map_or("none".to_string(), |technique| technique.to_string());
+    writeln!(out, "{}: {} (score {}, {} steps, hardest technique {}, {} guesses)", name, difficulty.grade, difficulty.score, difficulty.steps, hardest, difficulty.guesses)?;
+  }
+
+  Ok(code)
+}
+
+/// Parameters:
+/// - path: A folder whose puzzle files are all solved, or a single puzzle file
+/// - threads: The number of puzzles solved at once
+/// - options: Where the CSV and JSON reports are written, if they were asked for
+/// - out: Where the table is written, errors are written to stderr
+///
+/// Description:
+/// - Solves every puzzle and prints a table of its size, number of runs and cells, whether it has one solution,
+///   how long it took and how hard it is, followed by a count of each status and the total time
+fn solve_all(path: &Path, threads: usize, options: &Options, out: &mut dyn Write) -> io::Result<u8> {
+  let files = match puzzle_files(path) {
+    Ok(files) => files,
+    Err(code) => return Ok(code)
+  };
+
+  let start = Instant::now();
+  let results = batch::solve_all(&files, threads);
+  let elapsed = start.elapsed();
+
+  let mut rows = vec![["file", "size", "runs", "cells", "status", "time", "difficulty"].map(String::from)];
+  let mut code = EXIT_SUCCESS;
+
+  for result in &results {
+    let error = if let BatchStatus::Error(error) = &result.status { Some(error) } else { None };
+    let count = |count: usize| if error.is_some() { "-".to_string() } else { count.to_string() };
+
+    if let Some(error) = error {
+      eprintln!("{}: {}", result.file.display(), error);
+      code = EXIT_PARSE_ERROR;
+    }
+
+    rows.push([
+      batch::file_name(&result.file),
+      if error.is_some() { "-".to_string() } else { format!("{}x{}", result.width, result.height) },
+      count(result.runs),
+      count(result.cells),
+      result.status.to_string(),
+      format!("{:.1} ms", result.time.as_secs_f64() * 1000.0),
+      result.difficulty.as_ref().map_or("-".to_string(), |difficulty| format!("{} ({})", difficulty.grade, difficulty.score))
+    ]);
+  }
+
+  let widths: Vec<usize> = (0..rows[0].len()).map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0)).collect();
+
+  for row in &rows {
+    let cells: Vec<String> = row.iter().zip(&widths).map(|(cell, &width)| format!("{:width$}", cell, width = width)).collect();
+    writeln!(out, "{}", cells.join("  ").trim_end())?;
+  }
+
+  let count = |wanted: fn(&BatchStatus) -> bool| results.iter().filter(|result| wanted(&result.status)).count();
+  let solved = count(|status| *status == BatchStatus::Solved);
+  let unsolved = count(|status| *status == BatchStatus::Unsolvable);
+  let multiple = count(|status| *status == BatchStatus::MultipleSolutions);
+  let errors = count(|status| matches!(status, BatchStatus::Error(_)));
+
+  writeln!(out, "{} files: {} solved, {} unsolved, {} multiple, {} errors in {:.1} ms", results.len(), solved, unsolved, multiple, errors, elapsed.as_secs_f64() * 1000.0)?;
+
+  if let Some(csv_file) = options.value("--csv") {
+    std::fs::write(csv_file, batch::write_csv(&results))?;
+  }
+
+  if let Some(json_file) = options.value("--json") {
+    std::fs::write(json_file, batch::write_json(&results))?;
+  }
+
+  Ok(code)
+}
+
+/// Parameters:
+/// - options: The size of the grid, the seed and whether the layout is symmetric
+/// - out: Where the puzzle is written, errors are written to stderr
+///
+/// Description:
+/// - Prints a new puzzle in the same format as the puzzle files, so it can be saved and loaded again
+fn generate(options: &GenerateOptions, out: &mut dyn Write) -> io::Result<u8> {
+  match generator::generate(options) {
+    Ok(text) => write!(out, "{}", text).map(|_| EXIT_SUCCESS),
+    Err(error) => {
+      eprintln!("{}", error);
+      Ok(EXIT_ERROR)
+    }
+  }
+}
+
+/// Returns the settings given to generate, or an error message if one of the values is not a number
+/// - without a seed the current time is used, so every run gives a different puzzle
+fn generate_options(options: &Options) -> Result<GenerateOptions, String> {
+  let time_seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_nanos() as u64);
+
+  Ok(GenerateOptions {
+    width: options.number("--width", DEFAULT_GENERATE_SIZE)?,
+    height: options.number("--height", DEFAULT_GENERATE_SIZE)?,
+    seed: options.number("--seed", time_seed)?,
+    symmetric: options.has("--symmetric")
+  })
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being described
+/// - out: Where the information is written
+///
+/// Description:
+/// - Prints the size of the grid, the number of runs and cells, a histogram of the run lengths
+///   and the number of combinations of each run along with where its clue is
+fn info(puzzle: &Puzzle, out: &mut dyn Write) -> io::Result<u8> {
+  let mut lengths: BTreeMap<usize, usize> = BTreeMap::new();
+
+  for parent in &puzzle.parents {
+    *lengths.entry(parent.children.len()).or_default() += 1;
+  }
+
+  writeln!(out, "size: {}x{}", puzzle.width, puzzle.height)?;
+  writeln!(out, "runs: {}", puzzle.parents.len())?;
+  writeln!(out, "cells: {}", puzzle.children.len())?;
+  writeln!(out, "run lengths:")?;
+
+  for (length, count) in lengths {
+    writeln!(out, "  {}: {}", length, count)?;
+  }
+
+  writeln!(out, "combinations per run:")?;
+
+  for run_id in puzzle.run_ids() {
+    let parent = &puzzle[run_id];
+    let (row, column) = parent.head;
+    writeln!(out, "  run {}: {} {} in {} cells at row {}, column {}, {} combinations", run_id.0, parent.direction, parent.sum, parent.children.len(), row + 1, column + 1, parent.combinations.len())?;
+  }
+
+  Ok(EXIT_SUCCESS)
+}
+
+/// Parameters:
+/// - args: The command line arguments, not including the name of the program
+/// - out: Where the output of the command is written, errors are written to stderr
+///
+/// Description:
+/// - Runs one of the commands on a puzzle file and returns the exit code
+pub fn run(args: &[String], out: &mut dyn Write) -> u8 {
+  let Some((command, rest)) = args.split_first() else {
+    eprintln!("{}", USAGE);
+    return EXIT_ERROR;
+  };
+
+  // split the remaining arguments into the options and the puzzle file, which has to be given exactly once
+  let mut options = Options::default();
+  let mut files = Vec::new();
+  let mut rest = rest.iter().map(|arg| arg.as_str());
+
+  while let Some(arg) = rest.next() {
+    if !arg.starts_with("--") {
+      files.push(arg);
+    } else if !VALUE_OPTIONS.contains(&arg) {
+      options.flags.push(arg);
+    } else if let Some(value) = rest.next() {
+      options.values.push((arg, value));
+    } else {
+      eprintln!("missing value for {}\n\n{}", arg, USAGE);
+      return EXIT_ERROR;
+    }
+  }
+
+  if (command == "rate" || command == "solve-all") && files.is_empty() {
+    files.push(DEFAULT_PUZZLE_FOLDER);
+  }
+
+  let Some(allowed) = COMMANDS.iter().find(|(name, _)| name == command).map(|(_, allowed)| *allowed) else {
+    eprintln!("{}", USAGE);
+    return EXIT_ERROR;
+  };
+
+  if let Some(option) = options.names().find(|option| !allowed.contains(option)) {
+    eprintln!("unknown option {} for {}\n\n{}", option, command, USAGE);
+    return EXIT_ERROR;
+  }
+
+  // generate is the only command that does not read a puzzle
+  if command == "generate" {
+    if !files.is_empty() {
+      eprintln!("{}", USAGE);
+      return EXIT_ERROR;
+    }
+
+    return match generate_options(&options) {
+      Ok(generate_options) => exit_code(generate(&generate_options, out)),
+      Err(message) => {
+        eprintln!("{}\n\n{}", message, USAGE);
+        EXIT_ERROR
+      }
+    };
+  }
+
+  let [puzzle_file] = files.as_slice() else {
+    eprintln!("{}", USAGE);
+    return EXIT_ERROR;
+  };
+
+  let limit = match options.number("--limit", 2) {
+    Ok(limit) => limit,
+    Err(message) => {
+      eprintln!("{}\n\n{}", message, USAGE);
+      return EXIT_ERROR;
+    }
+  };
+
+  let cell_size = options.number("--cell-size", SvgOptions::default().cell_size)
+    .and_then(|size| if size == 0 { Err("--cell-size has to be at least 1".to_string()) } else { Ok(size) });
+
+  let cell_size = match cell_size {
+    Ok(cell_size) => cell_size,
+    Err(message) => {
+      eprintln!("{}\n\n{}", message, USAGE);
+      return EXIT_ERROR;
+    }
+  };
+
+  let puzzle_file = Path::new(puzzle_file);
+
+  // rate and solve-all can be given a whole folder, so they read their own puzzles
+  if command == "rate" {
+    return exit_code(rate(puzzle_file, out));
+  }
+
+  if command == "solve-all" {
+    let cores = thread::available_parallelism().map_or(1, |cores| cores.get());
+
+    return match options.number("--threads", cores) {
+      Ok(threads) => exit_code(solve_all(puzzle_file, threads, &options, out)),
+      Err(message) => {
+        eprintln!("{}\n\n{}", message, USAGE);
+        EXIT_ERROR
+      }
+    };
+  }
+
+  let puzzle = match Puzzle::load(puzzle_file) {
+    Ok(puzzle) => puzzle,
+    Err(error) => {
+      eprintln!("{}: {}", puzzle_file.display(), error);
+      return EXIT_PARSE_ERROR;
+    }
+  };
+
+  let result = match command.as_str() {
+    "solve" => solve(&puzzle, options.has("--grid"), options.has("--trace"), out),
+    "show" => write!(out, "{}", puzzle.render(options.has("--candidates"))).map(|_| EXIT_SUCCESS),
+    "info" => info(&puzzle, out),
+    "count" => count(&puzzle, limit, options.has("--grid"), out),
+    "steps" => steps(&puzzle, out),
+    "play" => play(puzzle, out),
+    "export-html" => export_html(&puzzle, out),
+    "svg" => svg(&puzzle, &SvgOptions { cell_size, show_values: true, show_candidates: options.has("--candidates") }, options.has("--solution"), out),
+    "hint" => match puzzle.next_hint() {
+      Some(hint) => writeln!(out, "{}: {}", hint.technique, hint.explanation).map(|_| EXIT_SUCCESS),
+      None => writeln!(out, "no technique can make any progress").map(|_| EXIT_SUCCESS)
+    },
+    _ => writeln!(out, "valid").map(|_| EXIT_SUCCESS)
+  };
+
+  exit_code(result)
+}
+
+/// Returns the exit code of a command, reporting the error if its output could not be written
+fn exit_code(result: io::Result<u8>) -> u8 {
+  result.unwrap_or_else(|error| {
+    eprintln!("failed to write output: {}", error);
+    EXIT_ERROR
+  })
+}
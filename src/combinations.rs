@@ -0,0 +1,49 @@
+//! The table of every combination of unique digits that adds up to a sum
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::digits::DigitSet;
+
+/// Every (sum, length) pair mapped to the sorted sets of unique digits from 1 to 9 that add up to that sum
+type CombinationTable = HashMap<(u8, usize), Vec<DigitSet>>;
+
+static COMBINATIONS: OnceLock<CombinationTable> = OnceLock::new();
+
+/// Description:
+/// - Builds the table of every combination of at least two unique digits from 1 to 9, keyed by their sum and length
+/// - Each subset of the digits is represented by a 9 bit mask, where bit n set means the digit n + 1 is used
+fn build_table() -> CombinationTable {
+  let mut table: CombinationTable = HashMap::new();
+
+  for mask in 1u16..(1 << 9) {
+    let digits = DigitSet::from_bits(mask);
+
+    // a run always has at least two cells, so single digits are never needed
+    if digits.len() < 2 {
+      continue;
+    }
+
+    table.entry((digits.sum() as u8, digits.len())).or_default().push(digits);
+  }
+
+  // sort each list so the combinations come out in the same order as they would be written by hand
+  for combos in table.values_mut() {
+    combos.sort_unstable_by(|a, b| a.iter().cmp(b.iter()));
+  }
+
+  table
+}
+
+/// Parameters:
+/// - sum: The sum the digits need to add up to
+/// - length: The number of unique digits
+///
+/// Description:
+/// - Returns every combination of unique digits that adds up to the sum, the table is only built on first use
+/// - An empty slice is returned if there are no such combinations, i.e. a sum of 3 in 3 cells
+pub fn combinations(sum: u8, length: usize) -> &'static [DigitSet] {
+  COMBINATIONS.get_or_init(build_table)
+    .get(&(sum, length))
+    .map_or(&[], |combos| combos.as_slice())
+}
@@ -0,0 +1,134 @@
+//! Rating how hard a puzzle is for a person to solve
+
+use std::fmt;
+
+use crate::digits::DigitSet;
+use crate::techniques::{self, Step, Technique};
+use crate::Puzzle;
+
+/// The hardest technique needed adds its points to the score this many times over, far more than the steps of a puzzle
+/// of any usual size add, so a puzzle that needs a harder technique scores higher
+const HARDEST_MULTIPLIER: u32 = 1000;
+
+/// The points a guess adds to the score, a guess is worth more than any technique
+const GUESS_SCORE: u32 = 10_000;
+
+/// The hardest technique a puzzle graded easy may need
+const EASY_TECHNIQUE: Technique = Technique::DigitIntersection;
+
+/// The hardest technique a puzzle graded medium may need
+const MEDIUM_TECHNIQUE: Technique = Technique::RequiredDigit;
+
+/// How hard a puzzle is, from the easiest to the hardest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Grade {
+  Easy,
+  Medium,
+  Hard,
+  /// Only given to puzzles that cannot be solved without guessing
+  Expert
+}
+
+impl fmt::Display for Grade {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Grade::Easy => write!(f, "easy"),
+      Grade::Medium => write!(f, "medium"),
+      Grade::Hard => write!(f, "hard"),
+      Grade::Expert => write!(f, "expert")
+    }
+  }
+}
+
+/// The difficulty of a puzzle
+/// * score adds up the points of the hardest technique, every step and every guess, see [`grade_steps`]
+/// * hardest is the hardest technique needed, if any step was needed at all
+/// * steps are the number of technique steps taken and guesses the number of cells that had to be guessed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difficulty {
+  pub score: u32,
+  pub grade: Grade,
+  pub hardest: Option<Technique>,
+  pub steps: usize,
+  pub guesses: usize
+}
+
+/// Returns the points of the technique, from 1 for the easiest technique up to 7
+pub fn technique_score(technique: Technique) -> u32 {
+  match technique {
+    Technique::NakedSingle => 1,
+    Technique::PlacedDigit => 2,
+    Technique::UniqueCombination => 3,
+    Technique::DigitIntersection => 4,
+    Technique::HiddenSingle => 5,
+    Technique::RequiredDigit => 6,
+    Technique::CombinationElimination => 7
+  }
+}
+
+/// Parameters:
+/// - steps: Every step taken while solving
+/// - guesses: The number of cells that had to be guessed
+///
+/// Description:
+/// - Grades the puzzle by the hardest technique it needs, so a large puzzle is not graded harder than a small one
+///   that needs the same techniques, and any guessing makes a puzzle expert
+/// - Scores the puzzle as the points of the hardest technique times 1000, plus 1 for every step, plus 10000 for every guess,
+///   so the number of steps only orders puzzles that need the same techniques
+pub fn grade_steps(steps: &[Step], guesses: usize) -> Difficulty {
+  let hardest = steps.iter().map(|step| step.technique).max();
+  let score = hardest.map_or(0, technique_score) * HARDEST_MULTIPLIER + steps.len() as u32 + GUESS_SCORE * guesses as u32;
+
+  let grade = match hardest {
+    _ if guesses > 0 => Grade::Expert,
+    Some(technique) if technique > MEDIUM_TECHNIQUE => Grade::Hard,
+    Some(technique) if technique > EASY_TECHNIQUE => Grade::Medium,
+    _ => Grade::Easy
+  };
+
+  Difficulty { score, grade, hardest, steps: steps.len(), guesses }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being rated
+///      - This puzzle must have already been set up by Puzzle::load
+///
+/// Description:
+/// - Solves the puzzle with human techniques, and whenever they get stuck the empty Child with the fewest
+///   possible values is guessed using the solution found by the solver, then the techniques carry on
+/// - Returns None if the puzzle has no solution
+pub fn rate(puzzle: &Puzzle) -> Option<Difficulty> {
+  let solution = puzzle.find_solutions(1).pop()?;
+
+  rate_with(puzzle, &solution)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being rated
+///      - This puzzle must have already been set up by Puzzle::load
+/// - solution: The puzzle's solution, for callers that have already searched for it
+///
+/// Description:
+/// - Rates the puzzle the same way as [`rate`], guessing with the solution given instead of solving the puzzle again
+/// - Returns None if the techniques get stuck with no empty Child left to guess, i.e. the solution does not belong to the puzzle
+pub fn rate_with(puzzle: &Puzzle, solution: &Puzzle) -> Option<Difficulty> {
+  let mut current = puzzle.clone();
+  let mut steps = Vec::new();
+  let mut guesses = 0;
+
+  loop {
+    steps.extend(techniques::solve_with_techniques(&mut current));
+
+    if current.is_solved() {
+      return Some(grade_steps(&steps, guesses));
+    }
+
+    // guess the empty cell with the fewest possible values, the next step will then fill it in
+    let guess = current.cell_ids()
+      .filter(|&id| current[id].value == 0)
+      .min_by_key(|&id| current[id].possible_values.len())?;
+
+    current[guess].possible_values = DigitSet::single(solution[guess].value);
+    guesses += 1;
+  }
+}
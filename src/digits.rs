@@ -0,0 +1,208 @@
+//! A compact set of the digits 1 to 9
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+/// A set of the digits 1 to 9 stored as a 9 bit mask, where bit n set means the digit n + 1 is in the set
+/// * it is Copy and never allocates, so it can be used freely while solving
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DigitSet(u16);
+
+impl DigitSet {
+  /// The set with no digits in it
+  pub const EMPTY: DigitSet = DigitSet(0);
+
+  /// The set of every digit from 1 to 9
+  pub const ALL: DigitSet = DigitSet(0b1_1111_1111);
+
+  /// Returns the set holding only the given digit, which has to be from 1 to 9
+  pub const fn single(digit: u8) -> DigitSet {
+    debug_assert!(digit >= 1 && digit <= 9);
+    DigitSet(1 << (digit - 1))
+  }
+
+  /// Returns the set from a raw 9 bit mask, any bits past the ninth are dropped
+  pub const fn from_bits(bits: u16) -> DigitSet {
+    DigitSet(bits & DigitSet::ALL.0)
+  }
+
+  /// Returns the raw 9 bit mask of the set
+  pub const fn bits(self) -> u16 {
+    self.0
+  }
+
+  /// Returns true if the digit is in the set, 0 and digits past 9 are never in it
+  pub const fn contains(self, digit: u8) -> bool {
+    digit >= 1 && digit <= 9 && self.0 & (1 << (digit - 1)) != 0
+  }
+
+  /// Adds the digit to the set
+  pub fn insert(&mut self, digit: u8) {
+    *self = self.union(DigitSet::single(digit));
+  }
+
+  /// Takes the digit out of the set
+  pub fn remove(&mut self, digit: u8) {
+    *self = self.difference(DigitSet::single(digit));
+  }
+
+  /// Returns the digits that are in either set
+  pub const fn union(self, other: DigitSet) -> DigitSet {
+    DigitSet(self.0 | other.0)
+  }
+
+  /// Returns the digits that are in both sets
+  pub const fn intersection(self, other: DigitSet) -> DigitSet {
+    DigitSet(self.0 & other.0)
+  }
+
+  /// Returns the digits of this set that are not in the other one
+  pub const fn difference(self, other: DigitSet) -> DigitSet {
+    DigitSet(self.0 & !other.0)
+  }
+
+  /// Returns the number of digits in the set
+  pub const fn len(self) -> usize {
+    self.0.count_ones() as usize
+  }
+
+  /// Returns true if there are no digits in the set
+  pub const fn is_empty(self) -> bool {
+    self.0 == 0
+  }
+
+  /// Returns true if every digit of this set is also in the other one
+  pub const fn is_subset(self, other: DigitSet) -> bool {
+    self.0 & !other.0 == 0
+  }
+
+  /// Returns the smallest digit in the set
+  pub const fn min(self) -> Option<u8> {
+    if self.0 == 0 { None } else { Some(self.0.trailing_zeros() as u8 + 1) }
+  }
+
+  /// Returns the digit if it is the only one in the set
+  pub const fn single_value(self) -> Option<u8> {
+    if self.len() == 1 { self.min() } else { None }
+  }
+
+  /// Returns the total of every digit in the set
+  pub fn sum(self) -> u32 {
+    self.iter().map(u32::from).sum()
+  }
+
+  /// Returns the digits in the set from smallest to largest
+  pub fn iter(self) -> Digits {
+    Digits(self.0)
+  }
+}
+
+/// The digits of a DigitSet from smallest to largest, see [`DigitSet::iter`]
+#[derive(Debug, Clone)]
+pub struct Digits(u16);
+
+impl Iterator for Digits {
+  type Item = u8;
+
+  fn next(&mut self) -> Option<u8> {
+    if self.0 == 0 {
+      return None;
+    }
+
+    // take the lowest bit off the mask each time
+    let digit = self.0.trailing_zeros() as u8 + 1;
+    self.0 &= self.0 - 1;
+
+    Some(digit)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.0.count_ones() as usize;
+    (len, Some(len))
+  }
+}
+
+impl ExactSizeIterator for Digits {}
+
+impl IntoIterator for DigitSet {
+  type Item = u8;
+  type IntoIter = Digits;
+
+  fn into_iter(self) -> Digits {
+    self.iter()
+  }
+}
+
+impl FromIterator<u8> for DigitSet {
+  fn from_iter<I: IntoIterator<Item = u8>>(digits: I) -> DigitSet {
+    let mut set = DigitSet::EMPTY;
+
+    for digit in digits {
+      set.insert(digit);
+    }
+
+    set
+  }
+}
+
+impl From<&[u8]> for DigitSet {
+  fn from(digits: &[u8]) -> DigitSet {
+    digits.iter().copied().collect()
+  }
+}
+
+impl BitOr for DigitSet {
+  type Output = DigitSet;
+
+  fn bitor(self, other: DigitSet) -> DigitSet {
+    self.union(other)
+  }
+}
+
+impl BitOrAssign for DigitSet {
+  fn bitor_assign(&mut self, other: DigitSet) {
+    *self = self.union(other);
+  }
+}
+
+impl BitAnd for DigitSet {
+  type Output = DigitSet;
+
+  fn bitand(self, other: DigitSet) -> DigitSet {
+    self.intersection(other)
+  }
+}
+
+impl BitAndAssign for DigitSet {
+  fn bitand_assign(&mut self, other: DigitSet) {
+    *self = self.intersection(other);
+  }
+}
+
+impl Sub for DigitSet {
+  type Output = DigitSet;
+
+  fn sub(self, other: DigitSet) -> DigitSet {
+    self.difference(other)
+  }
+}
+
+impl SubAssign for DigitSet {
+  fn sub_assign(&mut self, other: DigitSet) {
+    *self = self.difference(other);
+  }
+}
+
+/// Shows the digits the same way a set is written by hand, i.e. {1,2,4}
+impl fmt::Display for DigitSet {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let digits: Vec<String> = self.iter().map(|digit| digit.to_string()).collect();
+    write!(f, "{{{}}}", digits.join(","))
+  }
+}
+
+impl fmt::Debug for DigitSet {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_set().entries(self.iter()).finish()
+  }
+}
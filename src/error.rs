@@ -0,0 +1,84 @@
+//! Errors returned while reading a puzzle file
+
+use std::fmt;
+use std::io;
+
+use crate::validation::ValidationError;
+
+/// The reason a line of a puzzle file could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseReason {
+  /// The board has a character other than -, \ or x
+  UnknownCellCharacter,
+  /// A \ cell on the board has no clue left on the line to go with it
+  MissingClue,
+  /// A clue is neither a number nor a -
+  NonNumericClue,
+  /// A clue is a number that is too large to be a sum
+  ClueOutOfRange,
+  /// The line has more clues than there are \ cells on its board
+  ClueCountMismatch { cells: usize, clues: usize },
+  /// The board is not the same length as the board on the first line
+  RaggedRow { expected: usize, found: usize },
+  /// A cell of the grid format is not ##, a clue, . or a digit
+  UnknownGridCell
+}
+
+/// An error that stops a puzzle file from being read
+#[derive(Debug)]
+pub enum ParseError {
+  /// The file could not be opened or read
+  Io(io::Error),
+  /// The contents of the file are not a valid puzzle
+  /// - line and column start at 1 and point at the start of the offending token
+  Invalid { line: usize, column: usize, token: String, reason: ParseReason },
+  /// The file was read but the puzzle in it breaks one or more rules of Kakuro
+  Validation(Vec<ValidationError>)
+}
+
+impl fmt::Display for ParseReason {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseReason::UnknownCellCharacter => write!(f, "unknown cell character, expected -, \\ or x"),
+      ParseReason::MissingClue => write!(f, "missing clue for \\ cell"),
+      ParseReason::NonNumericClue => write!(f, "clue is not a number or -"),
+      ParseReason::ClueOutOfRange => write!(f, "clue is too large"),
+      ParseReason::ClueCountMismatch { cells, clues } => write!(f, "{} clues given for {} \\ cells", clues, cells),
+      ParseReason::RaggedRow { expected, found } => write!(f, "row is {} cells long, expected {}", found, expected),
+      ParseReason::UnknownGridCell => write!(f, "unknown cell, expected ##, a clue such as 17\\11, . or a digit")
+    }
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseError::Io(error) => write!(f, "{}", error),
+      ParseError::Invalid { line, column, token, reason } => write!(f, "line {}, column {}: {} at '{}'", line, column, reason, token),
+      ParseError::Validation(errors) => {
+        write!(f, "puzzle is not valid:")?;
+
+        for error in errors {
+          write!(f, "\n  {}", error)?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
+
+impl std::error::Error for ParseError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      ParseError::Io(error) => Some(error),
+      ParseError::Invalid { .. } | ParseError::Validation(_) => None
+    }
+  }
+}
+
+impl From<io::Error> for ParseError {
+  fn from(error: io::Error) -> Self {
+    ParseError::Io(error)
+  }
+}
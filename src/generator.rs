@@ -0,0 +1,399 @@
+//! Generating new puzzles that have exactly one solution
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::digits::DigitSet;
+use crate::validation::ValidationError;
+use crate::{solver, ClueCell, Puzzle};
+
+/// The smallest width and height of a puzzle, the clue row and column plus a run of 2 cells
+pub const MIN_SIZE: usize = 3;
+
+/// The largest width and height of a puzzle, checking larger grids for a single solution takes the solver too long
+pub const MAX_SIZE: usize = 20;
+
+/// The longest run a generated layout has, longer runs have so many combinations that a single solution is rare
+const MAX_RUN_LENGTH: usize = 6;
+
+/// The chance out of 100 that a cell starts out white before the layout is tidied up
+const WHITE_CHANCE: u64 = 75;
+
+/// The number of layouts tried before giving up
+const MAX_ATTEMPTS: usize = 100;
+
+/// The number of times the cells where two solutions differ are filled in with new digits before a cell is turned black
+const MAX_FILLS: usize = 50;
+
+/// How much a digit's place in the order it is tried in can move away from the order its runs lean towards
+const FILL_NOISE: usize = 4;
+
+/// The most values the solver may guess while counting the solutions of a layout before the layout is given up on
+const MAX_GUESSES: usize = 2_000;
+
+/// The most times filling in a layout backtracks before the layout is thrown away
+const MAX_FILL_STEPS: usize = 100_000;
+
+/// The settings a puzzle is generated from
+/// * width and height are the size of the whole grid, including the clue row and column
+/// * seed decides every random choice, so the same options always give the same puzzle
+/// * symmetric keeps the black cells the same when the grid is turned half way around
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerateOptions {
+  pub width: usize,
+  pub height: usize,
+  pub seed: u64,
+  pub symmetric: bool
+}
+
+/// The reason a puzzle could not be generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateError {
+  /// The width or height is below MIN_SIZE or above MAX_SIZE
+  InvalidSize { width: usize, height: usize },
+  /// None of the layouts tried could be made to have exactly one solution
+  NoUniquePuzzle { attempts: usize }
+}
+
+impl fmt::Display for GenerateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      GenerateError::InvalidSize { width, height } => write!(f, "a {}x{} grid is not allowed, the width and height have to be between {} and {}", width, height, MIN_SIZE, MAX_SIZE),
+      GenerateError::NoUniquePuzzle { attempts } => write!(f, "no puzzle with exactly one solution was found after {} layouts", attempts)
+    }
+  }
+}
+
+impl std::error::Error for GenerateError {}
+
+/// A small xorshift random number generator, so a seed gives the same puzzle on every platform
+struct Random {
+  state: u64
+}
+
+impl Random {
+  /// Creates the generator, the seed is mixed with splitmix64 so seeds that are close together still differ
+  fn new(seed: u64) -> Random {
+    let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    state ^= state >> 31;
+
+    // xorshift gets stuck on 0
+    Random { state: if state == 0 { 1 } else { state } }
+  }
+
+  /// Returns the next random number
+  fn next(&mut self) -> u64 {
+    self.state ^= self.state << 13;
+    self.state ^= self.state >> 7;
+    self.state ^= self.state << 17;
+    self.state
+  }
+
+  /// Returns a random number from 0 up to but not including n
+  fn below(&mut self, n: usize) -> usize {
+    (self.next() % n as u64) as usize
+  }
+}
+
+/// The black and white cells of a grid being generated, along with the digit of each white cell once filled in
+/// * the first row and column are always black so every run has a cell for its clue
+/// * values are 0 for black cells and for white cells that are not filled in yet
+struct Layout {
+  width: usize,
+  height: usize,
+  symmetric: bool,
+  white: Vec<Vec<bool>>,
+  values: Vec<Vec<u8>>
+}
+
+impl Layout {
+  /// Creates a layout where each cell after the first row and column is white with a chance of WHITE_CHANCE
+  fn random(options: &GenerateOptions, random: &mut Random) -> Layout {
+    let mut layout = Layout {
+      width: options.width,
+      height: options.height,
+      symmetric: options.symmetric,
+      white: vec![vec![false; options.width]; options.height],
+      values: vec![vec![0; options.width]; options.height]
+    };
+
+    for row in 1..layout.height {
+      for column in 1..layout.width {
+        // the second cell of a symmetric pair copies the first
+        let (partner_row, partner_column) = layout.partner(row, column);
+
+        layout.white[row][column] = if layout.symmetric && (partner_row, partner_column) < (row, column) {
+          layout.white[partner_row][partner_column]
+        } else {
+          random.next() % 100 < WHITE_CHANCE
+        };
+      }
+    }
+
+    layout.fix_runs();
+    layout
+  }
+
+  /// Returns the cell that mirrors the cell when the grid after the first row and column is turned half way around
+  fn partner(&self, row: usize, column: usize) -> (usize, usize) {
+    (self.height - row, self.width - column)
+  }
+
+  /// Turns the cell black, along with its partner if the layout is symmetric
+  fn set_black(&mut self, row: usize, column: usize) {
+    let (partner_row, partner_column) = self.partner(row, column);
+
+    self.white[row][column] = false;
+    self.values[row][column] = 0;
+
+    if self.symmetric {
+      self.white[partner_row][partner_column] = false;
+      self.values[partner_row][partner_column] = 0;
+    }
+  }
+
+  /// Returns the white cells of the run going through the cell, in order
+  /// - across is true for the run along the row and false for the run down the column
+  fn run(&self, row: usize, column: usize, across: bool) -> Vec<(usize, usize)> {
+    let step = |(r, c): (usize, usize), forward: bool| match (across, forward) {
+      (true, true) => (r, c + 1),
+      (true, false) => (r, c - 1),
+      (false, true) => (r + 1, c),
+      (false, false) => (r - 1, c)
+    };
+
+    // the first row and column are always black so walking backwards cannot go off the grid
+    let mut start = (row, column);
+
+    while { let (r, c) = step(start, false); self.white[r][c] } {
+      start = step(start, false);
+    }
+
+    let mut cells = vec![start];
+    let mut next = step(start, true);
+
+    while next.0 < self.height && next.1 < self.width && self.white[next.0][next.1] {
+      cells.push(next);
+      next = step(next, true);
+    }
+
+    cells
+  }
+
+  /// Turns cells black until every run is between 2 and MAX_RUN_LENGTH cells long, so every white cell is in two runs
+  /// - a white cell on its own is turned black and a run that is too long is split in the middle
+  /// - values already filled in stay valid, as runs only ever get shorter
+  fn fix_runs(&mut self) {
+    let mut changed = true;
+
+    while changed {
+      changed = false;
+
+      for row in 1..self.height {
+        for column in 1..self.width {
+          for across in [true, false] {
+            if !self.white[row][column] {
+              continue;
+            }
+
+            let run = self.run(row, column, across);
+
+            if run.len() == 1 {
+              self.set_black(row, column);
+              changed = true;
+            } else if run.len() > MAX_RUN_LENGTH {
+              let (middle_row, middle_column) = run[run.len() / 2];
+              self.set_black(middle_row, middle_column);
+              changed = true;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  /// Returns every white cell in reading order
+  fn white_cells(&self) -> Vec<(usize, usize)> {
+    (0..self.height)
+      .flat_map(|row| (0..self.width).map(move |column| (row, column)))
+      .filter(|&(row, column)| self.white[row][column])
+      .collect()
+  }
+
+  /// Returns the digits already used by the other cells of both runs going through the cell
+  fn used_digits(&self, row: usize, column: usize) -> DigitSet {
+    let mut used = DigitSet::EMPTY;
+
+    for across in [true, false] {
+      for (r, c) in self.run(row, column, across) {
+        if (r, c) != (row, column) && self.values[r][c] != 0 {
+          used.insert(self.values[r][c]);
+        }
+      }
+    }
+
+    used
+  }
+
+  /// Parameters:
+  /// - cells: The white cells to fill in, any other white cells keep their digits
+  /// - random: Decides the order the digits are tried in
+  ///
+  /// Description:
+  /// - Fills in the cells with random digits so that no run repeats a digit, backtracking when a cell has no digit left
+  /// - Each run is randomly made to lean towards small or large digits, as a run of the smallest or largest digits
+  ///   has a clue with only one combination, which makes a single solution far more likely
+  /// - Returns false if the cells could not be filled in within MAX_FILL_STEPS
+  fn fill(&mut self, cells: &[(usize, usize)], random: &mut Random) -> bool {
+    let mut leans = HashMap::new();
+    let mut steps = 0;
+
+    for &(row, column) in cells {
+      self.values[row][column] = 0;
+
+      for across in [true, false] {
+        leans.entry((self.run(row, column, across)[0], across)).or_insert_with(|| random.below(2) == 0);
+      }
+    }
+
+    self.fill_from(cells, &leans, random, &mut steps)
+  }
+
+  /// Fills in the first cell of the slice and then the rest, undoing the cell if the rest cannot be filled in
+  /// - leans holds whether each run leans towards small digits, keyed by the first cell of the run and its direction
+  fn fill_from(&mut self, cells: &[(usize, usize)], leans: &HashMap<((usize, usize), bool), bool>, random: &mut Random, steps: &mut usize) -> bool {
+    let Some((&(row, column), rest)) = cells.split_first() else {
+      return true;
+    };
+
+    // digits that suit both runs are tried first, with a little noise so the order still varies
+    let mut digits: Vec<(u8, usize)> = (DigitSet::ALL - self.used_digits(row, column)).iter().map(|digit| {
+      let score = [true, false].iter().map(|&across| {
+        let small = leans[&(self.run(row, column, across)[0], across)];
+        if small { digit as usize } else { 10 - digit as usize }
+      }).sum::<usize>();
+
+      (digit, score + random.below(FILL_NOISE))
+    }).collect();
+
+    digits.sort_by_key(|&(_, score)| score);
+
+    for (digit, _) in digits {
+      *steps += 1;
+
+      if *steps > MAX_FILL_STEPS {
+        break;
+      }
+
+      self.values[row][column] = digit;
+
+      if self.fill_from(rest, leans, random, steps) {
+        return true;
+      }
+    }
+
+    self.values[row][column] = 0;
+    false
+  }
+
+  /// Returns the clue of the run that starts after the cell, or None if the next cell is black or off the grid
+  fn clue(&self, row: usize, column: usize, across: bool) -> Option<u32> {
+    let (next_row, next_column) = if across { (row, column + 1) } else { (row + 1, column) };
+
+    if next_row >= self.height || next_column >= self.width || !self.white[next_row][next_column] {
+      return None;
+    }
+
+    Some(self.run(next_row, next_column, across).iter().map(|&(r, c)| self.values[r][c] as u32).sum())
+  }
+
+  /// Returns the filled in layout as a puzzle set up the same way as [`Puzzle::load`], with the clues worked out from the values
+  /// - Returns every rule of Kakuro the layout breaks if it does not make a valid puzzle
+  fn to_puzzle(&self) -> Result<Puzzle, Vec<ValidationError>> {
+    let mut clues: Vec<ClueCell> = Vec::new();
+
+    for row in 0..self.height {
+      for column in 0..self.width {
+        if self.white[row][column] {
+          continue;
+        }
+
+        // a sum is at most 45, the digits 1 to 9 added together
+        let (down, across) = (self.clue(row, column, false), self.clue(row, column, true));
+
+        if down.is_some() || across.is_some() {
+          clues.push(((row, column), down.map(|sum| sum as u8), across.map(|sum| sum as u8)));
+        }
+      }
+    }
+
+    Puzzle::from_layout(self.width, self.height, &clues, &self.white_cells())
+  }
+}
+
+/// Parameters:
+/// - options: The size of the grid, the seed and whether the layout is symmetric
+///
+/// Description:
+/// - Creates a random layout where every white cell is in two runs of at least 2 cells, fills it in with random digits
+///   and works out the clues
+/// - Until the solver finds exactly one solution, the cells where two solutions differ are filled in again,
+///   and if that keeps failing one of them is turned black, starting over with a new layout if every white cell ends up black
+/// - Returns the puzzle in the same format as the puzzle files, ready for [`Puzzle::parse`] or to be saved
+pub fn generate(options: &GenerateOptions) -> Result<String, GenerateError> {
+  let valid_size = |size: usize| (MIN_SIZE..=MAX_SIZE).contains(&size);
+
+  if !valid_size(options.width) || !valid_size(options.height) {
+    return Err(GenerateError::InvalidSize { width: options.width, height: options.height });
+  }
+
+  let mut random = Random::new(options.seed);
+
+  for _ in 0..MAX_ATTEMPTS {
+    let mut layout = Layout::random(options, &mut random);
+    let mut cells = layout.white_cells();
+
+    while !layout.white_cells().is_empty() {
+      // different digits give different clues, so only the cells where two solutions differ are filled in
+      // again a few times before the layout itself is changed
+      let mut differences = Vec::new();
+
+      for _ in 0..MAX_FILLS {
+        if !layout.fill(&cells, &mut random) {
+          break;
+        }
+
+        // the layout always makes a valid puzzle, so an error only means this layout is given up on
+        let Ok(puzzle) = layout.to_puzzle() else {
+          break;
+        };
+
+        let Some(solutions) = solver::count_solutions_within(&puzzle, 2, MAX_GUESSES) else {
+          break;
+        };
+
+        if solutions.is_unique() {
+          return Ok(puzzle.to_text());
+        }
+
+        differences = solutions.differences().into_iter().map(|id| puzzle[id].position).collect();
+        cells = differences.clone();
+      }
+
+      // without two solutions to compare the layout could not be filled in or checked, so a new one is tried
+      if differences.is_empty() {
+        break;
+      }
+
+      let (row, column) = differences[random.below(differences.len())];
+
+      layout.set_black(row, column);
+      layout.fix_runs();
+      cells = layout.white_cells();
+    }
+  }
+
+  Err(GenerateError::NoUniquePuzzle { attempts: MAX_ATTEMPTS })
+}
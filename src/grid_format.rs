@@ -0,0 +1,161 @@
+//! The visual grid format, where each cell of the puzzle is written in its place on the grid
+//!
+//! ```text
+//! ##   4\   6\
+//! \3   .    .
+//! \7   .    3
+//! ```
+//! * the cells of a row are separated by spaces, any number of them so the columns can be lined up
+//! * `##` is a black cell, `.` is an empty white cell and a digit is a white cell that is already filled in
+//! * a clue cell is written as down\across, i.e. `17\11`, and a direction without a run is left out, i.e. `\16` or `17\`
+
+use crate::digits::DigitSet;
+use crate::error::{ParseError, ParseReason};
+use crate::{tokens, ClueCell, Puzzle};
+
+/// What a cell of the grid format is
+enum Cell {
+  Black,
+  Clue(Option<u8>, Option<u8>),
+  White(Option<u8>)
+}
+
+/// Parameters:
+/// - text: The contents of a puzzle file
+///
+/// Description:
+/// - Returns true if the first cell of the text is not a board of the original format, which only uses -, \ and x,
+///   so the text is read as the grid format
+pub(crate) fn is_grid_format(text: &str) -> bool {
+  let first = text.lines().find_map(|line| tokens(line).first().map(|&(_, token)| token));
+
+  first.is_some_and(|token| !token.chars().all(|c| matches!(c, '-' | '\\' | 'x')))
+}
+
+/// Parameters:
+/// - token: One cell of the grid
+/// - line: The line of the cell, starting at 1
+/// - column: Where the cell starts on the line, starting at 1
+///
+/// Description:
+/// - Works out what the cell is, returning an error that points at the cell or the part of a clue that is wrong
+fn read_cell(token: &str, line: usize, column: usize) -> Result<Cell, ParseError> {
+  if token.chars().all(|c| c == '#') {
+    return Ok(Cell::Black);
+  }
+
+  if token == "." {
+    return Ok(Cell::White(None));
+  }
+
+  if let Some(digit) = token.parse().ok().filter(|digit| (1..=9).contains(digit)) {
+    return Ok(Cell::White(Some(digit)));
+  }
+
+  let Some((down, across)) = token.split_once('\\') else {
+    return Err(ParseError::Invalid { line, column, token: token.to_string(), reason: ParseReason::UnknownGridCell });
+  };
+
+  let sum = |relation: &str, start: usize| -> Result<Option<u8>, ParseError> {
+    if relation.is_empty() || relation == "-" {
+      return Ok(None);
+    }
+
+    relation.parse().map(Some).map_err(|_| {
+      let reason = if relation.chars().all(|digit| digit.is_ascii_digit()) { ParseReason::ClueOutOfRange } else { ParseReason::NonNumericClue };
+      ParseError::Invalid { line, column: start, token: relation.to_string(), reason }
+    })
+  };
+
+  Ok(Cell::Clue(sum(down, column)?, sum(across, column + down.len() + 1)?))
+}
+
+/// Parameters:
+/// - text: The contents of a puzzle file in the grid format
+///
+/// Description:
+/// - Reads every cell of the grid and sets up the puzzle the same way as [`Puzzle::load`], a filled in cell
+///   is given its value and is left with that value as its only possible value
+/// - Returns an error if a cell is not valid, a row is not as long as the first row or the puzzle is not valid
+pub(crate) fn read_grid(text: &str) -> Result<Puzzle, ParseError> {
+  let mut clues: Vec<ClueCell> = Vec::new();
+  let mut cells = Vec::new();
+  let mut givens = Vec::new();
+  let mut width = None;
+  let mut height = 0;
+
+  for (line_index, line) in text.lines().enumerate() {
+    let row_cells = tokens(line);
+
+    // blank lines are skipped
+    let Some(&(row_start, _)) = row_cells.first() else {
+      continue;
+    };
+
+    for (column, &(start, token)) in row_cells.iter().enumerate() {
+      match read_cell(token, line_index + 1, start + 1)? {
+        Cell::Black => {},
+        Cell::Clue(down, across) => clues.push(((height, column), down, across)),
+        Cell::White(value) => {
+          if let Some(value) = value {
+            givens.push((cells.len(), value));
+          }
+
+          cells.push((height, column));
+        }
+      }
+    }
+
+    // every row has to be as long as the first one
+    let expected = *width.get_or_insert(row_cells.len());
+
+    if row_cells.len() != expected {
+      return Err(ParseError::Invalid { line: line_index + 1, column: row_start + 1, token: line.trim().to_string(), reason: ParseReason::RaggedRow { expected, found: row_cells.len() } });
+    }
+
+    height += 1;
+  }
+
+  let mut puzzle = Puzzle::from_layout(width.unwrap_or(0), height, &clues, &cells).map_err(ParseError::Validation)?;
+
+  // the white cells are listed in reading order, so their index is their id
+  for (index, value) in givens {
+    let child = &mut puzzle.children[index];
+    child.value = value;
+    child.possible_values = DigitSet::single(value);
+  }
+
+  Ok(puzzle)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being written
+///
+/// Description:
+/// - Writes the puzzle in the grid format with the columns lined up, a white cell that has a value is written as its digit
+pub(crate) fn write_grid(puzzle: &Puzzle) -> String {
+  let rows: Vec<Vec<String>> = puzzle.grid.iter().map(|row| row.iter().map(|cell| {
+    if let Some(id) = cell.child {
+      let value = puzzle[id].value;
+      return if value == 0 { ".".to_string() } else { value.to_string() };
+    }
+
+    if cell.is_blank() {
+      return "##".to_string();
+    }
+
+    let sum = |run: Option<crate::RunId>| run.map_or(String::new(), |id| puzzle[id].sum.to_string());
+    format!("{}\\{}", sum(cell.vert), sum(cell.horz))
+  }).collect()).collect();
+
+  let width = rows.iter().flatten().map(|cell| cell.len()).max().unwrap_or(0);
+  let mut text = String::new();
+
+  for row in rows {
+    let line: Vec<String> = row.iter().map(|cell| format!("{:width$}", cell, width = width)).collect();
+    text.push_str(line.join(" ").trim_end());
+    text.push('\n');
+  }
+
+  text
+}
@@ -0,0 +1,146 @@
+//! Hints for a player part way through a puzzle
+
+use crate::digits::DigitSet;
+use crate::techniques::{self, cell_name, run_name, Step, Technique};
+use crate::{CellId, Puzzle, RunId};
+
+/// What a hint tells the player to do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HintAction {
+  /// Fill in the cell with the digit
+  Place { cell: CellId, digit: u8 },
+  /// Cross the digits off the cell's candidates
+  RemoveCandidates { cell: CellId, digits: DigitSet },
+  /// Cross the combinations off the run
+  RemoveCombinations { run: RunId, combinations: Vec<DigitSet> }
+}
+
+/// The easiest deduction the player can make next
+/// * action is the one change the hint is about, even if the step behind it changes more
+/// * explanation is a single line naming the run's clue, i.e. row clue 16 over 2 cells at row 4, column 1 must be {7,9}
+/// * step is the full deduction the hint was made from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hint {
+  pub technique: Technique,
+  pub action: HintAction,
+  pub explanation: String,
+  pub step: Step
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being played, with the values the player has filled in
+///
+/// Description:
+/// - Returns a copy of the puzzle whose possible values agree with the filled in values, so a front-end only has to
+///   set Children::value, every filled in cell is left with its own value and that value is removed from its siblings
+fn with_values_applied(puzzle: &Puzzle) -> Puzzle {
+  let mut current = puzzle.clone();
+
+  for cell_id in puzzle.cell_ids() {
+    let value = puzzle[cell_id].value;
+
+    if value == 0 {
+      continue;
+    }
+
+    current[cell_id].possible_values &= DigitSet::single(value);
+
+    for &sibling in &puzzle[cell_id].siblings {
+      current[sibling].possible_values.remove(value);
+    }
+  }
+
+  current
+}
+
+/// Parameters:
+/// - step: A step that has already been applied to a copy of the puzzle, so the Children it fills in are known
+///
+/// Description:
+/// - Picks the one change of the step to show the player, filling in a cell of the step is shown over
+///   crossing off candidates, which is shown over crossing off combinations
+fn action(step: &Step) -> HintAction {
+  let own_cells = |id: &CellId| step.cells.contains(id) || step.cells.is_empty();
+
+  if let Some(&(cell, digit)) = step.placed.iter().find(|(id, _)| own_cells(id)).or(step.placed.first()) {
+    return HintAction::Place { cell, digit };
+  }
+
+  if let Some(&(cell, digits)) = step.removed_candidates.iter().find(|(id, _)| own_cells(id)).or(step.removed_candidates.first()) {
+    return HintAction::RemoveCandidates { cell, digits };
+  }
+
+  let run = step.runs[0];
+  let combinations = step.removed_combinations.iter().map(|&(_, combo)| combo).collect();
+
+  HintAction::RemoveCombinations { run, combinations }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle the hint is for
+/// - step: The step the hint is made from
+/// - action: The change the hint is about
+///
+/// Description:
+/// - Returns a single line explaining why the change can be made, naming the run's clue
+fn explanation(puzzle: &Puzzle, step: &Step, action: &HintAction) -> String {
+  let run = |index: usize| run_name(puzzle, step.runs[index]);
+
+  // a naked single is explained by the cell alone, which is also the cell it fills in
+  if let (Technique::NakedSingle, HintAction::Place { cell, digit }) = (step.technique, action) {
+    return format!("{} has {} as its only candidate, so it must be {}", cell_name(puzzle, *cell), step.digits, digit);
+  }
+
+  let reason = match step.technique {
+    Technique::NakedSingle => format!("{} has {} as its only candidate", cell_name(puzzle, step.cells[0]), step.digits),
+    Technique::PlacedDigit => format!("{} already has {}", run(0), step.digits),
+    Technique::UniqueCombination => format!("{} must be {}", run(0), step.digits),
+    Technique::DigitIntersection => format!("{} can only be {} where the {} crosses the {}", cell_name(puzzle, step.cells[0]), step.digits, run(0), run(1)),
+    Technique::HiddenSingle => format!("{} needs {} and {} is the only cell that can take it", run(0), step.digits, cell_name(puzzle, step.cells[0])),
+    Technique::RequiredDigit => format!("{} must contain {}", run(0), step.digits),
+    Technique::CombinationElimination => String::new()
+  };
+
+  let change = match action {
+    HintAction::Place { cell, digit } => format!("leaving {} for {}", digit, cell_name(puzzle, *cell)),
+    HintAction::RemoveCandidates { cell, digits } => format!("so {} cannot be {}", cell_name(puzzle, *cell), digits),
+    HintAction::RemoveCombinations { run, combinations } => {
+      let combinations: Vec<String> = combinations.iter().map(|combo| combo.to_string()).collect();
+
+      // combination elimination is explained by the combinations alone
+      if reason.is_empty() {
+        return format!("{} cannot be {}", run_name(puzzle, *run), combinations.join(" or "));
+      }
+
+      format!("so it cannot be {}", combinations.join(" or "))
+    }
+  };
+
+  format!("{}, {}", reason, change)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being played
+///      - This puzzle must have already been set up by Puzzle::load, the player's digits are read from Children::value
+///
+/// Description:
+/// - Finds the single easiest deduction available, trying the techniques from the easiest to the hardest
+/// - Returns None if the puzzle is already solved, no technique can make any progress
+///   or the filled in values break the rules so no deduction makes sense
+pub fn next_hint(puzzle: &Puzzle) -> Option<Hint> {
+  let current = with_values_applied(puzzle);
+
+  if current.is_solved() || current.children.iter().any(|child| child.possible_values.is_empty()) {
+    return None;
+  }
+
+  let mut step = techniques::find_step(&current)?;
+
+  // applying the step to a copy shows which cells it fills in
+  techniques::apply_step(&mut current.clone(), &mut step);
+
+  let action = action(&step);
+  let explanation = explanation(&current, &step, &action);
+
+  Some(Hint { technique: step.technique, action, explanation, step })
+}
@@ -0,0 +1,332 @@
+//! Exporting a puzzle as a single HTML page that can be played in a browser
+//!
+//! The page needs nothing besides itself: the style and the script are written into it, and the puzzle is
+//! embedded as JSON made from the runs (Parents) and white cells (Children) of the puzzle along with its solution.
+//! * clicking a cell selects it, the arrow keys move around the grid
+//! * 1 to 9 fill in the selected cell and Backspace, Delete or 0 empty it
+//! * P or the pencil button switches between filling in values and toggling pencil marks
+//! * checking marks every run that repeats a digit, goes over its sum or is full without adding up to it,
+//!   along with every filled in cell that does not match the solution
+
+use crate::json::Json;
+use crate::Puzzle;
+
+/// How the page looks, a clue cell is split by a white line from its top left to its bottom right corner
+/// with the down sum below it and the across sum above it
+const STYLE: &str = r##"
+  :root { --cell: 48px; }
+  body { font-family: sans-serif; margin: 2em; color: #222; }
+  #board { display: grid; width: max-content; border: 2px solid #000; user-select: none; }
+  #board > div { width: var(--cell); height: var(--cell); box-sizing: border-box; border: 1px solid #000; position: relative; }
+  .black { background: #222; }
+  .clue { background: linear-gradient(to top right, #222 calc(50% - 1px), #fff 50%, #222 calc(50% + 1px)); color: #fff; font-size: calc(var(--cell) * 0.3); }
+  .clue .down { position: absolute; left: 10%; bottom: 5%; }
+  .clue .across { position: absolute; right: 10%; top: 5%; }
+  .white { background: #fff; cursor: pointer; display: flex; align-items: center; justify-content: center; font-size: calc(var(--cell) * 0.6); color: #1a4fa0; }
+  .white.given { color: #222; font-weight: bold; cursor: default; }
+  .white.selected { background: #cde3ff; }
+  .white.conflict { background: #ffd9d9; }
+  .white.selected.conflict { background: #f5c0d8; }
+  .white.wrong { color: #c00; }
+  .marks { display: grid; grid-template-columns: repeat(3, 1fr); width: 100%; height: 100%; font-size: calc(var(--cell) * 0.22); color: #666; text-align: center; align-items: center; }
+  #controls { margin-top: 1em; display: flex; gap: 0.5em; align-items: center; }
+  #pencil.on { background: #cde3ff; }
+  #status { margin-top: 1em; min-height: 1.2em; }
+"##;
+
+/// Draws the grid from the embedded puzzle and handles the player's input and the checking of their answers
+const SCRIPT: &str = r##"
+"use strict";
+
+const puzzle = JSON.parse(document.getElementById("puzzle").textContent);
+const board = document.getElementById("board");
+const status = document.getElementById("status");
+const pencilButton = document.getElementById("pencil");
+
+const values = puzzle.cells.map(cell => cell.given);
+const marks = puzzle.cells.map(() => new Set());
+const elements = [];
+let selected = null;
+let pencil = false;
+
+// every position is black unless a clue or a white cell is there
+const layout = Array.from({ length: puzzle.height }, () => Array(puzzle.width).fill(null));
+puzzle.clues.forEach(clue => { layout[clue.row][clue.column] = { clue }; });
+puzzle.cells.forEach((cell, index) => { layout[cell.row][cell.column] = { index }; });
+
+function label(className, text) {
+  const element = document.createElement("span");
+  element.className = className;
+  element.textContent = text;
+  return element;
+}
+
+function draw(index) {
+  const element = elements[index];
+  element.replaceChildren();
+
+  if (values[index] !== 0) {
+    element.textContent = values[index];
+  } else if (marks[index].size > 0) {
+    const grid = document.createElement("div");
+    grid.className = "marks";
+
+    for (let digit = 1; digit <= 9; digit++) {
+      grid.append(label("mark", marks[index].has(digit) ? digit : ""));
+    }
+
+    element.append(grid);
+  }
+}
+
+function select(index) {
+  if (selected !== null) {
+    elements[selected].classList.remove("selected");
+  }
+
+  selected = index;
+  elements[index].classList.add("selected");
+}
+
+// moves to the next white cell in the direction, staying put at the edge of the grid
+function move(rowStep, columnStep) {
+  if (selected === null) {
+    return select(0);
+  }
+
+  let { row, column } = puzzle.cells[selected];
+
+  for (;;) {
+    row += rowStep;
+    column += columnStep;
+
+    if (row < 0 || column < 0 || row >= puzzle.height || column >= puzzle.width) {
+      return;
+    }
+
+    const spot = layout[row][column];
+
+    if (spot !== null && spot.index !== undefined) {
+      return select(spot.index);
+    }
+  }
+}
+
+function clearCheck() {
+  elements.forEach(element => element.classList.remove("conflict", "wrong"));
+  status.textContent = "";
+}
+
+function enter(digit) {
+  if (selected === null || puzzle.cells[selected].given !== 0) {
+    return;
+  }
+
+  if (pencil && digit !== 0) {
+    if (values[selected] === 0) {
+      marks[selected].has(digit) ? marks[selected].delete(digit) : marks[selected].add(digit);
+    }
+  } else {
+    values[selected] = values[selected] === digit ? 0 : digit;
+  }
+
+  clearCheck();
+  draw(selected);
+}
+
+function togglePencil() {
+  pencil = !pencil;
+  pencilButton.classList.toggle("on", pencil);
+  pencilButton.textContent = pencil ? "Pencil marks: on" : "Pencil marks: off";
+}
+
+function count(number, word) {
+  return number === 1 ? `${number} ${word}` : `${number} ${word}s`;
+}
+
+// the same rules as the solver, a run cannot repeat a digit or go over its sum and a full run has to add up to it
+function check() {
+  clearCheck();
+  let broken = 0;
+
+  puzzle.runs.forEach(run => {
+    const digits = run.cells.map(index => values[index]).filter(value => value !== 0);
+    const total = digits.reduce((sum, value) => sum + value, 0);
+    const repeats = new Set(digits).size !== digits.length;
+
+    if (repeats || total > run.sum || (digits.length === run.cells.length && total !== run.sum)) {
+      broken++;
+      run.cells.forEach(index => elements[index].classList.add("conflict"));
+    }
+  });
+
+  let wrong = 0;
+  let empty = 0;
+
+  values.forEach((value, index) => {
+    if (value === 0) {
+      empty++;
+    } else if (value !== puzzle.solution[index]) {
+      wrong++;
+      elements[index].classList.add("wrong");
+    }
+  });
+
+  if (wrong === 0 && empty === 0) {
+    status.textContent = "Solved, well done!";
+  } else if (wrong === 0 && broken === 0) {
+    status.textContent = `So far so good, ${count(empty, "cell")} left to fill in.`;
+  } else {
+    status.textContent = `${count(wrong, "cell")} not matching the solution and ${count(broken, "run")} breaking the rules.`;
+  }
+}
+
+function reset() {
+  puzzle.cells.forEach((cell, index) => {
+    values[index] = cell.given;
+    marks[index].clear();
+    draw(index);
+  });
+
+  clearCheck();
+}
+
+board.style.gridTemplateColumns = `repeat(${puzzle.width}, var(--cell))`;
+
+layout.forEach(row => row.forEach(spot => {
+  const element = document.createElement("div");
+
+  if (spot === null) {
+    element.className = "black";
+  } else if (spot.clue !== undefined) {
+    element.className = "clue";
+
+    if (spot.clue.down !== undefined) {
+      element.append(label("down", spot.clue.down));
+    }
+
+    if (spot.clue.across !== undefined) {
+      element.append(label("across", spot.clue.across));
+    }
+  } else {
+    element.className = puzzle.cells[spot.index].given !== 0 ? "white given" : "white";
+    element.addEventListener("click", () => select(spot.index));
+    elements[spot.index] = element;
+  }
+
+  board.append(element);
+}));
+
+puzzle.cells.forEach((_, index) => draw(index));
+
+document.addEventListener("keydown", event => {
+  const arrows = { ArrowUp: [-1, 0], ArrowDown: [1, 0], ArrowLeft: [0, -1], ArrowRight: [0, 1] };
+
+  if (event.key in arrows) {
+    move(...arrows[event.key]);
+  } else if (/^[0-9]$/.test(event.key)) {
+    enter(Number(event.key));
+  } else if (event.key === "Backspace" || event.key === "Delete") {
+    enter(0);
+  } else if (event.key === "p" || event.key === "P") {
+    togglePencil();
+  } else {
+    return;
+  }
+
+  event.preventDefault();
+});
+
+pencilButton.addEventListener("click", togglePencil);
+document.getElementById("check").addEventListener("click", check);
+document.getElementById("reset").addEventListener("click", reset);
+"##;
+
+/// Parameters:
+/// - puzzle: The puzzle being exported, a white cell that already has a value is given to the player
+/// - solution: The solved puzzle the player's answers are checked against
+///
+/// Description:
+/// - Writes the clues, the runs with their sums and cells, the white cells with their runs and the solution as JSON,
+///   the white cells and runs are listed in the same order as the Children and Parents of the puzzle
+fn puzzle_data(puzzle: &Puzzle, solution: &Puzzle) -> Json {
+  let number = |value: usize| Json::Number(value as f64);
+  let position = |(row, column): (usize, usize)| vec![("row".to_string(), number(row)), ("column".to_string(), number(column))];
+
+  let mut clues = Vec::new();
+
+  for (row, cells) in puzzle.grid.iter().enumerate() {
+    for (column, cell) in cells.iter().enumerate() {
+      if cell.child.is_some() || cell.is_blank() {
+        continue;
+      }
+
+      let mut clue = position((row, column));
+
+      for (key, run) in [("down", cell.vert), ("across", cell.horz)] {
+        if let Some(id) = run {
+          clue.push((key.to_string(), number(puzzle[id].sum as usize)));
+        }
+      }
+
+      clues.push(Json::Object(clue));
+    }
+  }
+
+  let runs = puzzle.parents.iter().map(|parent| Json::Object(vec![
+    ("sum".to_string(), number(parent.sum as usize)),
+    ("cells".to_string(), Json::Array(parent.children.iter().map(|id| number(id.0)).collect()))
+  ])).collect();
+
+  let cells = puzzle.children.iter().map(|child| {
+    let (down, across) = child.parents;
+    let mut cell = position(child.position);
+
+    cell.push(("runs".to_string(), Json::Array(vec![number(down.0), number(across.0)])));
+    cell.push(("given".to_string(), number(child.value as usize)));
+    Json::Object(cell)
+  }).collect();
+
+  Json::Object(vec![
+    ("width".to_string(), number(puzzle.width)),
+    ("height".to_string(), number(puzzle.height)),
+    ("clues".to_string(), Json::Array(clues)),
+    ("runs".to_string(), Json::Array(runs)),
+    ("cells".to_string(), Json::Array(cells)),
+    ("solution".to_string(), Json::Array(solution.children.iter().map(|child| number(child.value as usize)).collect()))
+  ])
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being exported, with any values it already has given to the player
+/// - solution: The solved puzzle the player's answers are checked against
+///
+/// Description:
+/// - Writes a whole HTML page with the style, the script and the puzzle data written into it,
+///   so the page can be opened in a browser without any other files
+pub fn write_html(puzzle: &Puzzle, solution: &Puzzle) -> String {
+  format!(
+    "<!DOCTYPE html>\n\
+     <html lang=\"en\">\n\
+     <head>\n\
+     <meta charset=\"utf-8\">\n\
+     <title>Kakuro</title>\n\
+     <style>{}</style>\n\
+     </head>\n\
+     <body>\n\
+     <h1>Kakuro</h1>\n\
+     <div id=\"board\"></div>\n\
+     <div id=\"controls\">\n\
+     <button id=\"pencil\">Pencil marks: off</button>\n\
+     <button id=\"check\">Check</button>\n\
+     <button id=\"reset\">Start over</button>\n\
+     </div>\n\
+     <div id=\"status\"></div>\n\
+     <p>Click a cell and type 1 to 9, Backspace empties it. The arrow keys move around and P switches pencil marks on and off.</p>\n\
+     <script type=\"application/json\" id=\"puzzle\">\n{}\n</script>\n\
+     <script>{}</script>\n\
+     </body>\n\
+     </html>\n",
+    STYLE, puzzle_data(puzzle, solution).to_pretty_string(), SCRIPT
+  )
+}
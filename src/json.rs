@@ -0,0 +1,693 @@
+//! Reading and writing puzzles, their solutions and a player's progress as JSON
+//!
+//! A puzzle is a single object, only width, height, clues and cells are required:
+//! ```text
+//! {
+//!   "width": 3,
+//!   "height": 3,
+//!   "clues": [
+//!     {"row": 0, "column": 1, "down": 4},
+//!     {"row": 0, "column": 2, "down": 6},
+//!     {"row": 1, "column": 0, "across": 3},
+//!     {"row": 2, "column": 0, "across": 7}
+//!   ],
+//!   "cells": [
+//!     {"row": 1, "column": 1},
+//!     {"row": 1, "column": 2},
+//!     {"row": 2, "column": 1},
+//!     {"row": 2, "column": 2}
+//!   ],
+//!   "solution": [1, 2, 3, 4],
+//!   "state": {
+//!     "values": [1, 0, 0, 0],
+//!     "candidates": [[1], [2], [3], [4]]
+//!   }
+//! }
+//! ```
+//! * width and height are the size of the whole grid, including the clue cells
+//! * rows and columns start at 0 in the top left corner, every position that is not a clue or a white cell is black
+//! * a clue has a down sum, an across sum or both, a sum that is left out or null means there is no run that way
+//! * solution has the digit of every white cell, in the same order as cells
+//! * state is a puzzle part way through being solved, values has the digit filled in for each white cell,
+//!   0 for an empty cell, and candidates has the digits each white cell can still take, both in the same order as cells
+//! * the puzzle is written with its cells in reading order, but they can be read in any order
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+
+use crate::digits::DigitSet;
+use crate::validation::ValidationError;
+use crate::{CellId, Puzzle};
+
+/// The largest width and height a puzzle read from JSON can have, so a mistake cannot ask for a huge grid
+pub const MAX_GRID_SIZE: usize = 1000;
+
+/// A JSON value
+/// * objects keep their keys in the order they were written
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Array(Vec<Json>),
+  Object(Vec<(String, Json)>)
+}
+
+impl Json {
+  /// Returns the value of the key if this is an object that has it, a null value counts as not having it
+  pub(crate) fn get(&self, key: &str) -> Option<&Json> {
+    match self {
+      Json::Object(entries) => entries.iter().find(|(name, value)| name == key && *value != Json::Null).map(|(_, value)| value),
+      _ => None
+    }
+  }
+
+  /// Returns the text of the value, with an object that holds other objects or arrays and an array that holds
+  /// objects spread over several lines, indented by 2 spaces for each level
+  pub(crate) fn to_pretty_string(&self) -> String {
+    let mut text = String::new();
+    self.write(&mut text, 0);
+    text
+  }
+
+  /// Writes the value to the text, indent is the number of spaces the lines of the value start with
+  fn write(&self, text: &mut String, indent: usize) {
+    match self {
+      Json::Null => text.push_str("null"),
+      Json::Bool(value) => text.push_str(&value.to_string()),
+      Json::Number(value) => text.push_str(&value.to_string()),
+      Json::String(value) => write_string(text, value),
+      Json::Array(items) => write_items(text, indent, ('[', ']'), items.iter().map(|item| (None, item)).collect()),
+      Json::Object(entries) => write_items(text, indent, ('{', '}'), entries.iter().map(|(key, value)| (Some(key.as_str()), value)).collect())
+    }
+  }
+}
+
+/// Writes the items of an array, or the keys and values of an object, between the brackets
+/// - the items go on their own lines when any of them is an object, or an array that is a value of an object,
+///   otherwise they share one line so short lists such as the candidates of a cell stay together
+fn write_items(text: &mut String, indent: usize, (open, close): (char, char), items: Vec<(Option<&str>, &Json)>) {
+  let nested = items.iter().any(|(key, item)| matches!(item, Json::Object(_)) || (key.is_some() && matches!(item, Json::Array(_))));
+
+  text.push(open);
+
+  for (index, (key, item)) in items.into_iter().enumerate() {
+    if index > 0 {
+      text.push(',');
+    }
+
+    if nested {
+      text.push('\n');
+      text.push_str(&" ".repeat(indent + 2));
+    } else if index > 0 {
+      text.push(' ');
+    }
+
+    if let Some(key) = key {
+      write_string(text, key);
+      text.push_str(": ");
+    }
+
+    item.write(text, indent + 2);
+  }
+
+  if nested {
+    text.push('\n');
+    text.push_str(&" ".repeat(indent));
+  }
+
+  text.push(close);
+}
+
+/// Writes the string in quotes, escaping the characters JSON does not allow inside a string
+fn write_string(text: &mut String, value: &str) {
+  text.push('"');
+
+  for c in value.chars() {
+    match c {
+      '"' => text.push_str("\\\""),
+      '\\' => text.push_str("\\\\"),
+      '\n' => text.push_str("\\n"),
+      '\r' => text.push_str("\\r"),
+      '\t' => text.push_str("\\t"),
+      c if (c as u32) < 0x20 => text.push_str(&format!("\\u{:04x}", c as u32)),
+      c => text.push(c)
+    }
+  }
+
+  text.push('"');
+}
+
+/// An error that stops a puzzle from being read from JSON
+#[derive(Debug)]
+pub enum JsonError {
+  /// The file could not be opened or read
+  Io(io::Error),
+  /// The text is not valid JSON
+  /// - line and column start at 1 and point at where the problem was found
+  Syntax { line: usize, column: usize, reason: &'static str },
+  /// The JSON is valid but does not follow the puzzle schema
+  /// - path points at the value with the problem, i.e. `cells[2].row`
+  Schema { path: String, reason: String },
+  /// The puzzle was read but breaks one or more rules of Kakuro
+  Validation(Vec<ValidationError>)
+}
+
+impl fmt::Display for JsonError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      JsonError::Io(error) => write!(f, "{}", error),
+      JsonError::Syntax { line, column, reason } => write!(f, "line {}, column {}: {}", line, column, reason),
+      JsonError::Schema { path, reason } => write!(f, "{}: {}", path, reason),
+      JsonError::Validation(errors) => {
+        write!(f, "puzzle is not valid:")?;
+
+        for error in errors {
+          write!(f, "\n  {}", error)?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
+
+impl std::error::Error for JsonError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      JsonError::Io(error) => Some(error),
+      JsonError::Syntax { .. } | JsonError::Schema { .. } | JsonError::Validation(_) => None
+    }
+  }
+}
+
+impl From<io::Error> for JsonError {
+  fn from(error: io::Error) -> Self {
+    JsonError::Io(error)
+  }
+}
+
+/// The most arrays and objects that can be nested inside each other, deeper text is an error instead of overflowing the stack
+const MAX_DEPTH: usize = 64;
+
+/// Reads JSON text one byte at a time, keeping track of where it is for error messages
+/// * depth is the number of arrays and objects the current position is inside of
+struct Parser<'a> {
+  text: &'a str,
+  position: usize,
+  depth: usize
+}
+
+impl Parser<'_> {
+  /// Returns a syntax error at the current position
+  fn error(&self, reason: &'static str) -> JsonError {
+    let before = &self.text[..self.position];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+
+    JsonError::Syntax { line, column, reason }
+  }
+
+  /// Returns the next byte without moving past it
+  fn peek(&self) -> Option<u8> {
+    self.text.as_bytes().get(self.position).copied()
+  }
+
+  /// Moves past any spaces, tabs and line breaks
+  fn skip_whitespace(&mut self) {
+    while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+      self.position += 1;
+    }
+  }
+
+  /// Moves past the expected byte, or returns an error if the next byte is something else
+  fn expect(&mut self, byte: u8, reason: &'static str) -> Result<(), JsonError> {
+    if self.peek() != Some(byte) {
+      return Err(self.error(reason));
+    }
+
+    self.position += 1;
+    Ok(())
+  }
+
+  /// Reads an array or object with the given reader, one level deeper than the current position
+  fn nested(&mut self, read: fn(&mut Self) -> Result<Json, JsonError>) -> Result<Json, JsonError> {
+    if self.depth == MAX_DEPTH {
+      return Err(self.error("arrays and objects are nested too deeply"));
+    }
+
+    self.depth += 1;
+    let value = read(self)?;
+    self.depth -= 1;
+
+    Ok(value)
+  }
+
+  /// Reads the value that starts at the current position, along with any whitespace around it
+  fn value(&mut self) -> Result<Json, JsonError> {
+    self.skip_whitespace();
+
+    let value = match self.peek() {
+      Some(b'{') => self.nested(Self::object)?,
+      Some(b'[') => self.nested(Self::array)?,
+      Some(b'"') => Json::String(self.string()?),
+      Some(b'-' | b'0'..=b'9') => self.number()?,
+      Some(b't') => self.keyword("true", Json::Bool(true))?,
+      Some(b'f') => self.keyword("false", Json::Bool(false))?,
+      Some(b'n') => self.keyword("null", Json::Null)?,
+      Some(_) => return Err(self.error("expected a value")),
+      None => return Err(self.error("unexpected end of the text"))
+    };
+
+    self.skip_whitespace();
+    Ok(value)
+  }
+
+  /// Reads true, false or null
+  fn keyword(&mut self, word: &'static str, value: Json) -> Result<Json, JsonError> {
+    if !self.text[self.position..].starts_with(word) {
+      return Err(self.error("expected a value"));
+    }
+
+    self.position += word.len();
+    Ok(value)
+  }
+
+  /// Reads a number, which has to follow the JSON rules, i.e. no leading + or trailing .
+  fn number(&mut self) -> Result<Json, JsonError> {
+    let start = self.position;
+
+    while matches!(self.peek(), Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+      self.position += 1;
+    }
+
+    let number = &self.text[start..self.position];
+    let digits = number.strip_prefix('-').unwrap_or(number);
+    let (mantissa, exponent) = digits.split_once(['e', 'E']).map_or((digits, None), |(mantissa, exponent)| (mantissa, Some(exponent)));
+    let (whole, fraction) = mantissa.split_once('.').map_or((mantissa, None), |(whole, fraction)| (whole, Some(fraction)));
+
+    let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+    let valid = all_digits(whole)
+      && (whole == "0" || !whole.starts_with('0'))
+      && fraction.is_none_or(all_digits)
+      && exponent.is_none_or(|exponent| all_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)));
+
+    match number.parse() {
+      Ok(value) if valid => Ok(Json::Number(value)),
+      _ => {
+        self.position = start;
+        Err(self.error("invalid number"))
+      }
+    }
+  }
+
+  /// Reads a string in quotes, turning its escapes back into the characters they stand for
+  fn string(&mut self) -> Result<String, JsonError> {
+    self.expect(b'"', "expected a string")?;
+
+    let mut value = String::new();
+
+    loop {
+      let rest = &self.text[self.position..];
+      let Some(c) = rest.chars().next() else {
+        return Err(self.error("unterminated string"));
+      };
+
+      match c {
+        '"' => {
+          self.position += 1;
+          return Ok(value);
+        },
+        '\\' => {
+          self.position += 1;
+          value.push(self.escape()?);
+        },
+        c if (c as u32) < 0x20 => return Err(self.error("control character in string")),
+        c => {
+          value.push(c);
+          self.position += c.len_utf8();
+        }
+      }
+    }
+  }
+
+  /// Reads the escape after a \ in a string, a \u escape of a surrogate pair has to be followed by the other half
+  fn escape(&mut self) -> Result<char, JsonError> {
+    let Some(byte) = self.peek() else {
+      return Err(self.error("unterminated string"));
+    };
+
+    self.position += 1;
+
+    let c = match byte {
+      b'"' => '"',
+      b'\\' => '\\',
+      b'/' => '/',
+      b'b' => '\u{8}',
+      b'f' => '\u{c}',
+      b'n' => '\n',
+      b'r' => '\r',
+      b't' => '\t',
+      b'u' => {
+        let high = self.hex()?;
+
+        if !(0xd800..0xdc00).contains(&high) {
+          return char::from_u32(high).ok_or_else(|| self.error("invalid unicode escape"));
+        }
+
+        if !self.text[self.position..].starts_with("\\u") {
+          return Err(self.error("invalid unicode escape"));
+        }
+
+        self.position += 2;
+        let low = self.hex()?;
+
+        if !(0xdc00..0xe000).contains(&low) {
+          return Err(self.error("invalid unicode escape"));
+        }
+
+        return char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)).ok_or_else(|| self.error("invalid unicode escape"));
+      },
+      _ => {
+        self.position -= 1;
+        return Err(self.error("invalid escape"));
+      }
+    };
+
+    Ok(c)
+  }
+
+  /// Reads the 4 hex digits of a \u escape
+  fn hex(&mut self) -> Result<u32, JsonError> {
+    let digits = self.text.get(self.position..self.position + 4).filter(|digits| digits.bytes().all(|byte| byte.is_ascii_hexdigit()));
+    let Some(digits) = digits else {
+      return Err(self.error("invalid unicode escape"));
+    };
+
+    self.position += 4;
+    Ok(u32::from_str_radix(digits, 16).unwrap())
+  }
+
+  /// Reads an array of values separated by commas
+  fn array(&mut self) -> Result<Json, JsonError> {
+    self.expect(b'[', "expected an array")?;
+    self.skip_whitespace();
+
+    let mut items = Vec::new();
+
+    if self.peek() == Some(b']') {
+      self.position += 1;
+      return Ok(Json::Array(items));
+    }
+
+    loop {
+      items.push(self.value()?);
+
+      match self.peek() {
+        Some(b',') => self.position += 1,
+        Some(b']') => {
+          self.position += 1;
+          return Ok(Json::Array(items));
+        },
+        _ => return Err(self.error("expected , or ]"))
+      }
+    }
+  }
+
+  /// Reads an object of "key": value pairs separated by commas
+  fn object(&mut self) -> Result<Json, JsonError> {
+    self.expect(b'{', "expected an object")?;
+    self.skip_whitespace();
+
+    let mut entries = Vec::new();
+
+    if self.peek() == Some(b'}') {
+      self.position += 1;
+      return Ok(Json::Object(entries));
+    }
+
+    loop {
+      self.skip_whitespace();
+      let key = self.string()?;
+      self.skip_whitespace();
+      self.expect(b':', "expected :")?;
+      entries.push((key, self.value()?));
+
+      match self.peek() {
+        Some(b',') => self.position += 1,
+        Some(b'}') => {
+          self.position += 1;
+          return Ok(Json::Object(entries));
+        },
+        _ => return Err(self.error("expected , or }"))
+      }
+    }
+  }
+}
+
+/// Parameters:
+/// - text: The JSON text
+///
+/// Description:
+/// - Reads the single value that makes up the text, anything other than whitespace after it is an error
+pub(crate) fn parse(text: &str) -> Result<Json, JsonError> {
+  let mut parser = Parser { text, position: 0, depth: 0 };
+  let value = parser.value()?;
+
+  if parser.position < text.len() {
+    return Err(parser.error("unexpected text after the value"));
+  }
+
+  Ok(value)
+}
+
+/// Returns a schema error for the value at the path
+fn schema_error(path: &str, reason: &str) -> JsonError {
+  JsonError::Schema { path: path.to_string(), reason: reason.to_string() }
+}
+
+/// Returns the value of a key that has to be there
+fn required<'a>(value: &'a Json, path: &str, key: &str) -> Result<&'a Json, JsonError> {
+  value.get(key).ok_or_else(|| schema_error(&field_path(path, key), "missing"))
+}
+
+/// Returns the path of a key of the object at the path, i.e. `cells[2].row`
+fn field_path(path: &str, key: &str) -> String {
+  if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) }
+}
+
+/// Returns the items of the value, which has to be an array
+fn array<'a>(value: &'a Json, path: &str) -> Result<&'a [Json], JsonError> {
+  match value {
+    Json::Array(items) => Ok(items),
+    _ => Err(schema_error(path, "expected an array"))
+  }
+}
+
+/// Returns the value as a whole number from 0 up to max
+fn integer(value: &Json, path: &str, max: usize) -> Result<usize, JsonError> {
+  match value {
+    Json::Number(number) if number.fract() == 0.0 && *number >= 0.0 && *number <= max as f64 => Ok(*number as usize),
+    _ => Err(schema_error(path, &format!("expected a whole number from 0 to {}", max)))
+  }
+}
+
+/// Returns the row and column of the object at the path, which have to be on a grid of the size
+fn position(value: &Json, path: &str, width: usize, height: usize) -> Result<(usize, usize), JsonError> {
+  if width == 0 || height == 0 {
+    return Err(schema_error(path, "the grid has no cells"));
+  }
+
+  let row = integer(required(value, path, "row")?, &field_path(path, "row"), height - 1)?;
+  let column = integer(required(value, path, "column")?, &field_path(path, "column"), width - 1)?;
+
+  Ok((row, column))
+}
+
+/// Returns the digits of the array at the path, each digit has to be from min to 9
+/// - count is the number of digits the array has to have, one for every white cell
+fn digit_list(value: &Json, path: &str, count: usize, min: usize) -> Result<Vec<u8>, JsonError> {
+  let items = array(value, path)?;
+
+  if items.len() != count {
+    return Err(schema_error(path, &format!("expected {} digits, one for every white cell, found {}", count, items.len())));
+  }
+
+  items.iter().enumerate().map(|(index, item)| {
+    let item_path = format!("{}[{}]", path, index);
+    let digit = integer(item, &item_path, 9)?;
+
+    if digit < min {
+      return Err(schema_error(&item_path, &format!("expected a digit from {} to 9", min)));
+    }
+
+    Ok(digit as u8)
+  }).collect()
+}
+
+/// A puzzle read from JSON
+/// * puzzle has the values and candidates of the state, if there was one, otherwise it is ready to be solved
+/// * solution is the solved puzzle, if the JSON had one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPuzzle {
+  pub puzzle: Puzzle,
+  pub solution: Option<Puzzle>
+}
+
+/// Parameters:
+/// - text: The JSON text of a puzzle, following the schema at the top of this module
+///
+/// Description:
+/// - Reads the grid and clues and sets up the puzzle the same way as [`Puzzle::load`],
+///   then fills in the solution and the state if they are there
+/// - Returns an error if the text is not JSON, does not follow the schema, the puzzle is not valid
+///   or the solution does not solve the puzzle
+pub fn read_json(text: &str) -> Result<JsonPuzzle, JsonError> {
+  let root = parse(text)?;
+
+  if !matches!(root, Json::Object(_)) {
+    return Err(schema_error("", "expected an object"));
+  }
+
+  let width = integer(required(&root, "", "width")?, "width", MAX_GRID_SIZE)?;
+  let height = integer(required(&root, "", "height")?, "height", MAX_GRID_SIZE)?;
+  let mut taken = HashSet::new();
+
+  let mut clues = Vec::new();
+
+  for (index, clue) in array(required(&root, "", "clues")?, "clues")?.iter().enumerate() {
+    let path = format!("clues[{}]", index);
+    let position = position(clue, &path, width, height)?;
+    let sum = |key: &str| clue.get(key).map(|sum| integer(sum, &field_path(&path, key), u8::MAX as usize).map(|sum| sum as u8)).transpose();
+
+    if !taken.insert(position) {
+      return Err(schema_error(&path, "another clue or cell is already at this position"));
+    }
+
+    clues.push((position, sum("down")?, sum("across")?));
+  }
+
+  let mut cells = Vec::new();
+
+  for (index, cell) in array(required(&root, "", "cells")?, "cells")?.iter().enumerate() {
+    let path = format!("cells[{}]", index);
+    let position = position(cell, &path, width, height)?;
+
+    if !taken.insert(position) {
+      return Err(schema_error(&path, "another clue or cell is already at this position"));
+    }
+
+    cells.push(position);
+  }
+
+  let mut puzzle = Puzzle::from_layout(width, height, &clues, &cells).map_err(JsonError::Validation)?;
+
+  // the digits are listed in the same order as the cells, which may not be reading order
+  let ids: Vec<CellId> = cells.iter().map(|&(row, column)| puzzle.grid[row][column].child.unwrap()).collect();
+
+  let solution = match root.get("solution") {
+    Some(digits) => {
+      let mut solution = puzzle.clone();
+
+      for (&id, digit) in ids.iter().zip(digit_list(digits, "solution", ids.len(), 1)?) {
+        solution[id].value = digit;
+        solution[id].possible_values = DigitSet::single(digit);
+      }
+
+      if !solution.is_solved() {
+        return Err(schema_error("solution", "the digits do not solve the puzzle"));
+      }
+
+      Some(solution)
+    },
+    None => None
+  };
+
+  if let Some(state) = root.get("state") {
+    if let Some(values) = state.get("values") {
+      for (&id, value) in ids.iter().zip(digit_list(values, "state.values", ids.len(), 0)?) {
+        puzzle[id].value = value;
+      }
+    }
+
+    if let Some(candidates) = state.get("candidates") {
+      let lists = array(candidates, "state.candidates")?;
+
+      if lists.len() != ids.len() {
+        return Err(schema_error("state.candidates", &format!("expected {} lists, one for every white cell, found {}", ids.len(), lists.len())));
+      }
+
+      for (index, (&id, list)) in ids.iter().zip(lists).enumerate() {
+        let path = format!("state.candidates[{}]", index);
+        let count = array(list, &path)?.len();
+        let digits = digit_list(list, &path, count, 1)?;
+        puzzle[id].possible_values = digits.into_iter().collect();
+      }
+    }
+  }
+
+  Ok(JsonPuzzle { puzzle, solution })
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being written
+/// - solution: The solved puzzle, written as the solution if it is given
+/// - include_state: If true the values and possible values of the puzzle are written as the state
+///
+/// Description:
+/// - Writes the puzzle as JSON following the schema at the top of this module, with the cells in reading order
+pub fn write_json(puzzle: &Puzzle, solution: Option<&Puzzle>, include_state: bool) -> String {
+  let number = |value: usize| Json::Number(value as f64);
+  let position = |(row, column): (usize, usize)| vec![("row".to_string(), number(row)), ("column".to_string(), number(column))];
+
+  let mut clues = Vec::new();
+
+  for (row, cells) in puzzle.grid.iter().enumerate() {
+    for (column, cell) in cells.iter().enumerate() {
+      if cell.vert.is_none() && cell.horz.is_none() {
+        continue;
+      }
+
+      let mut clue = position((row, column));
+
+      for (key, run) in [("down", cell.vert), ("across", cell.horz)] {
+        if let Some(id) = run {
+          clue.push((key.to_string(), number(puzzle[id].sum as usize)));
+        }
+      }
+
+      clues.push(Json::Object(clue));
+    }
+  }
+
+  let cells = puzzle.children.iter().map(|child| Json::Object(position(child.position))).collect();
+  let digits = |puzzle: &Puzzle| Json::Array(puzzle.children.iter().map(|child| number(child.value as usize)).collect());
+
+  let mut root = vec![
+    ("width".to_string(), number(puzzle.width)),
+    ("height".to_string(), number(puzzle.height)),
+    ("clues".to_string(), Json::Array(clues)),
+    ("cells".to_string(), Json::Array(cells))
+  ];
+
+  if let Some(solution) = solution {
+    root.push(("solution".to_string(), digits(solution)));
+  }
+
+  if include_state {
+    let candidates = puzzle.children.iter()
+      .map(|child| Json::Array(child.possible_values.iter().map(|digit| number(digit as usize)).collect()))
+      .collect();
+
+    root.push(("state".to_string(), Json::Object(vec![
+      ("values".to_string(), digits(puzzle)),
+      ("candidates".to_string(), Json::Array(candidates))
+    ])));
+  }
+
+  let mut text = Json::Object(root).to_pretty_string();
+  text.push('\n');
+  text
+}
@@ -0,0 +1,672 @@
+/*
+*
+* Author: Matthew Jacobs
+* Created: July 2024
+* Updated: November 2nd, 2024
+* Copyright: Matthew Jacobs
+* Version: 0.1.0
+*
+*/
+
+//! Reads, validates, solves and draws Kakuro puzzles.
+//!
+//! A puzzle is loaded from a file with [`Puzzle::load`], which reads the grid, connects every run (a Parent)
+//! to its white cells (its Children) and works out the possible values of each cell.
+//! The loaded puzzle can then be solved with [`Puzzle::solve`] and drawn with [`Puzzle::render`],
+//! or as an SVG image with [`Puzzle::to_svg`]. [`Puzzle::to_html`] turns it into a page that can be played in a browser.
+//! Puzzles can also be written with each cell in its place on the grid, see [`grid_format`], which the loader tells apart by itself.
+//! [`Puzzle::to_text`] and [`Puzzle::save`] write a puzzle back out in the original format.
+//! Puzzles, their solutions and a player's progress can also be read and written as JSON, see [`json`].
+//! New puzzles with exactly one solution are made by [`generator::generate`].
+//! Whole folders of puzzles are solved at once, spread over every CPU core, by [`batch::solve_all`].
+//! A puzzle being solved by hand, with pencil marks and undo, is kept by [`play::Game`].
+
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::ops::{Index, IndexMut};
+use std::path::Path;
+
+use digits::DigitSet;
+use error::{ParseError, ParseReason};
+use validation::ValidationError;
+
+pub mod batch;
+pub mod combinations;
+pub mod difficulty;
+pub mod digits;
+pub mod error;
+pub mod generator;
+pub mod grid_format;
+pub mod hints;
+pub mod html;
+pub mod json;
+pub mod play;
+pub mod render;
+pub mod solver;
+pub mod svg;
+pub mod techniques;
+pub mod validation;
+
+#[cfg(test)]
+mod tests;
+
+/// The index of a run (a Parent) within a Puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RunId(pub usize);
+
+/// The index of a white cell (a Child) within a Puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CellId(pub usize);
+
+/// A clue cell of a puzzle that is built from its layout, its position followed by its down and across sums,
+/// None for a direction without a run
+pub(crate) type ClueCell = ((usize, usize), Option<u8>, Option<u8>);
+
+/// One cell of the grid
+/// * vert and horz are the runs whose clues are in this cell, if it has any
+/// * child is the white cell at this location, if there is one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridCell {
+  pub vert: Option<RunId>,
+  pub horz: Option<RunId>,
+  pub child: Option<CellId>
+}
+
+impl GridCell {
+  /// A black cell without any clues, every cell starts out like this while the puzzle is read
+  const BLACK: GridCell = GridCell { vert: None, horz: None, child: None };
+
+  /// Returns true if the cell is black and has no clue in either direction
+  pub fn is_blank(&self) -> bool {
+    self.child.is_none() && self.vert.is_none() && self.horz.is_none()
+  }
+}
+
+/// The direction a run of white cells goes from its clue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+  Down,
+  Across
+}
+
+impl fmt::Display for Direction {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Direction::Down => write!(f, "down"),
+      Direction::Across => write!(f, "across")
+    }
+  }
+}
+
+/// A run of white cells along with the clue it has to add up to
+/// * children are the run's cells, in order from the clue
+/// * value_size is the sum and the number of cells written as sum-size, i.e. 11-3
+/// * combinations are the sets of unique digits that could still fill the run
+/// * head is the (row, column) of the clue cell
+#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Parents {
+  pub children: Vec<CellId>,
+  pub sum: u8,
+  pub value_size: String,
+  pub combinations: Vec<DigitSet>,
+  pub direction: Direction,
+  pub head: (usize, usize)
+}
+
+/// A white cell of the grid
+/// * parents are its down run and across run, in that order
+/// * siblings are every other cell in either of its runs
+/// * value is 0 while the cell is empty
+/// * position is the (row, column) of the cell
+#[derive(Debug, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Children {
+  pub parents: (RunId, RunId),
+  pub siblings: Vec<CellId>,
+  pub value: u8,
+  pub possible_values: DigitSet,
+  pub position: (usize, usize)
+}
+
+impl Index<RunId> for Vec<Parents> {
+  type Output = Parents;
+
+  fn index(&self, id: RunId) -> &Parents {
+    &self[id.0]
+  }
+}
+
+impl IndexMut<RunId> for Vec<Parents> {
+  fn index_mut(&mut self, id: RunId) -> &mut Parents {
+    &mut self[id.0]
+  }
+}
+
+impl Index<CellId> for Vec<Children> {
+  type Output = Children;
+
+  fn index(&self, id: CellId) -> &Children {
+    &self[id.0]
+  }
+}
+
+impl IndexMut<CellId> for Vec<Children> {
+  fn index_mut(&mut self, id: CellId) -> &mut Children {
+    &mut self[id.0]
+  }
+}
+
+/// A puzzle along with the shape of its grid
+/// * width and height are the number of columns and rows in the grid
+/// * grid is the scaffold built while reading the puzzle, kept so the puzzle can be drawn and talked about by position
+/// * parents are the runs and children are the white cells, indexed by RunId and CellId
+/// * every position is a (row, column) pair starting at 0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+  pub width: usize,
+  pub height: usize,
+  pub grid: Vec<Vec<GridCell>>,
+  pub parents: Vec<Parents>,
+  pub children: Vec<Children>
+}
+
+impl Index<RunId> for Puzzle {
+  type Output = Parents;
+
+  fn index(&self, id: RunId) -> &Parents {
+    &self.parents[id]
+  }
+}
+
+impl IndexMut<RunId> for Puzzle {
+  fn index_mut(&mut self, id: RunId) -> &mut Parents {
+    &mut self.parents[id]
+  }
+}
+
+impl Index<CellId> for Puzzle {
+  type Output = Children;
+
+  fn index(&self, id: CellId) -> &Children {
+    &self.children[id]
+  }
+}
+
+impl IndexMut<CellId> for Puzzle {
+  fn index_mut(&mut self, id: CellId) -> &mut Children {
+    &mut self.children[id]
+  }
+}
+
+impl Puzzle {
+  /// Parameters:
+  /// - puzzle_file: The path to the file that contains the puzzle to be read in and solved
+  ///
+  /// Description:
+  /// - Reads in the puzzle, connects every Parent to its children and works out the possible values of each Child
+  ///   so the puzzle is ready to be solved
+  /// - Returns an error if the puzzle file cannot be read or is not a valid puzzle
+  pub fn load(puzzle_file: &Path) -> Result<Puzzle, ParseError> {
+    Puzzle::parse(&fs::read_to_string(puzzle_file)?)
+  }
+
+  /// Parameters:
+  /// - text: The contents of a puzzle file
+  ///
+  /// Description:
+  /// - Reads in the puzzle the same way as [`Puzzle::load`] does, but from text that is already in memory
+  /// - The format is worked out from the first cell, a board of -, \ and x followed by the clues is the original format,
+  ///   anything else is the [`grid_format`] where each cell is written in its place
+  pub fn parse(text: &str) -> Result<Puzzle, ParseError> {
+    if grid_format::is_grid_format(text) {
+      return grid_format::read_grid(text);
+    }
+
+    Puzzle::set_up(read_puzzle(text.as_bytes())?).map_err(ParseError::Validation)
+  }
+
+  /// Parameters:
+  /// - puzzle_file: The path the puzzle is written to, an existing file is replaced
+  ///
+  /// Description:
+  /// - Writes the puzzle in the original format, see [`Puzzle::to_text`]
+  pub fn save(&self, puzzle_file: &Path) -> io::Result<()> {
+    fs::write(puzzle_file, self.to_text())
+  }
+
+  /// Returns the puzzle in the format of the puzzle files, which [`Puzzle::parse`] reads back into the same puzzle
+  /// - Only the layout and the clues are written, the values filled in so far are not part of the format
+  pub fn to_text(&self) -> String {
+    write_puzzle(self)
+  }
+
+  /// Returns the puzzle in the [`grid_format`], with the columns lined up and the values filled in so far
+  pub fn to_grid_text(&self) -> String {
+    grid_format::write_grid(self)
+  }
+
+  /// Parameters:
+  /// - puzzle_file: The path to a JSON file that contains the puzzle, see [`json`] for the schema
+  ///
+  /// Description:
+  /// - Reads in the puzzle along with its solution and the state it was saved in, if the file has them
+  /// - Returns an error if the file cannot be read, does not follow the schema or the puzzle is not valid
+  pub fn load_json(puzzle_file: &Path) -> Result<json::JsonPuzzle, json::JsonError> {
+    json::read_json(&fs::read_to_string(puzzle_file)?)
+  }
+
+  /// Parameters:
+  /// - puzzle_file: The path the JSON is written to, an existing file is replaced
+  /// - solution: The solved puzzle, saved along with the puzzle if it is given
+  /// - include_state: If true the values and possible values filled in so far are saved as well
+  ///
+  /// Description:
+  /// - Writes the puzzle as JSON, see [`json::write_json`]
+  pub fn save_json(&self, puzzle_file: &Path, solution: Option<&Puzzle>, include_state: bool) -> io::Result<()> {
+    fs::write(puzzle_file, json::write_json(self, solution, include_state))
+  }
+
+  /// Parameters:
+  /// - width: The number of columns of the grid
+  /// - height: The number of rows of the grid
+  /// - clues: The position of each clue cell along with its down and across sums, None for a direction without a run
+  /// - cells: The position of each white cell
+  ///      - Every position must be on the grid and no two clue or white cells may share a position
+  ///
+  /// Description:
+  /// - Builds the puzzle the same way as [`Puzzle::load`], for puzzles that are not read from the text format,
+  ///   every other cell of the grid is black
+  /// - Returns every rule of Kakuro the puzzle breaks if it is not valid
+  pub(crate) fn from_layout(width: usize, height: usize, clues: &[ClueCell], cells: &[(usize, usize)]) -> Result<Puzzle, Vec<ValidationError>> {
+    let mut grid = vec![vec![GridCell::BLACK; width]; height];
+    let mut parents = Vec::new();
+    let mut children = Vec::new();
+    let mut clue_cells = vec![vec![None; width]; height];
+
+    for &((row, column), down, across) in clues {
+      clue_cells[row][column] = Some((down, across));
+    }
+
+    for &(row, column) in cells {
+      grid[row][column].child = Some(CellId(0));
+    }
+
+    // the ids are handed out in reading order, the same order read_puzzle gives them in
+    for row in 0..height {
+      for column in 0..width {
+        if grid[row][column].child.is_some() {
+          grid[row][column].child = Some(CellId(children.len()));
+          children.push(Children { parents: (RunId(0), RunId(0)), siblings: Vec::new(), value: 0, possible_values: DigitSet::EMPTY, position: (row, column) });
+          continue;
+        }
+
+        let Some((down, across)) = clue_cells[row][column] else {
+          continue;
+        };
+
+        for (direction, sum) in [(Direction::Down, down), (Direction::Across, across)] {
+          let Some(sum) = sum else {
+            continue;
+          };
+
+          let parent_id = Some(RunId(parents.len()));
+          if direction == Direction::Down { grid[row][column].vert = parent_id; } else { grid[row][column].horz = parent_id; }
+
+          parents.push(Parents { children: Vec::new(), sum, value_size: String::new(), combinations: Vec::new(), direction, head: (row, column) });
+        }
+      }
+    }
+
+    Puzzle::set_up(Puzzle { width, height, grid, parents, children })
+  }
+
+  /// Parameters:
+  /// - puzzle: A puzzle that has just been read in, whose parents and children are not connected yet
+  ///
+  /// Description:
+  /// - Connects every Parent to its children, validates the puzzle and works out the possible values of each Child
+  /// - Returns every rule of Kakuro the puzzle breaks if it is not valid
+  fn set_up(mut puzzle: Puzzle) -> Result<Puzzle, Vec<ValidationError>> {
+    puzzle.connect_parents_and_children();
+
+    // make sure the connected grid is a proper Kakuro before it is solved
+    let errors = puzzle.validate();
+
+    if !errors.is_empty() {
+      return Err(errors);
+    }
+
+    puzzle.set_possible_combinations();
+    puzzle.set_siblings_and_possible_values();
+
+    Ok(puzzle)
+  }
+
+  /// Returns the id of every run, in the order their clues appear on the grid
+  pub fn run_ids(&self) -> impl Iterator<Item = RunId> {
+    (0..self.parents.len()).map(RunId)
+  }
+
+  /// Returns the id of every white cell, in reading order
+  pub fn cell_ids(&self) -> impl Iterator<Item = CellId> {
+    (0..self.children.len()).map(CellId)
+  }
+
+  /// Checks the puzzle against the rules of Kakuro, returning every problem found
+  /// - A puzzle returned by [`Puzzle::load`] has already passed validation
+  pub fn validate(&self) -> Vec<ValidationError> {
+    validation::validate(self)
+  }
+
+  /// Narrows down the combinations and possible values without guessing, see [`solver::propagate`]
+  pub fn propagate(&mut self) -> bool {
+    solver::propagate(self)
+  }
+
+  /// Fills in every white cell, returning false and leaving the puzzle as it was if there is no solution
+  pub fn solve(&mut self) -> bool {
+    solver::solve(self)
+  }
+
+  /// Solves the puzzle like [`Puzzle::solve`], calling trace with every step the solver takes, see [`solver::solve_traced`]
+  pub fn solve_traced(&mut self, trace: &mut solver::Trace<'_>) -> bool {
+    solver::solve_traced(self, trace)
+  }
+
+  /// Returns up to limit solved copies of the puzzle, see [`solver::find_solutions`]
+  pub fn find_solutions(&self, limit: usize) -> Vec<Puzzle> {
+    solver::find_solutions(self, limit)
+  }
+
+  /// Counts the solutions of the puzzle up to a limit, see [`solver::count_solutions`]
+  pub fn count_solutions(&self, limit: usize) -> solver::SolutionCount {
+    solver::count_solutions(self, limit)
+  }
+
+  /// Solves the puzzle one human technique at a time and returns the log of steps, see [`techniques::solve_with_techniques`]
+  pub fn solve_with_techniques(&mut self) -> Vec<techniques::Step> {
+    techniques::solve_with_techniques(self)
+  }
+
+  /// Returns the easiest deduction the player can make next from the values filled in so far, see [`hints::next_hint`]
+  pub fn next_hint(&self) -> Option<hints::Hint> {
+    hints::next_hint(self)
+  }
+
+  /// Rates how hard the puzzle is for a person to solve, see [`difficulty::rate`]
+  pub fn rate(&self) -> Option<difficulty::Difficulty> {
+    difficulty::rate(self)
+  }
+
+  /// Returns true if every white cell is filled in and every run adds up to its clue without repeating a digit
+  pub fn is_solved(&self) -> bool {
+    solver::is_solved(self)
+  }
+
+  /// Draws the puzzle as a boxed grid, see [`render::render`]
+  pub fn render(&self, show_candidates: bool) -> String {
+    render::render(self, show_candidates)
+  }
+
+  /// Draws the puzzle as an SVG image, see [`svg::render_svg`]
+  pub fn to_svg(&self, options: &svg::SvgOptions) -> String {
+    svg::render_svg(self, options)
+  }
+
+  /// Writes the puzzle as an HTML page that can be played in a browser, checked against the solution, see [`html::write_html`]
+  pub fn to_html(&self, solution: &Puzzle) -> String {
+    html::write_html(self, solution)
+  }
+
+  /// Description:
+  /// - Looks up the sum combinations for each parent from the precomputed table of combinations
+  ///   and adds them to the list of possible combinations for that parent
+  fn set_possible_combinations(&mut self) {
+    for parent in &mut self.parents {
+      parent.combinations = combinations::combinations(parent.sum, parent.children.len()).to_vec();
+    }
+  }
+
+  /// Description:
+  /// - Walks down and across from every clue on the grid, connecting each Parent to the children in its run
+  ///   and each Child to its two parents
+  fn connect_parents_and_children(&mut self) {
+    let Puzzle { grid, parents, children, .. } = self;
+
+    // loop through each row and column
+    for (current_row_num, row) in grid.iter().enumerate() {
+      for (current_col_num, col) in row.iter().enumerate() {
+
+        // loop through the following twice, first as vert then as horz
+        for (direction, relation) in [(Direction::Down, col.vert), (Direction::Across, col.horz)] {
+          // skip the direction if this cell has no clue for it
+          let Some(parent_id) = relation else {
+            continue;
+          };
+
+          // get the max index and the current position on that axis
+          let max_pos = if direction == Direction::Down { grid.len() } else { grid[0].len() };
+          let mut pos_num = if direction == Direction::Down { current_row_num + 1 } else { current_col_num + 1 };
+
+          while pos_num < max_pos {
+            // while still on the grid get the child from the current grid position
+            let grid_cell = if direction == Direction::Down { &grid[pos_num][current_col_num] } else { &grid[current_row_num][pos_num] };
+
+            // if the GridCell is not that of a child then break from the loop
+            let Some(child_id) = grid_cell.child else {
+              break;
+            };
+
+            // add the child to its Parents list of children
+            parents[parent_id].children.push(child_id);
+
+            // this ensures that both parents are properly assigned and that one is not overwritten by the other on accident
+            if direction == Direction::Down {
+              children[child_id].parents.0 = parent_id;
+            } else {
+              children[child_id].parents.1 = parent_id;
+            }
+
+            pos_num += 1;
+          }
+
+          // calculates and set the Parents value_size
+          let parent = &mut parents[parent_id];
+          parent.value_size = format!("{}-{}", parent.sum, parent.children.len());
+        }
+      }
+    }
+  }
+
+  /// Description:
+  /// - This function finds and assigns both the siblings and the possible values for each Child
+  fn set_siblings_and_possible_values(&mut self) {
+    let Puzzle { parents, children, .. } = self;
+
+    // loop through all of the Children
+    for (index, child) in children.iter_mut().enumerate() {
+      // Get both parents of the selected child
+      let parent_1 = &parents[child.parents.0];
+      let parent_2 = &parents[child.parents.1];
+
+      // sets the siblings of the child to be its parents' children that are not itself
+      child.siblings.extend_from_slice(&parent_1.children);
+      child.siblings.extend_from_slice(&parent_2.children);
+      child.siblings.sort_unstable();
+      child.siblings.retain(|&e| e != CellId(index));
+
+      // Flattening the combinations into the set of digits used by any of them
+      let parent_1_values = parent_1.combinations.iter().fold(DigitSet::EMPTY, |values, &combo| values | combo);
+      let parent_2_values = parent_2.combinations.iter().fold(DigitSet::EMPTY, |values, &combo| values | combo);
+
+      // the possible values are the digits both parents could use
+      child.possible_values = parent_1_values & parent_2_values;
+    }
+  }
+}
+
+/// Parameters:
+/// - line: A line from the puzzle file
+///
+/// Description:
+/// - Splits the line at whitespace, keeping the byte offset each token starts at so errors can point to it
+pub(crate) fn tokens(line: &str) -> Vec<(usize, &str)> {
+  let mut tokens = Vec::new();
+  let mut start: Option<usize> = None;
+
+  for (index, c) in line.char_indices() {
+    if c.is_whitespace() {
+      if let Some(token_start) = start.take() {
+        tokens.push((token_start, &line[token_start..index]));
+      }
+    } else if start.is_none() {
+      start = Some(index);
+    }
+  }
+
+  if let Some(token_start) = start {
+    tokens.push((token_start, &line[token_start..]));
+  }
+
+  tokens
+}
+
+/// Parameters:
+/// - reader: Reads the lines of the puzzle, i.e. from a file
+///
+/// Description:
+/// - This function reads in the puzzle and establishes a grid which acts like a scaffold
+///   which allows the parents to be connected to their children
+/// - Every clue adds a Parent and every white cell adds a Child, which are not connected to each other yet
+/// - Returns an error if the puzzle cannot be read or a line of it is not a valid row of the puzzle
+fn read_puzzle(reader: impl BufRead) -> Result<Puzzle, ParseError> {
+  let mut grid: Vec<Vec<GridCell>> = Vec::new();
+  let mut parents: Vec<Parents> = Vec::new();
+  let mut children: Vec<Children> = Vec::new();
+
+  // Loop through each line of the file
+  for (line_index, line) in reader.lines().enumerate() {
+    let line = line?;
+    let line_number = line_index + 1;
+
+    // Split the line into multiple segments, blank lines are skipped
+    let mut elements = tokens(&line).into_iter();
+
+    // Extract the first item (the board) from elements
+    let Some((board_start, board)) = elements.next() else {
+      continue;
+    };
+
+    grid.push(Vec::new());
+
+    // loop through each character in the board
+    for (board_index, c) in board.char_indices() {
+      let column = board_start + board_index + 1;
+
+      match c {
+        '-' => {
+          // if - push a nothing cell onto the end of the grid
+          grid.last_mut().unwrap().push(GridCell::BLACK);
+        },
+        '\\' => {
+          // if \
+          // grab the next string from element and split it at the \, then grab both strings individually
+          let Some((clue_start, clue)) = elements.next() else {
+            return Err(ParseError::Invalid { line: line_number, column, token: c.to_string(), reason: ParseReason::MissingClue });
+          };
+
+          let mut values = clue.split('\\');
+          let vert = values.next().unwrap_or("-");
+          let horz = values.next().unwrap_or("-");
+          let mut cell = GridCell::BLACK;
+
+          // loop through both of the strings collected above, keeping track of where each one starts on the line
+          for (index, (relation, relation_start)) in [(vert, clue_start), (horz, clue_start + vert.len() + 1)].into_iter().enumerate() {
+            if relation != "-" {
+              // split the string and parse out the size component
+              let sum_value: u8 = relation.parse().map_err(|_| {
+                let reason = if !relation.is_empty() && relation.chars().all(|digit| digit.is_ascii_digit()) { ParseReason::ClueOutOfRange } else { ParseReason::NonNumericClue };
+                ParseError::Invalid { line: line_number, column: relation_start + 1, token: relation.to_string(), reason }
+              })?;
+
+              // if there is a value then set the proper GridCell attribute with the positional index
+              let parent_id = Some(RunId(parents.len()));
+              if index == 0 { cell.vert = parent_id; } else { cell.horz = parent_id; }
+
+              // add a new Parent to the list of parents
+              let direction = if index == 0 { Direction::Down } else { Direction::Across };
+              let head = (grid.len() - 1, grid.last().unwrap().len());
+              parents.push(Parents { children: Vec::new(), sum: sum_value, value_size: "".to_string().to_string(), combinations: Vec::new(), direction, head });
+            }
+          }
+
+          grid.last_mut().unwrap().push(cell);
+        },
+        'x' => {
+          // if x then add a child cell to the end of the grid and add a new Child to the list of children
+          let position = (grid.len() - 1, grid.last().unwrap().len());
+          grid.last_mut().unwrap().push(GridCell { child: Some(CellId(children.len())), ..GridCell::BLACK });
+          // its parents are filled in once every run has been connected to its children
+          children.push(Children { parents: (RunId(0), RunId(0)), siblings: Vec::new(), value: 0, possible_values: DigitSet::EMPTY, position });
+        },
+        _ => return Err(ParseError::Invalid { line: line_number, column, token: c.to_string(), reason: ParseReason::UnknownCellCharacter }),
+      }
+    }
+
+    // every clue on the line has to belong to a \ cell on the board
+    if let Some((clue_start, clue)) = elements.next() {
+      let cells = board.chars().filter(|&c| c == '\\').count();
+      let clues = tokens(&line).len() - 1;
+
+      return Err(ParseError::Invalid { line: line_number, column: clue_start + 1, token: clue.to_string(), reason: ParseReason::ClueCountMismatch { cells, clues } });
+    }
+
+    // every row has to be as long as the first one
+    let expected = grid[0].len();
+    let found = grid.last().unwrap().len();
+
+    if found != expected {
+      return Err(ParseError::Invalid { line: line_number, column: board_start + 1, token: board.to_string(), reason: ParseReason::RaggedRow { expected, found } });
+    }
+  }
+
+  Ok(Puzzle { width: grid.first().map_or(0, |row| row.len()), height: grid.len(), grid, parents, children })
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being written
+///
+/// Description:
+/// - Writes each row of the grid as a board of - for black cells, \ for clue cells and x for white cells,
+///   followed by the clue of each \ cell as down\across, where - stands for a direction without a run
+/// - This is the reverse of read_puzzle, so reading the text back gives the same puzzle
+fn write_puzzle(puzzle: &Puzzle) -> String {
+  let mut text = String::new();
+
+  for row in &puzzle.grid {
+    let mut clues = Vec::new();
+
+    for cell in row {
+      if cell.is_blank() {
+        text.push('-');
+      } else if cell.child.is_some() {
+        text.push('x');
+      } else {
+        let clue = |run: Option<RunId>| run.map_or("-".to_string(), |id| puzzle[id].sum.to_string());
+        text.push('\\');
+        clues.push(format!("{}\\{}", clue(cell.vert), clue(cell.horz)));
+      }
+    }
+
+    for clue in clues {
+      text.push(' ');
+      text.push_str(&clue);
+    }
+
+    text.push('\n');
+  }
+
+  text
+}
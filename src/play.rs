@@ -0,0 +1,195 @@
+//! A puzzle being played by hand, keeping the player's values and pencil marks along with their undo history
+//!
+//! The player's values are kept in Children::value of the puzzle, so the rest of the crate, i.e. [`Puzzle::next_hint`],
+//! sees the puzzle as the player left it. A front-end such as the terminal UI only has to turn key presses into calls.
+
+use crate::digits::DigitSet;
+use crate::hints::Hint;
+use crate::{CellId, Puzzle, RunId};
+
+/// One change to a white cell, as the value and pencil marks before and after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Change {
+  cell: CellId,
+  before: (u8, DigitSet),
+  after: (u8, DigitSet)
+}
+
+/// A puzzle being played
+/// * puzzle holds the player's values, a white cell that had a value when the game started is given and cannot be changed
+/// * solution is the solved puzzle cells are revealed from, None if the puzzle has no solution
+/// * marks are the pencil marks of each white cell, in the same order as the Children of the puzzle
+/// * undo and redo are the changes that can be taken back or made again, the latest last
+#[derive(Debug, Clone)]
+pub struct Game {
+  puzzle: Puzzle,
+  solution: Option<Puzzle>,
+  given: Vec<bool>,
+  marks: Vec<DigitSet>,
+  undo: Vec<Change>,
+  redo: Vec<Change>
+}
+
+impl Game {
+  /// Parameters:
+  /// - puzzle: The puzzle being played, set up by Puzzle::load, any values it already has are given to the player
+  ///
+  /// Description:
+  /// - Starts the game with no pencil marks, solving a copy of the puzzle so cells can be revealed
+  pub fn new(puzzle: Puzzle) -> Game {
+    let mut solution = puzzle.clone();
+    let solution = (solution.solve() && solution.is_solved()).then_some(solution);
+    let given = puzzle.children.iter().map(|child| child.value != 0).collect();
+    let marks = vec![DigitSet::EMPTY; puzzle.children.len()];
+
+    Game { puzzle, solution, given, marks, undo: Vec::new(), redo: Vec::new() }
+  }
+
+  /// Returns the puzzle with the player's values
+  pub fn puzzle(&self) -> &Puzzle {
+    &self.puzzle
+  }
+
+  /// Returns the solved puzzle, None if the puzzle has no solution
+  pub fn solution(&self) -> Option<&Puzzle> {
+    self.solution.as_ref()
+  }
+
+  /// Returns true if the cell had its value when the game started
+  pub fn is_given(&self, cell: CellId) -> bool {
+    self.given[cell.0]
+  }
+
+  /// Returns the pencil marks of the cell
+  pub fn marks(&self, cell: CellId) -> DigitSet {
+    self.marks[cell.0]
+  }
+
+  /// Parameters:
+  /// - cell: The white cell being changed
+  /// - after: The value and pencil marks the cell is left with
+  ///
+  /// Description:
+  /// - Changes the cell and remembers the change so it can be undone, which also forgets every change that was undone
+  /// - Returns false without changing anything if the cell is given or would be left the same
+  fn change(&mut self, cell: CellId, after: (u8, DigitSet)) -> bool {
+    let before = (self.puzzle[cell].value, self.marks[cell.0]);
+
+    if self.is_given(cell) || before == after {
+      return false;
+    }
+
+    self.set(cell, after);
+    self.undo.push(Change { cell, before, after });
+    self.redo.clear();
+    true
+  }
+
+  /// Sets the value and pencil marks of the cell without remembering the change
+  fn set(&mut self, cell: CellId, (value, marks): (u8, DigitSet)) {
+    self.puzzle[cell].value = value;
+    self.marks[cell.0] = marks;
+  }
+
+  /// Parameters:
+  /// - cell: The white cell being filled in
+  /// - value: The digit filled in, or 0 to empty the cell
+  ///
+  /// Description:
+  /// - Fills in or empties the cell, keeping its pencil marks for when it is emptied again
+  /// - Returns false if the cell is given or already has the value
+  pub fn set_value(&mut self, cell: CellId, value: u8) -> bool {
+    self.change(cell, (value, self.marks[cell.0]))
+  }
+
+  /// Parameters:
+  /// - cell: The white cell being marked
+  /// - digit: The pencil mark being added or taken away
+  ///
+  /// Description:
+  /// - Adds the pencil mark to the cell, or takes it away if the cell already has it
+  /// - Returns false if the cell is given
+  pub fn toggle_mark(&mut self, cell: CellId, digit: u8) -> bool {
+    let mut marks = self.marks[cell.0];
+
+    if marks.contains(digit) {
+      marks.remove(digit);
+    } else {
+      marks.insert(digit);
+    }
+
+    self.change(cell, (self.puzzle[cell].value, marks))
+  }
+
+  /// Takes back the latest change, returning the cell it was made to or None if there is nothing to undo
+  pub fn undo(&mut self) -> Option<CellId> {
+    let change = self.undo.pop()?;
+    self.set(change.cell, change.before);
+    self.redo.push(change);
+    Some(change.cell)
+  }
+
+  /// Makes the latest undone change again, returning the cell it was made to or None if there is nothing to redo
+  pub fn redo(&mut self) -> Option<CellId> {
+    let change = self.redo.pop()?;
+    self.set(change.cell, change.after);
+    self.undo.push(change);
+    Some(change.cell)
+  }
+
+  /// Parameters:
+  /// - cell: The white cell being revealed
+  ///
+  /// Description:
+  /// - Fills in the cell with its value from the solution, as a change that can be undone
+  /// - Returns false if the puzzle has no solution, the cell is given or it already has the right value
+  pub fn reveal(&mut self, cell: CellId) -> bool {
+    match self.solution.as_ref().map(|solution| solution[cell].value) {
+      Some(value) => self.set_value(cell, value),
+      None => false
+    }
+  }
+
+  /// Returns the easiest deduction the player can make from the values filled in so far, see [`Puzzle::next_hint`]
+  pub fn hint(&self) -> Option<Hint> {
+    self.puzzle.next_hint()
+  }
+
+  /// Returns true if the filled in digits of the run add up to more than its sum,
+  /// or every cell of the run is filled in and they do not add up to its sum
+  fn breaks_sum(&self, run: RunId) -> bool {
+    let parent = &self.puzzle[run];
+    let values: Vec<u32> = parent.children.iter().map(|&id| self.puzzle[id].value as u32).filter(|&value| value != 0).collect();
+    let total: u32 = values.iter().sum();
+
+    total > parent.sum as u32 || (values.len() == parent.children.len() && total != parent.sum as u32)
+  }
+
+  /// Parameters:
+  /// - cell: The white cell being checked
+  ///
+  /// Description:
+  /// - Returns true if the cell is filled in and breaks a rule, either a sibling has the same digit
+  ///   or one of its runs goes over its sum or is full without adding up to it
+  pub fn is_conflicting(&self, cell: CellId) -> bool {
+    let child = &self.puzzle[cell];
+
+    if child.value == 0 {
+      return false;
+    }
+
+    let (down, across) = child.parents;
+
+    child.siblings.iter().any(|&sibling| self.puzzle[sibling].value == child.value) || self.breaks_sum(down) || self.breaks_sum(across)
+  }
+
+  /// Returns every filled in cell that breaks a rule, see [`Game::is_conflicting`]
+  pub fn conflicts(&self) -> Vec<CellId> {
+    self.puzzle.cell_ids().filter(|&cell| self.is_conflicting(cell)).collect()
+  }
+
+  /// Returns true if every cell is filled in without breaking a rule
+  pub fn is_solved(&self) -> bool {
+    self.puzzle.is_solved()
+  }
+}
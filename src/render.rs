@@ -0,0 +1,122 @@
+//! Drawing puzzles as text
+
+use crate::digits::DigitSet;
+use crate::{CellId, GridCell, Puzzle, RunId};
+
+/// The number of characters inside each cell, wide enough for the largest clue i.e. 45\45
+const CELL_WIDTH: usize = 5;
+
+/// Parameters:
+/// - width: The number of cells in each row of the grid
+///
+/// Description:
+/// - Returns the line drawn between two rows of cells, i.e. +-----+-----+
+fn border(width: usize) -> String {
+  let mut line = String::from("+");
+
+  for _ in 0..width {
+    line.push_str(&"-".repeat(CELL_WIDTH));
+    line.push('+');
+  }
+
+  line
+}
+
+/// Parameters:
+/// - puzzle: The puzzle the cell belongs to
+/// - cell: A cell from the grid that has at least one clue
+///
+/// Description:
+/// - Returns the clue in the same vert\horz order as the puzzle file, with a missing clue left blank i.e. 17\11 or  4\
+fn clue(puzzle: &Puzzle, cell: &GridCell) -> String {
+  let sum = |parent_id: Option<RunId>| parent_id.map_or(String::new(), |id| puzzle[id].sum.to_string());
+
+  format!("{:>2}\\{:<2}", sum(cell.vert), sum(cell.horz))
+}
+
+/// Parameters:
+/// - candidates: The digits being drawn, i.e. the possible values of a Child
+/// - line: Which of the three candidate lines to draw, 0 for 1 2 3, 1 for 4 5 6 and 2 for 7 8 9
+///
+/// Description:
+/// - Returns one line of the digits, with a digit that is not one of them left blank
+fn candidate_line(candidates: DigitSet, line: usize) -> String {
+  let digits: Vec<String> = (1..=3u8)
+    .map(|offset| line as u8 * 3 + offset)
+    .map(|digit| if candidates.contains(digit) { digit.to_string() } else { " ".to_string() })
+    .collect();
+
+  digits.join(" ")
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being drawn, with or without values filled in
+/// - show_candidates: If true each empty white cell lists its possible values, making every cell three lines tall
+///
+/// Description:
+/// - Draws the puzzle as a boxed grid, with clue cells shown as vert\horz, other black cells filled with #,
+///   and white cells showing their value if it has been found
+pub fn render(puzzle: &Puzzle, show_candidates: bool) -> String {
+  let possible_values = |id: CellId| puzzle[id].possible_values;
+  let candidates: Option<&dyn Fn(CellId) -> DigitSet> = if show_candidates { Some(&possible_values) } else { None };
+
+  render_with(puzzle, candidates, &|_, text| text)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being drawn, with or without values filled in
+/// - candidates: Returns the digits listed in an empty white cell, making every cell three lines tall, None lists nothing
+/// - highlight: Called with each line of a white cell, padded to the width of the cell, and returns the line as it is drawn,
+///   i.e. wrapped in ANSI escape codes to show a cursor
+///
+/// Description:
+/// - Draws the puzzle the same way as [`render`], for front-ends such as the terminal UI that draw their own
+///   candidates or mark some of the white cells
+pub fn render_with(puzzle: &Puzzle, candidates: Option<&dyn Fn(CellId) -> DigitSet>, highlight: &dyn Fn(CellId, String) -> String) -> String {
+  let height = if candidates.is_some() { 3 } else { 1 };
+  let mut output = border(puzzle.width);
+
+  for row in &puzzle.grid {
+    for line in 0..height {
+      // the middle line of a cell is where its value or clue is written
+      let middle = line == height / 2;
+      output.push_str("\n|");
+
+      for cell in row {
+        let text = if let Some(child_id) = cell.child {
+          let value = puzzle[child_id].value;
+
+          let text = if value != 0 {
+            if middle { format!("{:^width$}", value, width = CELL_WIDTH) } else { String::new() }
+          } else if let Some(candidates) = candidates {
+            candidate_line(candidates(child_id), line)
+          } else {
+            String::new()
+          };
+
+          highlight(child_id, format!("{:<width$}", text, width = CELL_WIDTH))
+        } else if cell.is_blank() {
+          "#".repeat(CELL_WIDTH)
+        } else if middle {
+          clue(puzzle, cell)
+        } else {
+          String::new()
+        };
+
+        // a highlighted line is already as wide as the cell, its escape codes are not padded
+        if cell.child.is_some() {
+          output.push_str(&text);
+          output.push('|');
+        } else {
+          output.push_str(&format!("{:<width$}|", text, width = CELL_WIDTH));
+        }
+      }
+    }
+
+    output.push('\n');
+    output.push_str(&border(puzzle.width));
+  }
+
+  output.push('\n');
+  output
+}
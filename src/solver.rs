@@ -0,0 +1,435 @@
+//! Constraint propagation and backtracking search for solving puzzles
+
+use std::fmt;
+
+use crate::digits::DigitSet;
+use crate::techniques::{cell_name, run_name};
+use crate::{CellId, Parents, Puzzle, RunId};
+
+/// The most cells a run can have, one for each digit
+const MAX_RUN_LENGTH: usize = 9;
+
+/// A step the solver took, reported to the trace of [`solve_traced`] as it happens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceStep {
+  /// A pass over the runs crossed these combinations off them
+  Combinations { removed: Vec<(RunId, Vec<DigitSet>)> },
+  /// A pass over the white cells shrank their possible values, from the first set to the second
+  PossibleValues { shrunk: Vec<(CellId, DigitSet, DigitSet)> },
+  /// A run ran out of combinations or a cell ran out of possible values, so the current guesses are wrong
+  Contradiction,
+  /// Propagation stalled so the value is tried in the cell, which has the fewest possible values (options) left
+  Guess { cell: CellId, value: u8, options: DigitSet },
+  /// The guessed value led to a contradiction, so the puzzle goes back to how it was before the guess
+  Backtrack { cell: CellId, value: u8 },
+  /// Every cell is filled in
+  Solved
+}
+
+impl TraceStep {
+  /// Parameters:
+  /// - puzzle: The puzzle the step was taken in
+  ///
+  /// Description:
+  /// - Returns a one line description of the step, naming runs by their clue and cells by their position
+  pub fn describe(&self, puzzle: &Puzzle) -> String {
+    match self {
+      TraceStep::Combinations { removed } => {
+        let runs: Vec<String> = removed.iter().map(|(run, combinations)| {
+          let combinations: Vec<String> = combinations.iter().map(|combo| combo.to_string()).collect();
+          format!("{} cannot be {}", run_name(puzzle, *run), combinations.join(" or "))
+        }).collect();
+
+        format!("combinations: {}", runs.join("; "))
+      },
+      TraceStep::PossibleValues { shrunk } => {
+        let cells: Vec<String> = shrunk.iter().map(|(cell, before, after)| format!("{} {} to {}", cell_name(puzzle, *cell), before, after)).collect();
+        format!("possible values: {}", cells.join("; "))
+      },
+      TraceStep::Contradiction => "contradiction: a run has no combinations or a cell has no possible values left".to_string(),
+      TraceStep::Guess { cell, value, options } => format!("guess: {} in {}, out of {}", value, cell_name(puzzle, *cell), options),
+      TraceStep::Backtrack { cell, value } => format!("backtrack: {} in {} leads to a contradiction", value, cell_name(puzzle, *cell)),
+      TraceStep::Solved => "solved".to_string()
+    }
+  }
+}
+
+/// Called with each step the solver takes and the puzzle as it is after the step
+pub type Trace<'a> = dyn FnMut(&TraceStep, &Puzzle) + 'a;
+
+/// Parameters:
+/// - options: The possible values of each child in a run, in the same order as the run's children
+/// - remaining: The digits of the combination being tested that have not been given to an earlier child yet
+///
+/// Description:
+/// - Checks if the digits of the combination can be handed out to the children so that each child gets
+///   a different digit which is still one of its possible values
+fn has_assignment(options: &[DigitSet], remaining: DigitSet) -> bool {
+  let Some((first, rest)) = options.split_first() else {
+    return true;
+  };
+
+  (*first & remaining).iter().any(|digit| has_assignment(rest, remaining - DigitSet::single(digit)))
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///
+/// Description:
+/// - Removes every combination from each Parent that can no longer be placed in its children's possible values
+/// - Returns true if any combination was removed
+fn prune_combinations(puzzle: &mut Puzzle) -> bool {
+  let Puzzle { parents, children, .. } = puzzle;
+  let mut changed = false;
+
+  for parent in parents.iter_mut() {
+    // a run is never longer than 9 cells, so its options fit on the stack
+    let mut options = [DigitSet::EMPTY; MAX_RUN_LENGTH];
+
+    for (option, &id) in options.iter_mut().zip(&parent.children) {
+      *option = children[id].possible_values;
+    }
+
+    let options = &options[..parent.children.len()];
+    let before = parent.combinations.len();
+
+    parent.combinations.retain(|&combo| has_assignment(options, combo));
+
+    changed |= parent.combinations.len() != before;
+  }
+
+  changed
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///
+/// Description:
+/// - Shrinks each Child's possible values to the digits that still appear in a combination of both of its parents
+///   and that have not already been placed in one of its siblings, a Child with one possible value left is filled in
+/// - Returns true if any possible value was removed
+fn prune_possible_values(puzzle: &mut Puzzle) -> bool {
+  let mut changed = false;
+
+  for child_id in puzzle.cell_ids() {
+    let Puzzle { parents, children, .. } = &*puzzle;
+    let child = &children[child_id];
+
+    let in_combinations = |parent: &Parents| parent.combinations.iter().fold(DigitSet::EMPTY, |values, &combo| values | combo);
+    let placed_by_siblings: DigitSet = child.siblings.iter().map(|&sibling| children[sibling].value).filter(|&value| value != 0).collect();
+
+    let mut remaining = child.possible_values & in_combinations(&parents[child.parents.0]) & in_combinations(&parents[child.parents.1]);
+
+    if child.value != 0 {
+      remaining &= DigitSet::single(child.value);
+    }
+
+    remaining -= placed_by_siblings;
+
+    let child = &mut puzzle[child_id];
+
+    if remaining != child.possible_values {
+      child.possible_values = remaining;
+      changed = true;
+    }
+
+    if let Some(value) = child.possible_values.single_value() {
+      child.value = value;
+    }
+  }
+
+  changed
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///      - This puzzle must have already been set up by Puzzle::load
+///
+/// Description:
+/// - Alternates between pruning the Parents' combinations and the Children's possible values until neither changes
+/// - Returns false if a Parent runs out of combinations or a Child runs out of possible values,
+///   meaning the current grid cannot be solved
+pub fn propagate(puzzle: &mut Puzzle) -> bool {
+  propagate_with(puzzle, None)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+/// - trace: Told about every pass that changes the puzzle, the state before each pass is only kept when there is one
+///
+/// Description:
+/// - Propagates the same way as [`propagate`], reporting what each pass removed and any contradiction to the trace
+fn propagate_with(puzzle: &mut Puzzle, mut trace: Option<&mut Trace<'_>>) -> bool {
+  loop {
+    let combinations_before: Option<Vec<Vec<DigitSet>>> = trace.as_ref().map(|_| puzzle.parents.iter().map(|parent| parent.combinations.clone()).collect());
+    let combinations_changed = prune_combinations(puzzle);
+
+    if let (Some(trace), Some(before)) = (trace.as_deref_mut(), combinations_before.filter(|_| combinations_changed)) {
+      let removed = puzzle.run_ids().zip(before)
+        .map(|(id, before)| (id, before.into_iter().filter(|combo| !puzzle[id].combinations.contains(combo)).collect::<Vec<_>>()))
+        .filter(|(_, removed)| !removed.is_empty())
+        .collect();
+
+      trace(&TraceStep::Combinations { removed }, puzzle);
+    }
+
+    let values_before: Option<Vec<DigitSet>> = trace.as_ref().map(|_| puzzle.children.iter().map(|child| child.possible_values).collect());
+    let values_changed = prune_possible_values(puzzle);
+
+    if let (Some(trace), Some(before)) = (trace.as_deref_mut(), values_before.filter(|_| values_changed)) {
+      let shrunk = puzzle.cell_ids().zip(before)
+        .filter(|&(id, before)| puzzle[id].possible_values != before)
+        .map(|(id, before)| (id, before, puzzle[id].possible_values))
+        .collect();
+
+      trace(&TraceStep::PossibleValues { shrunk }, puzzle);
+    }
+
+    if puzzle.parents.iter().any(|parent| parent.combinations.is_empty()) || puzzle.children.iter().any(|child| child.possible_values.is_empty()) {
+      if let Some(trace) = trace.as_deref_mut() {
+        trace(&TraceStep::Contradiction, puzzle);
+      }
+
+      return false;
+    }
+
+    if !combinations_changed && !values_changed {
+      return true;
+    }
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being searched, it is owned so each guess can work on its own copy
+/// - guesses: The number of values that may still be guessed, the search stops once it runs out
+/// - found: Called with every solution found, it returns false once no more solutions are wanted
+/// - trace: Told about every step of the search, if there is one
+///
+/// Description:
+/// - Constraint propagation is run first, and when it stalls the empty Child with the fewest possible values
+///   is guessed and each of its possible values is searched in turn
+/// - Returns false if the search was stopped by found or ran out of guesses
+fn search(mut puzzle: Puzzle, guesses: &mut usize, found: &mut dyn FnMut(Puzzle) -> bool, mut trace: Option<&mut Trace<'_>>) -> bool {
+  if !propagate_with(&mut puzzle, trace.as_deref_mut()) {
+    return true;
+  }
+
+  // find the empty child with the fewest possible values, if every child is filled then the puzzle is solved
+  let guess = puzzle.cell_ids()
+    .filter(|&id| puzzle[id].value == 0)
+    .min_by_key(|&id| puzzle[id].possible_values.len())
+    .map(|id| (id, puzzle[id].possible_values));
+
+  let Some((child_id, values)) = guess else {
+    if let Some(trace) = trace.as_deref_mut() {
+      trace(&TraceStep::Solved, &puzzle);
+    }
+
+    return found(puzzle);
+  };
+
+  // try each possible value on its own copy so a failed guess is simply thrown away
+  for value in values {
+    if *guesses == 0 {
+      return false;
+    }
+
+    *guesses -= 1;
+
+    let mut attempt = puzzle.clone();
+    attempt[child_id].value = value;
+    attempt[child_id].possible_values = DigitSet::single(value);
+
+    if let Some(trace) = trace.as_deref_mut() {
+      trace(&TraceStep::Guess { cell: child_id, value, options: values }, &attempt);
+    }
+
+    if !search(attempt, guesses, found, trace.as_deref_mut()) {
+      return false;
+    }
+
+    if let Some(trace) = trace.as_deref_mut() {
+      trace(&TraceStep::Backtrack { cell: child_id, value }, &puzzle);
+    }
+  }
+
+  true
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///      - This puzzle must have already been set up by Puzzle::load
+/// - trace: Called with each step the solver takes, if it is given
+///
+/// Description:
+/// - Searches for the first solution and fills the puzzle in with it, shared by [`solve`] and [`solve_traced`]
+/// - Returns true if the puzzle was solved, otherwise the puzzle is left as it was
+fn solve_with(puzzle: &mut Puzzle, trace: Option<&mut Trace<'_>>) -> bool {
+  let mut solution = None;
+  let mut guesses = usize::MAX;
+
+  search(puzzle.clone(), &mut guesses, &mut |found| {
+    solution = Some(found);
+    false
+  }, trace);
+
+  match solution {
+    Some(solution) => {
+      *puzzle = solution;
+      true
+    },
+    None => false
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///      - This puzzle must have already been set up by Puzzle::load
+///
+/// Description:
+/// - Fills in the value of every Child so that each Parent's children add up to its sum without repeating a value
+/// - Returns true if the puzzle was solved, otherwise the puzzle is left as it was
+pub fn solve(puzzle: &mut Puzzle) -> bool {
+  solve_with(puzzle, None)
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///      - This puzzle must have already been set up by Puzzle::load
+/// - trace: Called with each step the solver takes and the puzzle as it is after the step
+///
+/// Description:
+/// - Solves the puzzle the same way as [`solve`], reporting every pass of propagation that changes the puzzle,
+///   every guess and every guess that is taken back, so a hard puzzle or a solver bug can be followed step by step
+/// - Returns true if the puzzle was solved, otherwise the puzzle is left as it was
+pub fn solve_traced(puzzle: &mut Puzzle, trace: &mut Trace<'_>) -> bool {
+  solve_with(puzzle, Some(trace))
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///      - This puzzle must have already been set up by Puzzle::load
+/// - limit: The number of solutions after which the search stops
+///
+/// Description:
+/// - Returns up to limit solved copies of the puzzle
+/// - A limit of 2 is enough to tell an unsolvable, a unique and a multiple solution puzzle apart
+pub fn find_solutions(puzzle: &Puzzle, limit: usize) -> Vec<Puzzle> {
+  let mut solutions = Vec::new();
+
+  if limit == 0 {
+    return solutions;
+  }
+
+  let mut guesses = usize::MAX;
+
+  search(puzzle.clone(), &mut guesses, &mut |found| {
+    solutions.push(found);
+    solutions.len() < limit
+  }, None);
+
+  solutions
+}
+
+/// The number of solutions a puzzle has, counted up to a limit
+/// * count stops at the limit, so a count equal to the limit means there may be even more solutions
+/// * examples are the first two solutions found, so there are fewer when the puzzle has fewer solutions
+#[derive(Debug, Clone)]
+pub struct SolutionCount {
+  pub count: usize,
+  pub limit: usize,
+  pub examples: Vec<Puzzle>
+}
+
+impl SolutionCount {
+  /// Returns true if the puzzle has exactly one solution
+  pub fn is_unique(&self) -> bool {
+    self.count == 1
+  }
+
+  /// Returns true if the search stopped at the limit before every solution was found
+  pub fn limit_reached(&self) -> bool {
+    self.count >= self.limit
+  }
+
+  /// Returns every white cell whose value differs between the two example solutions, in reading order
+  /// - The list is empty unless the puzzle has at least two solutions
+  pub fn differences(&self) -> Vec<CellId> {
+    match self.examples.as_slice() {
+      [first, second, ..] => first.cell_ids().filter(|&id| first[id].value != second[id].value).collect(),
+      _ => Vec::new()
+    }
+  }
+}
+
+/// Shows the count the way it is reported to a puzzle setter, i.e. 0, 1 or 2 or more
+impl fmt::Display for SolutionCount {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.limit_reached() && self.count > 1 {
+      write!(f, "{} or more", self.count)
+    } else {
+      write!(f, "{}", self.count)
+    }
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being counted
+///      - This puzzle must have already been set up by Puzzle::load
+/// - limit: The number of solutions after which the search stops, it is raised to 2 if it is lower
+///   so a unique puzzle can always be told apart from one with several solutions
+///
+/// Description:
+/// - Searches for every solution of the puzzle up to the limit, keeping the first two as examples
+pub fn count_solutions(puzzle: &Puzzle, limit: usize) -> SolutionCount {
+  // usize::MAX guesses could never all be made, so the search always finishes and only stops early at the limit
+  count_solutions_within(puzzle, limit, usize::MAX).expect("the search ran out of guesses")
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being counted
+///      - This puzzle must have already been set up by Puzzle::load
+/// - limit: The number of solutions after which the search stops, it is raised to 2 if it is lower
+/// - max_guesses: The most values the search may guess before it gives up
+///
+/// Description:
+/// - Counts the solutions the same way as [`count_solutions`], for callers that would rather give up on a puzzle
+///   than wait for a long search, i.e. the generator
+/// - Returns None if the search ran out of guesses before it could finish
+pub fn count_solutions_within(puzzle: &Puzzle, limit: usize, max_guesses: usize) -> Option<SolutionCount> {
+  let limit = limit.max(2);
+  let mut count = 0;
+  let mut examples = Vec::new();
+  let mut guesses = max_guesses;
+
+  let finished = search(puzzle.clone(), &mut guesses, &mut |found| {
+    count += 1;
+
+    if examples.len() < 2 {
+      examples.push(found);
+    }
+
+    count < limit
+  }, None);
+
+  // stopping at the limit is not running out of guesses
+  if !finished && count < limit {
+    return None;
+  }
+
+  Some(SolutionCount { count, limit, examples })
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being checked
+///
+/// Description:
+/// - Checks that every Child has a value and that each Parent's children add up to its sum without a repeated value
+pub fn is_solved(puzzle: &Puzzle) -> bool {
+  puzzle.parents.iter().all(|parent| {
+    let filled = parent.children.iter().all(|&id| puzzle[id].value != 0);
+    let values: DigitSet = parent.children.iter().map(|&id| puzzle[id].value).filter(|&value| value != 0).collect();
+
+    filled && values.len() == parent.children.len() && values.sum() == parent.sum as u32
+  })
+}
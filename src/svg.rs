@@ -0,0 +1,140 @@
+//! Drawing puzzles as SVG images, for printing or putting on a web page
+
+use std::fmt::Write;
+
+use crate::{Children, GridCell, Puzzle, RunId};
+
+/// The width of the line drawn around the whole grid, the lines between cells are half as wide
+const BORDER_WIDTH: f64 = 2.0;
+
+/// What an SVG image of a puzzle shows
+/// * cell_size is the width and height of each cell in pixels, everything else in the image is scaled to it
+/// * show_values draws the value of every white cell that has one, i.e. to draw the solution of a solved puzzle
+/// * show_candidates draws the possible values of every empty white cell as small pencil marks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvgOptions {
+  pub cell_size: u32,
+  pub show_values: bool,
+  pub show_candidates: bool
+}
+
+impl Default for SvgOptions {
+  fn default() -> Self {
+    SvgOptions { cell_size: 40, show_values: true, show_candidates: false }
+  }
+}
+
+/// Rounds a position or size to a tenth of a pixel, which is finer than can be seen, to keep the image short
+fn round(value: f64) -> f64 {
+  (value * 10.0).round() / 10.0
+}
+
+/// Parameters:
+/// - svg: The image being drawn
+/// - x, y: Where the middle of the text goes
+/// - size: The height of the font
+/// - fill: The colour of the text
+/// - text: The text being drawn
+///
+/// Description:
+/// - Adds the text centred on the point
+fn text(svg: &mut String, (x, y): (f64, f64), size: f64, fill: &str, text: &str) {
+  let _ = writeln!(svg, r##"    <text x="{}" y="{}" font-size="{}" fill="{}">{}</text>"##, round(x), round(y), round(size), fill, text);
+}
+
+/// Parameters:
+/// - svg: The image being drawn
+/// - puzzle: The puzzle the cell belongs to
+/// - cell: A cell from the grid that has at least one clue
+/// - x, y: The top left corner of the cell
+/// - size: The width and height of the cell
+///
+/// Description:
+/// - Puts the down sum below the diagonal of the cell and the across sum above it,
+///   the same vert\horz order as the puzzle file
+fn clue(svg: &mut String, puzzle: &Puzzle, cell: &GridCell, (x, y): (f64, f64), size: f64) {
+  let sum = |parent_id: Option<RunId>| parent_id.map(|id| puzzle[id].sum.to_string());
+
+  if let Some(down) = sum(cell.vert) {
+    text(svg, (x + size * 0.3, y + size * 0.72), size * 0.3, "#fff", &down);
+  }
+
+  if let Some(across) = sum(cell.horz) {
+    text(svg, (x + size * 0.7, y + size * 0.3), size * 0.3, "#fff", &across);
+  }
+}
+
+/// Parameters:
+/// - svg: The image being drawn
+/// - child: The Child whose candidates are being drawn
+/// - x, y: The top left corner of the cell
+/// - size: The width and height of the cell
+///
+/// Description:
+/// - Draws each possible value in its own spot of a 3 by 3 block, 1 2 3 along the top down to 7 8 9 along the bottom,
+///   so a digit is always found in the same place
+fn candidates(svg: &mut String, child: &Children, (x, y): (f64, f64), size: f64) {
+  for digit in child.possible_values {
+    let (row, column) = ((digit - 1) / 3, (digit - 1) % 3);
+    let centre = (x + size * (column as f64 + 0.5) / 3.0, y + size * (row as f64 + 0.5) / 3.0);
+
+    text(svg, centre, size * 0.22, "#555", &digit.to_string());
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being drawn, with or without values filled in
+/// - options: The size of the cells and whether values and candidates are drawn
+///
+/// Description:
+/// - Draws the puzzle as an SVG image, black cells are filled in, clue cells are split along their diagonal
+///   with their sums in the matching corners and white cells show their value or candidates if asked to
+pub fn render_svg(puzzle: &Puzzle, options: &SvgOptions) -> String {
+  let size = options.cell_size as f64;
+  let (width, height) = (puzzle.width as f64 * size, puzzle.height as f64 * size);
+  let mut svg = String::new();
+
+  let _ = writeln!(svg, r##"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"##, width, height, width, height);
+  let _ = writeln!(svg, r##"  <g stroke="#000" stroke-width="{}">"##, BORDER_WIDTH / 2.0);
+
+  for (row, cells) in puzzle.grid.iter().enumerate() {
+    for (column, cell) in cells.iter().enumerate() {
+      let fill = if cell.child.is_some() { "#fff" } else { "#000" };
+      let (x, y) = (column as f64 * size, row as f64 * size);
+      let _ = writeln!(svg, r##"    <rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"##, x, y, size, size, fill);
+
+      // clue cells are split from their top left to their bottom right corner
+      if cell.child.is_none() && !cell.is_blank() {
+        let _ = writeln!(svg, r##"    <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#fff"/>"##, x, y, x + size, y + size);
+      }
+    }
+  }
+
+  let _ = writeln!(svg, r##"    <rect x="0" y="0" width="{}" height="{}" fill="none" stroke-width="{}"/>"##, width, height, BORDER_WIDTH);
+  let _ = writeln!(svg, "  </g>");
+  let _ = writeln!(svg, r##"  <g font-family="sans-serif" text-anchor="middle" dominant-baseline="central">"##);
+
+  for (row, cells) in puzzle.grid.iter().enumerate() {
+    for (column, cell) in cells.iter().enumerate() {
+      let corner = (column as f64 * size, row as f64 * size);
+
+      match cell.child {
+        Some(child_id) => {
+          let child = &puzzle[child_id];
+
+          if child.value != 0 && options.show_values {
+            text(&mut svg, (corner.0 + size / 2.0, corner.1 + size / 2.0), size * 0.6, "#000", &child.value.to_string());
+          } else if child.value == 0 && options.show_candidates {
+            candidates(&mut svg, child, corner, size);
+          }
+        },
+        None if !cell.is_blank() => clue(&mut svg, puzzle, cell, corner, size),
+        None => {}
+      }
+    }
+  }
+
+  let _ = writeln!(svg, "  </g>");
+  let _ = writeln!(svg, "</svg>");
+  svg
+}
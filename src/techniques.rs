@@ -0,0 +1,417 @@
+//! Solving puzzles one named human technique at a time, keeping a log of every step
+
+use std::fmt;
+
+use crate::digits::DigitSet;
+use crate::{CellId, Direction, Puzzle, RunId};
+
+/// The techniques a person uses to solve a puzzle, from the easiest to the hardest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Technique {
+  /// An empty cell that only has one candidate left, i.e. after its siblings were filled in
+  NakedSingle,
+  /// A run cannot use a combination that leaves out a digit already placed in it, i.e. 10 in 3 with a 7 placed is {1,2,7}
+  PlacedDigit,
+  /// A run that only has one combination left can only use the digits of that combination, i.e. 3 in 2 is {1,2}
+  UniqueCombination,
+  /// A cell can only use the digits that appear in a combination of both its down run and its across run
+  DigitIntersection,
+  /// A digit every combination of a run needs that only one cell of the run can take
+  HiddenSingle,
+  /// A digit every combination of a run needs has to fit somewhere else in the run, i.e. every 17-2 combination contains 9
+  RequiredDigit,
+  /// A combination of a run that cannot be spread over the candidates of the run's cells
+  CombinationElimination
+}
+
+/// Every technique in the order they are tried
+pub const TECHNIQUES: [Technique; 7] = [
+  Technique::NakedSingle,
+  Technique::PlacedDigit,
+  Technique::UniqueCombination,
+  Technique::DigitIntersection,
+  Technique::HiddenSingle,
+  Technique::RequiredDigit,
+  Technique::CombinationElimination
+];
+
+impl fmt::Display for Technique {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Technique::NakedSingle => write!(f, "naked single"),
+      Technique::PlacedDigit => write!(f, "placed digit"),
+      Technique::UniqueCombination => write!(f, "unique combination"),
+      Technique::DigitIntersection => write!(f, "digit intersection"),
+      Technique::HiddenSingle => write!(f, "hidden single"),
+      Technique::RequiredDigit => write!(f, "required digit"),
+      Technique::CombinationElimination => write!(f, "combination elimination")
+    }
+  }
+}
+
+/// One deduction made while solving
+/// * runs and cells are the Parents and Children the deduction was made from
+/// * digits are the digits the deduction is about, i.e. the only combination, the hidden single or the required digits
+/// * removed_candidates are the possible values taken off each Child
+/// * removed_combinations are the combinations taken off each Parent
+/// * placed are the Children filled in because they only had one possible value left after the step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+  pub technique: Technique,
+  pub runs: Vec<RunId>,
+  pub cells: Vec<CellId>,
+  pub digits: DigitSet,
+  pub removed_candidates: Vec<(CellId, DigitSet)>,
+  pub removed_combinations: Vec<(RunId, DigitSet)>,
+  pub placed: Vec<(CellId, u8)>
+}
+
+impl Step {
+  /// Returns a new step for the technique that has not removed or placed anything yet
+  fn new(technique: Technique, runs: Vec<RunId>, cells: Vec<CellId>, digits: DigitSet) -> Step {
+    Step { technique, runs, cells, digits, removed_candidates: Vec::new(), removed_combinations: Vec::new(), placed: Vec::new() }
+  }
+
+  /// Adds digits to the candidates removed from a Child, merging them with any already removed from it
+  fn remove_candidates(&mut self, cell: CellId, digits: DigitSet) {
+    if digits.is_empty() {
+      return;
+    }
+
+    match self.removed_candidates.iter_mut().find(|(id, _)| *id == cell) {
+      Some((_, removed)) => *removed |= digits,
+      None => self.removed_candidates.push((cell, digits))
+    }
+  }
+
+  /// Parameters:
+  /// - puzzle: The puzzle the step was found in
+  ///
+  /// Description:
+  /// - Returns a one line description of the step, naming runs by their clue and cells by their position
+  pub fn describe(&self, puzzle: &Puzzle) -> String {
+    let run = |index: usize| self.runs.get(index).map_or(String::new(), |&id| run_name(puzzle, id));
+    let cell = |index: usize| self.cells.get(index).map_or(String::new(), |&id| cell_name(puzzle, id));
+
+    let mut description = match self.technique {
+      Technique::NakedSingle => format!("{} can only be {}", cell(0), self.digits),
+      Technique::PlacedDigit => {
+        let combinations: Vec<String> = self.removed_combinations.iter().map(|(_, combo)| combo.to_string()).collect();
+        format!("{} already has {}, so it cannot be {}", run(0), self.digits, combinations.join(" or "))
+      },
+      Technique::UniqueCombination => format!("{} must be {}", run(0), self.digits),
+      Technique::DigitIntersection => format!("{} can only be {} where the {} crosses the {}", cell(0), self.digits, run(0), run(1)),
+      Technique::HiddenSingle => format!("{} can only go in {} of the {}", self.digits, cell(0), run(0)),
+      Technique::RequiredDigit => format!("{} must contain {}", run(0), self.digits),
+      Technique::CombinationElimination => {
+        let combinations: Vec<String> = self.removed_combinations.iter().map(|(_, combo)| combo.to_string()).collect();
+        format!("{} cannot be {}", run(0), combinations.join(" or "))
+      }
+    };
+
+    let removed: Vec<String> = self.removed_candidates.iter()
+      .map(|(id, digits)| format!("{} from {}", digits, cell_name(puzzle, *id)))
+      .collect();
+
+    if !removed.is_empty() {
+      description.push_str(&format!(", removing {}", removed.join(", ")));
+    }
+
+    let placed: Vec<String> = self.placed.iter().map(|(id, value)| format!("{} in {}", value, cell_name(puzzle, *id))).collect();
+
+    if !placed.is_empty() {
+      description.push_str(&format!(", placing {}", placed.join(", ")));
+    }
+
+    format!("{}: {}", self.technique, description)
+  }
+}
+
+/// Returns the name of a run the way a person would point it out, i.e. row clue 16 over 2 cells at row 3, column 1
+pub(crate) fn run_name(puzzle: &Puzzle, id: RunId) -> String {
+  let parent = &puzzle[id];
+  let (row, column) = parent.head;
+  let line = if parent.direction == Direction::Across { "row" } else { "column" };
+
+  format!("{} clue {} over {} cells at row {}, column {}", line, parent.sum, parent.children.len(), row + 1, column + 1)
+}
+
+/// Returns the position of a cell the way a person would point it out, i.e. row 3, column 2
+pub(crate) fn cell_name(puzzle: &Puzzle, id: CellId) -> String {
+  let (row, column) = puzzle[id].position;
+
+  format!("row {}, column {}", row + 1, column + 1)
+}
+
+/// Returns every digit used by at least one of the run's combinations
+fn combination_digits(puzzle: &Puzzle, id: RunId) -> DigitSet {
+  puzzle[id].combinations.iter().fold(DigitSet::EMPTY, |digits, &combo| digits | combo)
+}
+
+/// Returns every digit used by all of the run's combinations, which the run cannot be filled in without
+fn required_digits(puzzle: &Puzzle, id: RunId) -> DigitSet {
+  puzzle[id].combinations.iter().fold(DigitSet::ALL, |digits, &combo| digits & combo)
+}
+
+/// Parameters:
+/// - options: The possible values of each cell the digits can be placed in
+/// - digits: The digits that all have to be placed
+/// - used: Marks which of the options have already been given a digit
+///
+/// Description:
+/// - Checks if each digit can be given to a different cell that can still take it
+fn covers(options: &[DigitSet], digits: DigitSet, used: u16) -> bool {
+  let Some(digit) = digits.min() else {
+    return true;
+  };
+
+  let rest = digits - DigitSet::single(digit);
+
+  options.iter().enumerate().any(|(index, option)| used & (1 << index) == 0 && option.contains(digit) && covers(options, rest, used | (1 << index)))
+}
+
+/// Finds an empty cell with only one possible value left
+fn naked_single(puzzle: &Puzzle) -> Option<Step> {
+  puzzle.cell_ids().find_map(|cell_id| {
+    let child = &puzzle[cell_id];
+
+    (child.value == 0 && child.possible_values.len() == 1).then(|| Step::new(Technique::NakedSingle, Vec::new(), vec![cell_id], child.possible_values))
+  })
+}
+
+/// Finds a run with a combination that leaves out one of the digits already placed in the run
+fn placed_digit(puzzle: &Puzzle) -> Option<Step> {
+  puzzle.run_ids().find_map(|run_id| {
+    let parent = &puzzle[run_id];
+    let cells: Vec<CellId> = parent.children.iter().copied().filter(|&id| puzzle[id].value != 0).collect();
+    let placed: DigitSet = cells.iter().map(|&id| puzzle[id].value).collect();
+
+    let removed: Vec<(RunId, DigitSet)> = parent.combinations.iter()
+      .filter(|&&combo| combo & placed != placed)
+      .map(|&combo| (run_id, combo))
+      .collect();
+
+    if removed.is_empty() {
+      return None;
+    }
+
+    let mut step = Step::new(Technique::PlacedDigit, vec![run_id], cells, placed);
+    step.removed_combinations = removed;
+
+    Some(step)
+  })
+}
+
+/// Finds a run with only one combination left whose cells still have digits outside of it
+fn unique_combination(puzzle: &Puzzle) -> Option<Step> {
+  puzzle.run_ids().find_map(|run_id| {
+    let [combo] = puzzle[run_id].combinations.as_slice() else {
+      return None;
+    };
+
+    let mut step = Step::new(Technique::UniqueCombination, vec![run_id], Vec::new(), *combo);
+
+    for &cell_id in &puzzle[run_id].children {
+      step.remove_candidates(cell_id, puzzle[cell_id].possible_values - *combo);
+    }
+
+    step.cells = step.removed_candidates.iter().map(|&(id, _)| id).collect();
+
+    (!step.cells.is_empty()).then_some(step)
+  })
+}
+
+/// Finds a cell with a digit that one of its runs has no combination for
+fn digit_intersection(puzzle: &Puzzle) -> Option<Step> {
+  puzzle.cell_ids().find_map(|cell_id| {
+    let child = &puzzle[cell_id];
+    let (down, across) = child.parents;
+    let allowed = child.possible_values & combination_digits(puzzle, down) & combination_digits(puzzle, across);
+    let removed = child.possible_values - allowed;
+
+    if removed.is_empty() {
+      return None;
+    }
+
+    let mut step = Step::new(Technique::DigitIntersection, vec![down, across], vec![cell_id], allowed);
+    step.remove_candidates(cell_id, removed);
+
+    Some(step)
+  })
+}
+
+/// Finds a digit a run needs that only one of the run's empty cells can still take
+fn hidden_single(puzzle: &Puzzle) -> Option<Step> {
+  puzzle.run_ids().find_map(|run_id| {
+    let children = &puzzle[run_id].children;
+
+    required_digits(puzzle, run_id).iter().find_map(|digit| {
+      let mut holders = children.iter().filter(|&&id| puzzle[id].possible_values.contains(digit));
+
+      let (Some(&cell_id), None) = (holders.next(), holders.next()) else {
+        return None;
+      };
+
+      let child = &puzzle[cell_id];
+
+      if child.value != 0 || child.possible_values.len() == 1 {
+        return None;
+      }
+
+      let mut step = Step::new(Technique::HiddenSingle, vec![run_id], vec![cell_id], DigitSet::single(digit));
+      step.remove_candidates(cell_id, child.possible_values - DigitSet::single(digit));
+
+      Some(step)
+    })
+  })
+}
+
+/// Finds a run where putting a digit in one cell leaves no room for the digits the run needs in its other cells
+fn required_digit(puzzle: &Puzzle) -> Option<Step> {
+  puzzle.run_ids().find_map(|run_id| {
+    let children = &puzzle[run_id].children;
+    let required = required_digits(puzzle, run_id);
+
+    if required.is_empty() || puzzle[run_id].combinations.is_empty() {
+      return None;
+    }
+
+    let mut step = Step::new(Technique::RequiredDigit, vec![run_id], Vec::new(), required);
+
+    for (index, &cell_id) in children.iter().enumerate() {
+      let child = &puzzle[cell_id];
+
+      if child.value != 0 {
+        continue;
+      }
+
+      // the other cells of the run, with this cell marked as used so it can not take a required digit
+      let options: Vec<DigitSet> = children.iter().map(|&id| puzzle[id].possible_values).collect();
+      let removed: DigitSet = child.possible_values.iter()
+        .filter(|&digit| !covers(&options, required - DigitSet::single(digit), 1 << index))
+        .collect();
+
+      step.remove_candidates(cell_id, removed);
+    }
+
+    step.cells = step.removed_candidates.iter().map(|&(id, _)| id).collect();
+
+    (!step.cells.is_empty()).then_some(step)
+  })
+}
+
+/// Finds a run with a combination that cannot be spread over the candidates of its cells
+fn combination_elimination(puzzle: &Puzzle) -> Option<Step> {
+  puzzle.run_ids().find_map(|run_id| {
+    let parent = &puzzle[run_id];
+    let options: Vec<DigitSet> = parent.children.iter().map(|&id| puzzle[id].possible_values).collect();
+
+    let removed: Vec<(RunId, DigitSet)> = parent.combinations.iter()
+      .filter(|&&combo| !covers(&options, combo, 0))
+      .map(|&combo| (run_id, combo))
+      .collect();
+
+    if removed.is_empty() {
+      return None;
+    }
+
+    let mut step = Step::new(Technique::CombinationElimination, vec![run_id], parent.children.clone(), DigitSet::EMPTY);
+    step.removed_combinations = removed;
+
+    Some(step)
+  })
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+/// - technique: The technique to look for
+///
+/// Description:
+/// - Returns the first deduction the technique can make on the puzzle, without changing the puzzle
+pub fn find_step_with(puzzle: &Puzzle, technique: Technique) -> Option<Step> {
+  match technique {
+    Technique::NakedSingle => naked_single(puzzle),
+    Technique::PlacedDigit => placed_digit(puzzle),
+    Technique::UniqueCombination => unique_combination(puzzle),
+    Technique::DigitIntersection => digit_intersection(puzzle),
+    Technique::HiddenSingle => hidden_single(puzzle),
+    Technique::RequiredDigit => required_digit(puzzle),
+    Technique::CombinationElimination => combination_elimination(puzzle)
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///
+/// Description:
+/// - Tries each technique from the easiest to the hardest and returns the first deduction found
+pub fn find_step(puzzle: &Puzzle) -> Option<Step> {
+  TECHNIQUES.iter().find_map(|&technique| find_step_with(puzzle, technique))
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+/// - step: The step being applied, the Children it fills in are added to it
+///
+/// Description:
+/// - Removes the step's candidates and combinations from the puzzle, then fills in every empty Child of the step
+///   left with one possible value and removes that value from its siblings
+/// - A sibling left with one possible value is not filled in, that is left for the next step to find
+pub fn apply_step(puzzle: &mut Puzzle, step: &mut Step) {
+  for &(cell_id, digits) in &step.removed_candidates {
+    puzzle[cell_id].possible_values -= digits;
+  }
+
+  for &(run_id, combo) in &step.removed_combinations {
+    puzzle[run_id].combinations.retain(|&other| other != combo);
+  }
+
+  let mut affected: Vec<CellId> = step.cells.iter().copied().chain(step.removed_candidates.iter().map(|&(id, _)| id)).collect();
+  affected.sort_unstable();
+  affected.dedup();
+
+  for cell_id in affected {
+    let child = &puzzle[cell_id];
+
+    let (0, Some(value)) = (child.value, child.possible_values.single_value()) else {
+      continue;
+    };
+
+    puzzle[cell_id].value = value;
+    step.placed.push((cell_id, value));
+
+    for sibling in puzzle[cell_id].siblings.clone() {
+      if puzzle[sibling].possible_values.contains(value) {
+        puzzle[sibling].possible_values.remove(value);
+        step.remove_candidates(sibling, DigitSet::single(value));
+      }
+    }
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being solved
+///      - This puzzle must have already been set up by Puzzle::load, the techniques start from the candidates found then
+///
+/// Description:
+/// - Applies the easiest technique that makes progress over and over until the puzzle is solved,
+///   no technique makes any more progress or a Child runs out of possible values
+/// - Returns every step taken, the puzzle is left with all of them applied
+pub fn solve_with_techniques(puzzle: &mut Puzzle) -> Vec<Step> {
+  let mut steps = Vec::new();
+
+  while !puzzle.is_solved() {
+    if puzzle.children.iter().any(|child| child.possible_values.is_empty()) {
+      break;
+    }
+
+    let Some(mut step) = find_step(puzzle) else {
+      break;
+    };
+
+    apply_step(puzzle, &mut step);
+    steps.push(step);
+  }
+
+  steps
+}
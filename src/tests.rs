@@ -1,1016 +1,1966 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
-  use crate::puzzle_setup;
+  use crate::Puzzle;
   use crate::Parents;
   use crate::Children;
+  use crate::{CellId, RunId};
+  use crate::digits::DigitSet;
+  use crate::techniques::{Step, Technique, TECHNIQUES};
+  use crate::difficulty::{grade_steps, rate_with, Difficulty, Grade};
+  use crate::hints::HintAction;
+  use crate::generator::{generate, GenerateError, GenerateOptions};
+  use crate::json::{parse, read_json, write_json, Json, JsonError};
+  use crate::svg::SvgOptions;
+  use crate::play::Game;
+  use crate::batch::{solve_all, write_csv, BatchResult, BatchStatus};
+  use std::time::Duration;
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use crate::combinations::combinations;
+  use crate::render::{render, render_with};
+  use crate::error::{ParseError, ParseReason};
+  use crate::validation::ValidationError;
+  use crate::Direction;
+  use crate::solver::{is_solved, propagate, solve, TraceStep};
+
+  /// Resolves a file in the puzzles folder relative to the crate root so the tests work from any directory
+  fn puzzle_path(file_name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("puzzles").join(file_name)
+  }
+
+  /// Writes a small puzzle to the temporary directory and returns its path
+  fn write_puzzle(file_name: &str, contents: &str) -> PathBuf {
+    let puzzle_file = std::env::temp_dir().join(format!("kakuro_{}", file_name));
+    fs::write(&puzzle_file, contents).unwrap();
+    puzzle_file
+  }
+
+  /// Turns a list of indexes into the ids of white cells
+  fn cells(indexes: &[usize]) -> Vec<CellId> {
+    indexes.iter().map(|&index| CellId(index)).collect()
+  }
+
+  /// Turns a list of digits into a DigitSet
+  fn digits(digits: &[u8]) -> DigitSet {
+    DigitSet::from(digits)
+  }
+
+  /// Turns the indexes of a down and an across run into the ids of a white cell's parents
+  fn runs(down: usize, across: usize) -> (RunId, RunId) {
+    (RunId(down), RunId(across))
+  }
 
   #[test]
   fn test_puzzle_setup_with_input_file_1() {
-    let mut parents_and_children: (Vec<Parents>, Vec<Children>) = (Vec::new(), Vec::new());
-    let puzzle_file = "puzzles\\Kakuro_input.txt".to_string();
+    let puzzle_file = puzzle_path("Kakuro_input.txt");
 
-    puzzle_setup(&mut parents_and_children, puzzle_file);
+    let puzzle = Puzzle::load(&puzzle_file).unwrap();
 
-    let parents = parents_and_children.0;
-    let children = parents_and_children.1;
+    let parents = puzzle.parents;
+    let children = puzzle.children;
 
     assert_eq!(parents.len(), 48);
     assert_eq!(children.len(), 72);
 
-    assert_eq!(parents[0], Parents { children: vec![0, 7, 15], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[1], Parents { children: vec![1, 8], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[2], Parents { children: vec![2, 9, 16, 22, 29], sum: 17, value_size: "17-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 7], vec![1, 2, 3, 5, 6]] });
-    assert_eq!(parents[3], Parents { children: vec![3, 10, 18, 24, 30], sum: 17, value_size: "17-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 7], vec![1, 2, 3, 5, 6]] });
-    assert_eq!(parents[4], Parents { children: vec![4, 11], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[5], Parents { children: vec![5, 12, 19], sum: 20, value_size: "20-3".to_string(), combinations: vec![vec![3, 8, 9], vec![4, 7, 9], vec![5, 6, 9], vec![5, 7, 8]] });
-    assert_eq!(parents[6], Parents { children: vec![6, 14], sum: 14, value_size: "14-2".to_string(), combinations: vec![vec![5, 9], vec![6, 8]] });
-    assert_eq!(parents[7], Parents { children: vec![0, 1, 2], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[8], Parents { children: vec![3, 4, 5], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[9], Parents { children: vec![13, 20], sum: 15, value_size: "15-2".to_string(), combinations: vec![vec![6, 9], vec![7, 8]] });
-    assert_eq!(parents[10], Parents { children: vec![6, 7, 8, 9], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[11], Parents { children: vec![17, 23], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[12], Parents { children: vec![10, 11, 12, 13], sum: 27, value_size: "27-4".to_string(), combinations: vec![vec![3, 7, 8, 9], vec![4, 6, 8, 9], vec![5, 6, 7, 9]] });
-    assert_eq!(parents[13], Parents { children: vec![14, 15], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[14], Parents { children: vec![21, 28], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[15], Parents { children: vec![16, 17, 18], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[16], Parents { children: vec![25, 31], sum: 13, value_size: "13-2".to_string(), combinations: vec![vec![4, 9], vec![5, 8], vec![6, 7]] });
-    assert_eq!(parents[17], Parents { children: vec![19, 20], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[18], Parents { children: vec![26, 34, 38], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[19], Parents { children: vec![27, 35, 39], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[20], Parents { children: vec![21, 22, 23, 24, 25], sum: 18, value_size: "18-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 8], vec![1, 2, 3, 5, 7], vec![1, 2, 4, 5, 6]] });
-    assert_eq!(parents[21], Parents { children: vec![32, 36, 44], sum: 14, value_size: "14-3".to_string(), combinations: vec![vec![1, 4, 9], vec![1, 5, 8], vec![1, 6, 7], vec![2, 3, 9], vec![2, 4, 8], vec![2, 5, 7], vec![3, 4, 7], vec![3, 5, 6]] });
-    assert_eq!(parents[22], Parents { children: vec![33, 37, 45], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[23], Parents { children: vec![26, 27, 28, 29], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[24], Parents { children: vec![30, 31, 32, 33], sum: 21, value_size: "21-4".to_string(), combinations: vec![vec![1, 3, 8, 9], vec![1, 4, 7, 9], vec![1, 5, 6, 9], vec![1, 5, 7, 8], vec![2, 3, 7, 9], vec![2, 4, 6, 9], vec![2, 4, 7, 8], vec![2, 5, 6, 8], vec![3, 4, 5, 9], vec![3, 4, 6, 8], vec![3, 5, 6, 7]] });
-    assert_eq!(parents[25], Parents { children: vec![34, 35], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[26], Parents { children: vec![40, 46], sum: 8, value_size: "8-2".to_string(), combinations: vec![vec![1, 7], vec![2, 6], vec![3, 5]] });
-    assert_eq!(parents[27], Parents { children: vec![41, 47, 53, 61, 68], sum: 34, value_size: "34-5".to_string(), combinations: vec![vec![4, 6, 7, 8, 9]] });
-    assert_eq!(parents[28], Parents { children: vec![42, 49, 55, 62, 69], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[29], Parents { children: vec![43, 50], sum: 5, value_size: "5-2".to_string(), combinations: vec![vec![1, 4], vec![2, 3]] });
-    assert_eq!(parents[30], Parents { children: vec![36, 37], sum: 9, value_size: "9-2".to_string(), combinations: vec![vec![1, 8], vec![2, 7], vec![3, 6], vec![4, 5]] });
-    assert_eq!(parents[31], Parents { children: vec![38, 39, 40, 41], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[32], Parents { children: vec![48, 54], sum: 12, value_size: "12-2".to_string(), combinations: vec![vec![3, 9], vec![4, 8], vec![5, 7]] });
-    assert_eq!(parents[33], Parents { children: vec![42, 43, 44, 45], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[34], Parents { children: vec![51, 58], sum: 15, value_size: "15-2".to_string(), combinations: vec![vec![6, 9], vec![7, 8]] });
-    assert_eq!(parents[35], Parents { children: vec![52, 59, 66], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[36], Parents { children: vec![46, 47, 48, 49, 50], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[37], Parents { children: vec![56, 64, 71], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[38], Parents { children: vec![57, 65], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[39], Parents { children: vec![51, 52], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[40], Parents { children: vec![60, 67], sum: 8, value_size: "8-2".to_string(), combinations: vec![vec![1, 7], vec![2, 6], vec![3, 5]] });
-    assert_eq!(parents[41], Parents { children: vec![53, 54, 55], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[42], Parents { children: vec![63, 70], sum: 7, value_size: "7-2".to_string(), combinations: vec![vec![1, 6], vec![2, 5], vec![3, 4]] });
-    assert_eq!(parents[43], Parents { children: vec![56, 57], sum: 6, value_size: "6-2".to_string(), combinations: vec![vec![1, 5], vec![2, 4]] });
-    assert_eq!(parents[44], Parents { children: vec![58, 59, 60, 61], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[45], Parents { children: vec![62, 63, 64, 65], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[46], Parents { children: vec![66, 67, 68], sum: 13, value_size: "13-3".to_string(), combinations: vec![vec![1, 3, 9], vec![1, 4, 8], vec![1, 5, 7], vec![2, 3, 8], vec![2, 4, 7], vec![2, 5, 6], vec![3, 4, 6]] });
-    assert_eq!(parents[47], Parents { children: vec![69, 70, 71], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
+    assert_eq!(parents[0], Parents { children: cells(&[0, 7, 15]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (0, 2) });
+    assert_eq!(parents[1], Parents { children: cells(&[1, 8]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (0, 3) });
+    assert_eq!(parents[2], Parents { children: cells(&[2, 9, 16, 22, 29]), sum: 17, value_size: "17-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 7]), digits(&[1, 2, 3, 5, 6])], direction: Direction::Down, head: (0, 4) });
+    assert_eq!(parents[3], Parents { children: cells(&[3, 10, 18, 24, 30]), sum: 17, value_size: "17-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 7]), digits(&[1, 2, 3, 5, 6])], direction: Direction::Down, head: (0, 6) });
+    assert_eq!(parents[4], Parents { children: cells(&[4, 11]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Down, head: (0, 7) });
+    assert_eq!(parents[5], Parents { children: cells(&[5, 12, 19]), sum: 20, value_size: "20-3".to_string(), combinations: vec![digits(&[3, 8, 9]), digits(&[4, 7, 9]), digits(&[5, 6, 9]), digits(&[5, 7, 8])], direction: Direction::Down, head: (0, 8) });
+    assert_eq!(parents[6], Parents { children: cells(&[6, 14]), sum: 14, value_size: "14-2".to_string(), combinations: vec![digits(&[5, 9]), digits(&[6, 8])], direction: Direction::Down, head: (1, 1) });
+    assert_eq!(parents[7], Parents { children: cells(&[0, 1, 2]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (1, 1) });
+    assert_eq!(parents[8], Parents { children: cells(&[3, 4, 5]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (1, 5) });
+    assert_eq!(parents[9], Parents { children: cells(&[13, 20]), sum: 15, value_size: "15-2".to_string(), combinations: vec![digits(&[6, 9]), digits(&[7, 8])], direction: Direction::Down, head: (1, 9) });
+    assert_eq!(parents[10], Parents { children: cells(&[6, 7, 8, 9]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Across, head: (2, 0) });
+    assert_eq!(parents[11], Parents { children: cells(&[17, 23]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (2, 5) });
+    assert_eq!(parents[12], Parents { children: cells(&[10, 11, 12, 13]), sum: 27, value_size: "27-4".to_string(), combinations: vec![digits(&[3, 7, 8, 9]), digits(&[4, 6, 8, 9]), digits(&[5, 6, 7, 9])], direction: Direction::Across, head: (2, 5) });
+    assert_eq!(parents[13], Parents { children: cells(&[14, 15]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (3, 0) });
+    assert_eq!(parents[14], Parents { children: cells(&[21, 28]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Down, head: (3, 3) });
+    assert_eq!(parents[15], Parents { children: cells(&[16, 17, 18]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (3, 3) });
+    assert_eq!(parents[16], Parents { children: cells(&[25, 31]), sum: 13, value_size: "13-2".to_string(), combinations: vec![digits(&[4, 9]), digits(&[5, 8]), digits(&[6, 7])], direction: Direction::Down, head: (3, 7) });
+    assert_eq!(parents[17], Parents { children: cells(&[19, 20]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (3, 7) });
+    assert_eq!(parents[18], Parents { children: cells(&[26, 34, 38]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Down, head: (4, 1) });
+    assert_eq!(parents[19], Parents { children: cells(&[27, 35, 39]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Down, head: (4, 2) });
+    assert_eq!(parents[20], Parents { children: cells(&[21, 22, 23, 24, 25]), sum: 18, value_size: "18-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 8]), digits(&[1, 2, 3, 5, 7]), digits(&[1, 2, 4, 5, 6])], direction: Direction::Across, head: (4, 2) });
+    assert_eq!(parents[21], Parents { children: cells(&[32, 36, 44]), sum: 14, value_size: "14-3".to_string(), combinations: vec![digits(&[1, 4, 9]), digits(&[1, 5, 8]), digits(&[1, 6, 7]), digits(&[2, 3, 9]), digits(&[2, 4, 8]), digits(&[2, 5, 7]), digits(&[3, 4, 7]), digits(&[3, 5, 6])], direction: Direction::Down, head: (4, 8) });
+    assert_eq!(parents[22], Parents { children: cells(&[33, 37, 45]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Down, head: (4, 9) });
+    assert_eq!(parents[23], Parents { children: cells(&[26, 27, 28, 29]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Across, head: (5, 0) });
+    assert_eq!(parents[24], Parents { children: cells(&[30, 31, 32, 33]), sum: 21, value_size: "21-4".to_string(), combinations: vec![digits(&[1, 3, 8, 9]), digits(&[1, 4, 7, 9]), digits(&[1, 5, 6, 9]), digits(&[1, 5, 7, 8]), digits(&[2, 3, 7, 9]), digits(&[2, 4, 6, 9]), digits(&[2, 4, 7, 8]), digits(&[2, 5, 6, 8]), digits(&[3, 4, 5, 9]), digits(&[3, 4, 6, 8]), digits(&[3, 5, 6, 7])], direction: Direction::Across, head: (5, 5) });
+    assert_eq!(parents[25], Parents { children: cells(&[34, 35]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (6, 0) });
+    assert_eq!(parents[26], Parents { children: cells(&[40, 46]), sum: 8, value_size: "8-2".to_string(), combinations: vec![digits(&[1, 7]), digits(&[2, 6]), digits(&[3, 5])], direction: Direction::Down, head: (6, 3) });
+    assert_eq!(parents[27], Parents { children: cells(&[41, 47, 53, 61, 68]), sum: 34, value_size: "34-5".to_string(), combinations: vec![digits(&[4, 6, 7, 8, 9])], direction: Direction::Down, head: (6, 4) });
+    assert_eq!(parents[28], Parents { children: cells(&[42, 49, 55, 62, 69]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Down, head: (6, 6) });
+    assert_eq!(parents[29], Parents { children: cells(&[43, 50]), sum: 5, value_size: "5-2".to_string(), combinations: vec![digits(&[1, 4]), digits(&[2, 3])], direction: Direction::Down, head: (6, 7) });
+    assert_eq!(parents[30], Parents { children: cells(&[36, 37]), sum: 9, value_size: "9-2".to_string(), combinations: vec![digits(&[1, 8]), digits(&[2, 7]), digits(&[3, 6]), digits(&[4, 5])], direction: Direction::Across, head: (6, 7) });
+    assert_eq!(parents[31], Parents { children: cells(&[38, 39, 40, 41]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Across, head: (7, 0) });
+    assert_eq!(parents[32], Parents { children: cells(&[48, 54]), sum: 12, value_size: "12-2".to_string(), combinations: vec![digits(&[3, 9]), digits(&[4, 8]), digits(&[5, 7])], direction: Direction::Down, head: (7, 5) });
+    assert_eq!(parents[33], Parents { children: cells(&[42, 43, 44, 45]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Across, head: (7, 5) });
+    assert_eq!(parents[34], Parents { children: cells(&[51, 58]), sum: 15, value_size: "15-2".to_string(), combinations: vec![digits(&[6, 9]), digits(&[7, 8])], direction: Direction::Down, head: (8, 1) });
+    assert_eq!(parents[35], Parents { children: cells(&[52, 59, 66]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Down, head: (8, 2) });
+    assert_eq!(parents[36], Parents { children: cells(&[46, 47, 48, 49, 50]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Across, head: (8, 2) });
+    assert_eq!(parents[37], Parents { children: cells(&[56, 64, 71]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Down, head: (8, 8) });
+    assert_eq!(parents[38], Parents { children: cells(&[57, 65]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (8, 9) });
+    assert_eq!(parents[39], Parents { children: cells(&[51, 52]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (9, 0) });
+    assert_eq!(parents[40], Parents { children: cells(&[60, 67]), sum: 8, value_size: "8-2".to_string(), combinations: vec![digits(&[1, 7]), digits(&[2, 6]), digits(&[3, 5])], direction: Direction::Down, head: (9, 3) });
+    assert_eq!(parents[41], Parents { children: cells(&[53, 54, 55]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (9, 3) });
+    assert_eq!(parents[42], Parents { children: cells(&[63, 70]), sum: 7, value_size: "7-2".to_string(), combinations: vec![digits(&[1, 6]), digits(&[2, 5]), digits(&[3, 4])], direction: Direction::Down, head: (9, 7) });
+    assert_eq!(parents[43], Parents { children: cells(&[56, 57]), sum: 6, value_size: "6-2".to_string(), combinations: vec![digits(&[1, 5]), digits(&[2, 4])], direction: Direction::Across, head: (9, 7) });
+    assert_eq!(parents[44], Parents { children: cells(&[58, 59, 60, 61]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Across, head: (10, 0) });
+    assert_eq!(parents[45], Parents { children: cells(&[62, 63, 64, 65]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Across, head: (10, 5) });
+    assert_eq!(parents[46], Parents { children: cells(&[66, 67, 68]), sum: 13, value_size: "13-3".to_string(), combinations: vec![digits(&[1, 3, 9]), digits(&[1, 4, 8]), digits(&[1, 5, 7]), digits(&[2, 3, 8]), digits(&[2, 4, 7]), digits(&[2, 5, 6]), digits(&[3, 4, 6])], direction: Direction::Across, head: (11, 1) });
+    assert_eq!(parents[47], Parents { children: cells(&[69, 70, 71]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Across, head: (11, 5) });
    
-    assert_eq!(children[0], Children { parents: (0, 7), siblings: vec![1, 2, 7, 15], value: 0, possible_values: vec![1, 2, 4] }); 
-    assert_eq!(children[1], Children { parents: (1, 7), siblings: vec![0, 2, 8], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[2], Children { parents: (2, 7), siblings: vec![0, 1, 9, 16, 22, 29], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[3], Children { parents: (3, 8), siblings: vec![4, 5, 10, 18, 24, 30], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[4], Children { parents: (4, 8), siblings: vec![3, 5, 11], value: 0, possible_values: vec![2, 4] });
-    assert_eq!(children[5], Children { parents: (5, 8), siblings: vec![3, 4, 12, 19], value: 0, possible_values: vec![4] });
-    assert_eq!(children[6], Children { parents: (6, 10), siblings: vec![7, 8, 9, 14], value: 0, possible_values: vec![5] });
-    assert_eq!(children[7], Children { parents: (0, 10), siblings: vec![0, 6, 8, 9, 15], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[8], Children { parents: (1, 10), siblings: vec![1, 6, 7, 9], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[9], Children { parents: (2, 10), siblings: vec![2, 6, 7, 8, 16, 22, 29], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[10], Children { parents: (3, 12), siblings: vec![3, 11, 12, 13, 18, 24, 30], value: 0, possible_values: vec![3, 4, 5, 6, 7] });
-    assert_eq!(children[11], Children { parents: (4, 12), siblings: vec![4, 10, 12, 13], value: 0, possible_values: vec![3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[12], Children { parents: (5, 12), siblings: vec![5, 10, 11, 13, 19], value: 0, possible_values: vec![3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[13], Children { parents: (9, 12), siblings: vec![10, 11, 12, 20], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[14], Children { parents: (6, 13), siblings: vec![6, 15], value: 0, possible_values: vec![9] });
-    assert_eq!(children[15], Children { parents: (0, 13), siblings: vec![0, 7, 14], value: 0, possible_values: vec![7] });
-    assert_eq!(children[16], Children { parents: (2, 15), siblings: vec![2, 9, 17, 18, 22, 29], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[17], Children { parents: (11, 15), siblings: vec![16, 18, 23], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[18], Children { parents: (3, 15), siblings: vec![3, 10, 16, 17, 24, 30], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[19], Children { parents: (5, 17), siblings: vec![5, 12, 20], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[20], Children { parents: (9, 17), siblings: vec![13, 19], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[21], Children { parents: (14, 20), siblings: vec![22, 23, 24, 25, 28], value: 0, possible_values: vec![8] });
-    assert_eq!(children[22], Children { parents: (2, 20), siblings: vec![2, 9, 16, 21, 23, 24, 25, 29], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[23], Children { parents: (11, 20), siblings: vec![17, 21, 22, 24, 25], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[24], Children { parents: (3, 20), siblings: vec![3, 10, 18, 21, 22, 23, 25, 30], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[25], Children { parents: (16, 20), siblings: vec![21, 22, 23, 24, 31], value: 0, possible_values: vec![4, 5, 6, 7, 8] });
-    assert_eq!(children[26], Children { parents: (18, 23), siblings: vec![27, 28, 29, 34, 38], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[27], Children { parents: (19, 23), siblings: vec![26, 28, 29, 35, 39], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[28], Children { parents: (14, 23), siblings: vec![21, 26, 27, 29], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[29], Children { parents: (2, 23), siblings: vec![2, 9, 16, 22, 26, 27, 28], value: 0, possible_values: vec![6, 7] });
-    assert_eq!(children[30], Children { parents: (3, 24), siblings: vec![3, 10, 18, 24, 31, 32, 33], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[31], Children { parents: (16, 24), siblings: vec![25, 30, 32, 33], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[32], Children { parents: (21, 24), siblings: vec![30, 31, 33, 36, 44], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[33], Children { parents: (22, 24), siblings: vec![30, 31, 32, 37, 45], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[34], Children { parents: (18, 25), siblings: vec![26, 35, 38], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[35], Children { parents: (19, 25), siblings: vec![27, 34, 39], value: 0, possible_values: vec![9] });
-    assert_eq!(children[36], Children { parents: (21, 30), siblings: vec![32, 37, 44], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[37], Children { parents: (22, 30), siblings: vec![33, 36, 45], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[38], Children { parents: (18, 31), siblings: vec![26, 34, 39, 40, 41], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[39], Children { parents: (19, 31), siblings: vec![27, 35, 38, 40, 41], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[40], Children { parents: (26, 31), siblings: vec![38, 39, 41, 46], value: 0, possible_values: vec![6, 7] });
-    assert_eq!(children[41], Children { parents: (27, 31), siblings: vec![38, 39, 40, 47, 53, 61, 68], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[42], Children { parents: (28, 33), siblings: vec![43, 44, 45, 49, 55, 62, 69], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[43], Children { parents: (29, 33), siblings: vec![42, 44, 45, 50], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[44], Children { parents: (21, 33), siblings: vec![32, 36, 42, 43, 45], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[45], Children { parents: (22, 33), siblings: vec![33, 37, 42, 43, 44], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[46], Children { parents: (26, 36), siblings: vec![40, 47, 48, 49, 50], value: 0, possible_values: vec![1, 2, 3, 6] });
-    assert_eq!(children[47], Children { parents: (27, 36), siblings: vec![41, 46, 48, 49, 50, 53, 61, 68], value: 0, possible_values: vec![4, 6] });
-    assert_eq!(children[48], Children { parents: (32, 36), siblings: vec![46, 47, 49, 50, 54], value: 0, possible_values: vec![3, 4] });
-    assert_eq!(children[49], Children { parents: (28, 36), siblings: vec![42, 46, 47, 48, 50, 55, 62, 69], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[50], Children { parents: (29, 36), siblings: vec![43, 46, 47, 48, 49], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[51], Children { parents: (34, 39), siblings: vec![52, 58], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[52], Children { parents: (35, 39), siblings: vec![51, 59, 66], value: 0, possible_values: vec![9] });
-    assert_eq!(children[53], Children { parents: (27, 41), siblings: vec![41, 47, 54, 55, 61, 68], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[54], Children { parents: (32, 41), siblings: vec![48, 53, 55], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[55], Children { parents: (28, 41), siblings: vec![42, 49, 53, 54, 62, 69], value: 0, possible_values: vec![6] });
-    assert_eq!(children[56], Children { parents: (37, 43), siblings: vec![57, 64, 71], value: 0, possible_values: vec![1, 2, 4, 5] });
-    assert_eq!(children[57], Children { parents: (38, 43), siblings: vec![56, 65], value: 0, possible_values: vec![1] });
-    assert_eq!(children[58], Children { parents: (34, 44), siblings: vec![51, 59, 60, 61], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[59], Children { parents: (35, 44), siblings: vec![52, 58, 60, 61, 66], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[60], Children { parents: (40, 44), siblings: vec![58, 59, 61, 67], value: 0, possible_values: vec![6, 7] });
-    assert_eq!(children[61], Children { parents: (27, 44), siblings: vec![41, 47, 53, 58, 59, 60, 68], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[62], Children { parents: (28, 45), siblings: vec![42, 49, 55, 63, 64, 65, 69], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[63], Children { parents: (42, 45), siblings: vec![62, 64, 65, 70], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[64], Children { parents: (37, 45), siblings: vec![56, 62, 63, 65, 71], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[65], Children { parents: (38, 45), siblings: vec![57, 62, 63, 64], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[66], Children { parents: (35, 46), siblings: vec![52, 59, 67, 68], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[67], Children { parents: (40, 46), siblings: vec![60, 66, 68], value: 0, possible_values: vec![1, 2, 3, 5, 6, 7] });
-    assert_eq!(children[68], Children { parents: (27, 46), siblings: vec![41, 47, 53, 61, 66, 67], value: 0, possible_values: vec![4, 6, 7, 8, 9] });
-    assert_eq!(children[69], Children { parents: (28, 47), siblings: vec![42, 49, 55, 62, 70, 71], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[70], Children { parents: (42, 47), siblings: vec![63, 69, 71], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[71], Children { parents: (37, 47), siblings: vec![56, 64, 69, 70], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
+    assert_eq!(children[0], Children { parents: runs(0, 7), siblings: cells(&[1, 2, 7, 15]), value: 0, possible_values: digits(&[1, 2, 4]), position: (1, 2) }); 
+    assert_eq!(children[1], Children { parents: runs(1, 7), siblings: cells(&[0, 2, 8]), value: 0, possible_values: digits(&[1, 2]), position: (1, 3) });
+    assert_eq!(children[2], Children { parents: runs(2, 7), siblings: cells(&[0, 1, 9, 16, 22, 29]), value: 0, possible_values: digits(&[1, 2, 4]), position: (1, 4) });
+    assert_eq!(children[3], Children { parents: runs(3, 8), siblings: cells(&[4, 5, 10, 18, 24, 30]), value: 0, possible_values: digits(&[1, 2, 4]), position: (1, 6) });
+    assert_eq!(children[4], Children { parents: runs(4, 8), siblings: cells(&[3, 5, 11]), value: 0, possible_values: digits(&[2, 4]), position: (1, 7) });
+    assert_eq!(children[5], Children { parents: runs(5, 8), siblings: cells(&[3, 4, 12, 19]), value: 0, possible_values: digits(&[4]), position: (1, 8) });
+    assert_eq!(children[6], Children { parents: runs(6, 10), siblings: cells(&[7, 8, 9, 14]), value: 0, possible_values: digits(&[5]), position: (2, 1) });
+    assert_eq!(children[7], Children { parents: runs(0, 10), siblings: cells(&[0, 6, 8, 9, 15]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (2, 2) });
+    assert_eq!(children[8], Children { parents: runs(1, 10), siblings: cells(&[1, 6, 7, 9]), value: 0, possible_values: digits(&[1, 2]), position: (2, 3) });
+    assert_eq!(children[9], Children { parents: runs(2, 10), siblings: cells(&[2, 6, 7, 8, 16, 22, 29]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (2, 4) });
+    assert_eq!(children[10], Children { parents: runs(3, 12), siblings: cells(&[3, 11, 12, 13, 18, 24, 30]), value: 0, possible_values: digits(&[3, 4, 5, 6, 7]), position: (2, 6) });
+    assert_eq!(children[11], Children { parents: runs(4, 12), siblings: cells(&[4, 10, 12, 13]), value: 0, possible_values: digits(&[3, 4, 5, 6, 7, 8, 9]), position: (2, 7) });
+    assert_eq!(children[12], Children { parents: runs(5, 12), siblings: cells(&[5, 10, 11, 13, 19]), value: 0, possible_values: digits(&[3, 4, 5, 6, 7, 8, 9]), position: (2, 8) });
+    assert_eq!(children[13], Children { parents: runs(9, 12), siblings: cells(&[10, 11, 12, 20]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (2, 9) });
+    assert_eq!(children[14], Children { parents: runs(6, 13), siblings: cells(&[6, 15]), value: 0, possible_values: digits(&[9]), position: (3, 1) });
+    assert_eq!(children[15], Children { parents: runs(0, 13), siblings: cells(&[0, 7, 14]), value: 0, possible_values: digits(&[7]), position: (3, 2) });
+    assert_eq!(children[16], Children { parents: runs(2, 15), siblings: cells(&[2, 9, 17, 18, 22, 29]), value: 0, possible_values: digits(&[1, 2, 4]), position: (3, 4) });
+    assert_eq!(children[17], Children { parents: runs(11, 15), siblings: cells(&[16, 18, 23]), value: 0, possible_values: digits(&[1, 2]), position: (3, 5) });
+    assert_eq!(children[18], Children { parents: runs(3, 15), siblings: cells(&[3, 10, 16, 17, 24, 30]), value: 0, possible_values: digits(&[1, 2, 4]), position: (3, 6) });
+    assert_eq!(children[19], Children { parents: runs(5, 17), siblings: cells(&[5, 12, 20]), value: 0, possible_values: digits(&[7, 9]), position: (3, 8) });
+    assert_eq!(children[20], Children { parents: runs(9, 17), siblings: cells(&[13, 19]), value: 0, possible_values: digits(&[7, 9]), position: (3, 9) });
+    assert_eq!(children[21], Children { parents: runs(14, 20), siblings: cells(&[22, 23, 24, 25, 28]), value: 0, possible_values: digits(&[8]), position: (4, 3) });
+    assert_eq!(children[22], Children { parents: runs(2, 20), siblings: cells(&[2, 9, 16, 21, 23, 24, 25, 29]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (4, 4) });
+    assert_eq!(children[23], Children { parents: runs(11, 20), siblings: cells(&[17, 21, 22, 24, 25]), value: 0, possible_values: digits(&[1, 2]), position: (4, 5) });
+    assert_eq!(children[24], Children { parents: runs(3, 20), siblings: cells(&[3, 10, 18, 21, 22, 23, 25, 30]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (4, 6) });
+    assert_eq!(children[25], Children { parents: runs(16, 20), siblings: cells(&[21, 22, 23, 24, 31]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8]), position: (4, 7) });
+    assert_eq!(children[26], Children { parents: runs(18, 23), siblings: cells(&[27, 28, 29, 34, 38]), value: 0, possible_values: digits(&[7, 8, 9]), position: (5, 1) });
+    assert_eq!(children[27], Children { parents: runs(19, 23), siblings: cells(&[26, 28, 29, 35, 39]), value: 0, possible_values: digits(&[6, 8, 9]), position: (5, 2) });
+    assert_eq!(children[28], Children { parents: runs(14, 23), siblings: cells(&[21, 26, 27, 29]), value: 0, possible_values: digits(&[8, 9]), position: (5, 3) });
+    assert_eq!(children[29], Children { parents: runs(2, 23), siblings: cells(&[2, 9, 16, 22, 26, 27, 28]), value: 0, possible_values: digits(&[6, 7]), position: (5, 4) });
+    assert_eq!(children[30], Children { parents: runs(3, 24), siblings: cells(&[3, 10, 18, 24, 31, 32, 33]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (5, 6) });
+    assert_eq!(children[31], Children { parents: runs(16, 24), siblings: cells(&[25, 30, 32, 33]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (5, 7) });
+    assert_eq!(children[32], Children { parents: runs(21, 24), siblings: cells(&[30, 31, 33, 36, 44]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (5, 8) });
+    assert_eq!(children[33], Children { parents: runs(22, 24), siblings: cells(&[30, 31, 32, 37, 45]), value: 0, possible_values: digits(&[1, 2, 4]), position: (5, 9) });
+    assert_eq!(children[34], Children { parents: runs(18, 25), siblings: cells(&[26, 35, 38]), value: 0, possible_values: digits(&[7, 9]), position: (6, 1) });
+    assert_eq!(children[35], Children { parents: runs(19, 25), siblings: cells(&[27, 34, 39]), value: 0, possible_values: digits(&[9]), position: (6, 2) });
+    assert_eq!(children[36], Children { parents: runs(21, 30), siblings: cells(&[32, 37, 44]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (6, 8) });
+    assert_eq!(children[37], Children { parents: runs(22, 30), siblings: cells(&[33, 36, 45]), value: 0, possible_values: digits(&[1, 2, 4]), position: (6, 9) });
+    assert_eq!(children[38], Children { parents: runs(18, 31), siblings: cells(&[26, 34, 39, 40, 41]), value: 0, possible_values: digits(&[7, 8, 9]), position: (7, 1) });
+    assert_eq!(children[39], Children { parents: runs(19, 31), siblings: cells(&[27, 35, 38, 40, 41]), value: 0, possible_values: digits(&[6, 8, 9]), position: (7, 2) });
+    assert_eq!(children[40], Children { parents: runs(26, 31), siblings: cells(&[38, 39, 41, 46]), value: 0, possible_values: digits(&[6, 7]), position: (7, 3) });
+    assert_eq!(children[41], Children { parents: runs(27, 31), siblings: cells(&[38, 39, 40, 47, 53, 61, 68]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (7, 4) });
+    assert_eq!(children[42], Children { parents: runs(28, 33), siblings: cells(&[43, 44, 45, 49, 55, 62, 69]), value: 0, possible_values: digits(&[1, 2, 3]), position: (7, 6) });
+    assert_eq!(children[43], Children { parents: runs(29, 33), siblings: cells(&[42, 44, 45, 50]), value: 0, possible_values: digits(&[1, 2, 3]), position: (7, 7) });
+    assert_eq!(children[44], Children { parents: runs(21, 33), siblings: cells(&[32, 36, 42, 43, 45]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (7, 8) });
+    assert_eq!(children[45], Children { parents: runs(22, 33), siblings: cells(&[33, 37, 42, 43, 44]), value: 0, possible_values: digits(&[1, 2]), position: (7, 9) });
+    assert_eq!(children[46], Children { parents: runs(26, 36), siblings: cells(&[40, 47, 48, 49, 50]), value: 0, possible_values: digits(&[1, 2, 3, 6]), position: (8, 3) });
+    assert_eq!(children[47], Children { parents: runs(27, 36), siblings: cells(&[41, 46, 48, 49, 50, 53, 61, 68]), value: 0, possible_values: digits(&[4, 6]), position: (8, 4) });
+    assert_eq!(children[48], Children { parents: runs(32, 36), siblings: cells(&[46, 47, 49, 50, 54]), value: 0, possible_values: digits(&[3, 4]), position: (8, 5) });
+    assert_eq!(children[49], Children { parents: runs(28, 36), siblings: cells(&[42, 46, 47, 48, 50, 55, 62, 69]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (8, 6) });
+    assert_eq!(children[50], Children { parents: runs(29, 36), siblings: cells(&[43, 46, 47, 48, 49]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (8, 7) });
+    assert_eq!(children[51], Children { parents: runs(34, 39), siblings: cells(&[52, 58]), value: 0, possible_values: digits(&[7, 9]), position: (9, 1) });
+    assert_eq!(children[52], Children { parents: runs(35, 39), siblings: cells(&[51, 59, 66]), value: 0, possible_values: digits(&[9]), position: (9, 2) });
+    assert_eq!(children[53], Children { parents: runs(27, 41), siblings: cells(&[41, 47, 54, 55, 61, 68]), value: 0, possible_values: digits(&[6, 8, 9]), position: (9, 4) });
+    assert_eq!(children[54], Children { parents: runs(32, 41), siblings: cells(&[48, 53, 55]), value: 0, possible_values: digits(&[8, 9]), position: (9, 5) });
+    assert_eq!(children[55], Children { parents: runs(28, 41), siblings: cells(&[42, 49, 53, 54, 62, 69]), value: 0, possible_values: digits(&[6]), position: (9, 6) });
+    assert_eq!(children[56], Children { parents: runs(37, 43), siblings: cells(&[57, 64, 71]), value: 0, possible_values: digits(&[1, 2, 4, 5]), position: (9, 8) });
+    assert_eq!(children[57], Children { parents: runs(38, 43), siblings: cells(&[56, 65]), value: 0, possible_values: digits(&[1]), position: (9, 9) });
+    assert_eq!(children[58], Children { parents: runs(34, 44), siblings: cells(&[51, 59, 60, 61]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (10, 1) });
+    assert_eq!(children[59], Children { parents: runs(35, 44), siblings: cells(&[52, 58, 60, 61, 66]), value: 0, possible_values: digits(&[6, 8, 9]), position: (10, 2) });
+    assert_eq!(children[60], Children { parents: runs(40, 44), siblings: cells(&[58, 59, 61, 67]), value: 0, possible_values: digits(&[6, 7]), position: (10, 3) });
+    assert_eq!(children[61], Children { parents: runs(27, 44), siblings: cells(&[41, 47, 53, 58, 59, 60, 68]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (10, 4) });
+    assert_eq!(children[62], Children { parents: runs(28, 45), siblings: cells(&[42, 49, 55, 63, 64, 65, 69]), value: 0, possible_values: digits(&[1, 2, 3]), position: (10, 6) });
+    assert_eq!(children[63], Children { parents: runs(42, 45), siblings: cells(&[62, 64, 65, 70]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (10, 7) });
+    assert_eq!(children[64], Children { parents: runs(37, 45), siblings: cells(&[56, 62, 63, 65, 71]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (10, 8) });
+    assert_eq!(children[65], Children { parents: runs(38, 45), siblings: cells(&[57, 62, 63, 64]), value: 0, possible_values: digits(&[1, 3]), position: (10, 9) });
+    assert_eq!(children[66], Children { parents: runs(35, 46), siblings: cells(&[52, 59, 67, 68]), value: 0, possible_values: digits(&[6, 8, 9]), position: (11, 2) });
+    assert_eq!(children[67], Children { parents: runs(40, 46), siblings: cells(&[60, 66, 68]), value: 0, possible_values: digits(&[1, 2, 3, 5, 6, 7]), position: (11, 3) });
+    assert_eq!(children[68], Children { parents: runs(27, 46), siblings: cells(&[41, 47, 53, 61, 66, 67]), value: 0, possible_values: digits(&[4, 6, 7, 8, 9]), position: (11, 4) });
+    assert_eq!(children[69], Children { parents: runs(28, 47), siblings: cells(&[42, 49, 55, 62, 70, 71]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (11, 6) });
+    assert_eq!(children[70], Children { parents: runs(42, 47), siblings: cells(&[63, 69, 71]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (11, 7) });
+    assert_eq!(children[71], Children { parents: runs(37, 47), siblings: cells(&[56, 64, 69, 70]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (11, 8) });
   }
 
   #[test]
   fn test_puzzle_setup_with_input_file_2() {
-    let mut parents_and_children: (Vec<Parents>, Vec<Children>) = (Vec::new(), Vec::new());
-    let puzzle_file = "puzzles\\Kakuro_input2.txt".to_string();
+    let puzzle_file = puzzle_path("Kakuro_input2.txt");
 
-    puzzle_setup(&mut parents_and_children, puzzle_file);
+    let puzzle = Puzzle::load(&puzzle_file).unwrap();
 
-    let parents = parents_and_children.0;
-    let children = parents_and_children.1;
+    let parents = puzzle.parents;
+    let children = puzzle.children;
 
     assert_eq!(parents.len(), 66);
     assert_eq!(children.len(), 97);
 
-    assert_eq!(parents[0], Parents { children: vec![0, 5, 14, 22], sum: 13, value_size: "13-4".to_string(), combinations: vec![vec![1, 2, 3, 7], vec![1, 2, 4, 6], vec![1, 3, 4, 5]] });
-    assert_eq!(parents[1], Parents { children: vec![1, 6], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[2], Parents { children: vec![2, 9], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[3], Parents { children: vec![3, 10, 18], sum: 8, value_size: "8-3".to_string(), combinations: vec![vec![1, 2, 5], vec![1, 3, 4]] });
-    assert_eq!(parents[4], Parents { children: vec![4, 13], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[5], Parents { children: vec![0, 1], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[6], Parents { children: vec![7, 15], sum: 13, value_size: "13-2".to_string(), combinations: vec![vec![4, 9], vec![5, 8], vec![6, 7]] });
-    assert_eq!(parents[7], Parents { children: vec![8, 17, 25], sum: 6, value_size: "6-3".to_string(), combinations: vec![vec![1, 2, 3]] });
-    assert_eq!(parents[8], Parents { children: vec![2, 3], sum: 5, value_size: "5-2".to_string(), combinations: vec![vec![1, 4], vec![2, 3]] });
-    assert_eq!(parents[9], Parents { children: vec![11, 20, 28], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[10], Parents { children: vec![12, 21, 29], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[11], Parents { children: vec![4, 5, 6, 7], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[12], Parents { children: vec![16, 24], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[13], Parents { children: vec![8, 9, 10], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[14], Parents { children: vec![19, 27, 34, 41], sum: 22, value_size: "22-4".to_string(), combinations: vec![vec![1, 4, 8, 9], vec![1, 5, 7, 9], vec![1, 6, 7, 8], vec![2, 3, 8, 9], vec![2, 4, 7, 9], vec![2, 5, 6, 9], vec![2, 5, 7, 8], vec![3, 4, 6, 9], vec![3, 4, 7, 8], vec![3, 5, 6, 8], vec![4, 5, 6, 7]] });
-    assert_eq!(parents[15], Parents { children: vec![11, 12], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[16], Parents { children: vec![13, 14], sum: 12, value_size: "12-2".to_string(), combinations: vec![vec![3, 9], vec![4, 8], vec![5, 7]] });
-    assert_eq!(parents[17], Parents { children: vec![23, 30, 36], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[18], Parents { children: vec![15, 16, 17], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[19], Parents { children: vec![26, 32, 39, 49], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[20], Parents { children: vec![18, 19, 20, 21], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[21], Parents { children: vec![22, 23], sum: 10, value_size: "10-2".to_string(), combinations: vec![vec![1, 9], vec![2, 8], vec![3, 7], vec![4, 6]] });
-    assert_eq!(parents[22], Parents { children: vec![31, 37, 46, 56, 63], sum: 34, value_size: "34-5".to_string(), combinations: vec![vec![4, 6, 7, 8, 9]] });
-    assert_eq!(parents[23], Parents { children: vec![24, 25, 26], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[24], Parents { children: vec![33, 40, 50, 59, 65], sum: 31, value_size: "31-5".to_string(), combinations: vec![vec![1, 6, 7, 8, 9], vec![2, 5, 7, 8, 9], vec![3, 4, 7, 8, 9], vec![3, 5, 6, 8, 9], vec![4, 5, 6, 7, 9]] });
-    assert_eq!(parents[25], Parents { children: vec![27, 28, 29], sum: 18, value_size: "18-3".to_string(), combinations: vec![vec![1, 8, 9], vec![2, 7, 9], vec![3, 6, 9], vec![3, 7, 8], vec![4, 5, 9], vec![4, 6, 8], vec![5, 6, 7]] });
-    assert_eq!(parents[26], Parents { children: vec![35, 45, 54], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[27], Parents { children: vec![30, 31], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[28], Parents { children: vec![38, 48, 58], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[29], Parents { children: vec![32, 33, 34], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[30], Parents { children: vec![42, 51, 61], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[31], Parents { children: vec![43, 52], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[32], Parents { children: vec![44, 53], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[33], Parents { children: vec![35, 36, 37], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[34], Parents { children: vec![47, 57, 64, 70], sum: 24, value_size: "24-4".to_string(), combinations: vec![vec![1, 6, 8, 9], vec![2, 5, 8, 9], vec![2, 6, 7, 9], vec![3, 4, 8, 9], vec![3, 5, 7, 9], vec![3, 6, 7, 8], vec![4, 5, 6, 9], vec![4, 5, 7, 8]] });
-    assert_eq!(parents[35], Parents { children: vec![38, 39, 40, 41, 42, 43], sum: 39, value_size: "39-6".to_string(), combinations: vec![vec![4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[36], Parents { children: vec![44, 45], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[37], Parents { children: vec![55, 62, 69, 77], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[38], Parents { children: vec![46, 47, 48, 49, 50], sum: 35, value_size: "35-5".to_string(), combinations: vec![vec![5, 6, 7, 8, 9]] });
-    assert_eq!(parents[39], Parents { children: vec![60, 66, 73], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[40], Parents { children: vec![51, 52], sum: 9, value_size: "9-2".to_string(), combinations: vec![vec![1, 8], vec![2, 7], vec![3, 6], vec![4, 5]] });
-    assert_eq!(parents[41], Parents { children: vec![53, 54, 55, 56, 57, 58], sum: 34, value_size: "34-6".to_string(), combinations: vec![vec![1, 3, 6, 7, 8, 9], vec![1, 4, 5, 7, 8, 9], vec![2, 3, 5, 7, 8, 9], vec![2, 4, 5, 6, 8, 9], vec![3, 4, 5, 6, 7, 9]] });
-    assert_eq!(parents[42], Parents { children: vec![59, 60, 61], sum: 6, value_size: "6-3".to_string(), combinations: vec![vec![1, 2, 3]] });
-    assert_eq!(parents[43], Parents { children: vec![67, 75, 84], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[44], Parents { children: vec![68, 76, 85], sum: 12, value_size: "12-3".to_string(), combinations: vec![vec![1, 2, 9], vec![1, 3, 8], vec![1, 4, 7], vec![1, 5, 6], vec![2, 3, 7], vec![2, 4, 6], vec![3, 4, 5]] });
-    assert_eq!(parents[45], Parents { children: vec![62, 63, 64], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[46], Parents { children: vec![71, 79, 88], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[47], Parents { children: vec![72, 80], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[48], Parents { children: vec![65, 66], sum: 7, value_size: "7-2".to_string(), combinations: vec![vec![1, 6], vec![2, 5], vec![3, 4]] });
-    assert_eq!(parents[49], Parents { children: vec![74, 82, 91, 96], sum: 29, value_size: "29-4".to_string(), combinations: vec![vec![5, 7, 8, 9]] });
-    assert_eq!(parents[50], Parents { children: vec![67, 68, 69], sum: 12, value_size: "12-3".to_string(), combinations: vec![vec![1, 2, 9], vec![1, 3, 8], vec![1, 4, 7], vec![1, 5, 6], vec![2, 3, 7], vec![2, 4, 6], vec![3, 4, 5]] });
-    assert_eq!(parents[51], Parents { children: vec![78, 86, 93], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[52], Parents { children: vec![70, 71, 72], sum: 18, value_size: "18-3".to_string(), combinations: vec![vec![1, 8, 9], vec![2, 7, 9], vec![3, 6, 9], vec![3, 7, 8], vec![4, 5, 9], vec![4, 6, 8], vec![5, 6, 7]] });
-    assert_eq!(parents[53], Parents { children: vec![81, 89], sum: 9, value_size: "9-2".to_string(), combinations: vec![vec![1, 8], vec![2, 7], vec![3, 6], vec![4, 5]] });
-    assert_eq!(parents[54], Parents { children: vec![73, 74], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[55], Parents { children: vec![83, 92], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[56], Parents { children: vec![75, 76, 77, 78], sum: 29, value_size: "29-4".to_string(), combinations: vec![vec![5, 7, 8, 9]] });
-    assert_eq!(parents[57], Parents { children: vec![87, 94], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[58], Parents { children: vec![79, 80, 81], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[59], Parents { children: vec![90, 95], sum: 10, value_size: "10-2".to_string(), combinations: vec![vec![1, 9], vec![2, 8], vec![3, 7], vec![4, 6]] });
-    assert_eq!(parents[60], Parents { children: vec![82, 83], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[61], Parents { children: vec![84, 85], sum: 8, value_size: "8-2".to_string(), combinations: vec![vec![1, 7], vec![2, 6], vec![3, 5]] });
-    assert_eq!(parents[62], Parents { children: vec![86, 87, 88], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[63], Parents { children: vec![89, 90, 91, 92], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[64], Parents { children: vec![93, 94], sum: 12, value_size: "12-2".to_string(), combinations: vec![vec![3, 9], vec![4, 8], vec![5, 7]] });
-    assert_eq!(parents[65], Parents { children: vec![95, 96], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
+    assert_eq!(parents[0], Parents { children: cells(&[0, 5, 14, 22]), sum: 13, value_size: "13-4".to_string(), combinations: vec![digits(&[1, 2, 3, 7]), digits(&[1, 2, 4, 6]), digits(&[1, 3, 4, 5])], direction: Direction::Down, head: (0, 2) });
+    assert_eq!(parents[1], Parents { children: cells(&[1, 6]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Down, head: (0, 3) });
+    assert_eq!(parents[2], Parents { children: cells(&[2, 9]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (0, 7) });
+    assert_eq!(parents[3], Parents { children: cells(&[3, 10, 18]), sum: 8, value_size: "8-3".to_string(), combinations: vec![digits(&[1, 2, 5]), digits(&[1, 3, 4])], direction: Direction::Down, head: (0, 8) });
+    assert_eq!(parents[4], Parents { children: cells(&[4, 13]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Down, head: (1, 1) });
+    assert_eq!(parents[5], Parents { children: cells(&[0, 1]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Across, head: (1, 1) });
+    assert_eq!(parents[6], Parents { children: cells(&[7, 15]), sum: 13, value_size: "13-2".to_string(), combinations: vec![digits(&[4, 9]), digits(&[5, 8]), digits(&[6, 7])], direction: Direction::Down, head: (1, 4) });
+    assert_eq!(parents[7], Parents { children: cells(&[8, 17, 25]), sum: 6, value_size: "6-3".to_string(), combinations: vec![digits(&[1, 2, 3])], direction: Direction::Down, head: (1, 6) });
+    assert_eq!(parents[8], Parents { children: cells(&[2, 3]), sum: 5, value_size: "5-2".to_string(), combinations: vec![digits(&[1, 4]), digits(&[2, 3])], direction: Direction::Across, head: (1, 6) });
+    assert_eq!(parents[9], Parents { children: cells(&[11, 20, 28]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (1, 10) });
+    assert_eq!(parents[10], Parents { children: cells(&[12, 21, 29]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Down, head: (1, 11) });
+    assert_eq!(parents[11], Parents { children: cells(&[4, 5, 6, 7]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Across, head: (2, 0) });
+    assert_eq!(parents[12], Parents { children: cells(&[16, 24]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (2, 5) });
+    assert_eq!(parents[13], Parents { children: cells(&[8, 9, 10]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (2, 5) });
+    assert_eq!(parents[14], Parents { children: cells(&[19, 27, 34, 41]), sum: 22, value_size: "22-4".to_string(), combinations: vec![digits(&[1, 4, 8, 9]), digits(&[1, 5, 7, 9]), digits(&[1, 6, 7, 8]), digits(&[2, 3, 8, 9]), digits(&[2, 4, 7, 9]), digits(&[2, 5, 6, 9]), digits(&[2, 5, 7, 8]), digits(&[3, 4, 6, 9]), digits(&[3, 4, 7, 8]), digits(&[3, 5, 6, 8]), digits(&[4, 5, 6, 7])], direction: Direction::Down, head: (2, 9) });
+    assert_eq!(parents[15], Parents { children: cells(&[11, 12]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (2, 9) });
+    assert_eq!(parents[16], Parents { children: cells(&[13, 14]), sum: 12, value_size: "12-2".to_string(), combinations: vec![digits(&[3, 9]), digits(&[4, 8]), digits(&[5, 7])], direction: Direction::Across, head: (3, 0) });
+    assert_eq!(parents[17], Parents { children: cells(&[23, 30, 36]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Down, head: (3, 3) });
+    assert_eq!(parents[18], Parents { children: cells(&[15, 16, 17]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Across, head: (3, 3) });
+    assert_eq!(parents[19], Parents { children: cells(&[26, 32, 39, 49]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Down, head: (3, 7) });
+    assert_eq!(parents[20], Parents { children: cells(&[18, 19, 20, 21]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Across, head: (3, 7) });
+    assert_eq!(parents[21], Parents { children: cells(&[22, 23]), sum: 10, value_size: "10-2".to_string(), combinations: vec![digits(&[1, 9]), digits(&[2, 8]), digits(&[3, 7]), digits(&[4, 6])], direction: Direction::Across, head: (4, 1) });
+    assert_eq!(parents[22], Parents { children: cells(&[31, 37, 46, 56, 63]), sum: 34, value_size: "34-5".to_string(), combinations: vec![digits(&[4, 6, 7, 8, 9])], direction: Direction::Down, head: (4, 4) });
+    assert_eq!(parents[23], Parents { children: cells(&[24, 25, 26]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Across, head: (4, 4) });
+    assert_eq!(parents[24], Parents { children: cells(&[33, 40, 50, 59, 65]), sum: 31, value_size: "31-5".to_string(), combinations: vec![digits(&[1, 6, 7, 8, 9]), digits(&[2, 5, 7, 8, 9]), digits(&[3, 4, 7, 8, 9]), digits(&[3, 5, 6, 8, 9]), digits(&[4, 5, 6, 7, 9])], direction: Direction::Down, head: (4, 8) });
+    assert_eq!(parents[25], Parents { children: cells(&[27, 28, 29]), sum: 18, value_size: "18-3".to_string(), combinations: vec![digits(&[1, 8, 9]), digits(&[2, 7, 9]), digits(&[3, 6, 9]), digits(&[3, 7, 8]), digits(&[4, 5, 9]), digits(&[4, 6, 8]), digits(&[5, 6, 7])], direction: Direction::Across, head: (4, 8) });
+    assert_eq!(parents[26], Parents { children: cells(&[35, 45, 54]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Down, head: (5, 2) });
+    assert_eq!(parents[27], Parents { children: cells(&[30, 31]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (5, 2) });
+    assert_eq!(parents[28], Parents { children: cells(&[38, 48, 58]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Down, head: (5, 6) });
+    assert_eq!(parents[29], Parents { children: cells(&[32, 33, 34]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (5, 6) });
+    assert_eq!(parents[30], Parents { children: cells(&[42, 51, 61]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Down, head: (5, 10) });
+    assert_eq!(parents[31], Parents { children: cells(&[43, 52]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Down, head: (5, 11) });
+    assert_eq!(parents[32], Parents { children: cells(&[44, 53]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Down, head: (6, 1) });
+    assert_eq!(parents[33], Parents { children: cells(&[35, 36, 37]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (6, 1) });
+    assert_eq!(parents[34], Parents { children: cells(&[47, 57, 64, 70]), sum: 24, value_size: "24-4".to_string(), combinations: vec![digits(&[1, 6, 8, 9]), digits(&[2, 5, 8, 9]), digits(&[2, 6, 7, 9]), digits(&[3, 4, 8, 9]), digits(&[3, 5, 7, 9]), digits(&[3, 6, 7, 8]), digits(&[4, 5, 6, 9]), digits(&[4, 5, 7, 8])], direction: Direction::Down, head: (6, 5) });
+    assert_eq!(parents[35], Parents { children: cells(&[38, 39, 40, 41, 42, 43]), sum: 39, value_size: "39-6".to_string(), combinations: vec![digits(&[4, 5, 6, 7, 8, 9])], direction: Direction::Across, head: (6, 5) });
+    assert_eq!(parents[36], Parents { children: cells(&[44, 45]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Across, head: (7, 0) });
+    assert_eq!(parents[37], Parents { children: cells(&[55, 62, 69, 77]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Down, head: (7, 3) });
+    assert_eq!(parents[38], Parents { children: cells(&[46, 47, 48, 49, 50]), sum: 35, value_size: "35-5".to_string(), combinations: vec![digits(&[5, 6, 7, 8, 9])], direction: Direction::Across, head: (7, 3) });
+    assert_eq!(parents[39], Parents { children: cells(&[60, 66, 73]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Down, head: (7, 9) });
+    assert_eq!(parents[40], Parents { children: cells(&[51, 52]), sum: 9, value_size: "9-2".to_string(), combinations: vec![digits(&[1, 8]), digits(&[2, 7]), digits(&[3, 6]), digits(&[4, 5])], direction: Direction::Across, head: (7, 9) });
+    assert_eq!(parents[41], Parents { children: cells(&[53, 54, 55, 56, 57, 58]), sum: 34, value_size: "34-6".to_string(), combinations: vec![digits(&[1, 3, 6, 7, 8, 9]), digits(&[1, 4, 5, 7, 8, 9]), digits(&[2, 3, 5, 7, 8, 9]), digits(&[2, 4, 5, 6, 8, 9]), digits(&[3, 4, 5, 6, 7, 9])], direction: Direction::Across, head: (8, 0) });
+    assert_eq!(parents[42], Parents { children: cells(&[59, 60, 61]), sum: 6, value_size: "6-3".to_string(), combinations: vec![digits(&[1, 2, 3])], direction: Direction::Across, head: (8, 7) });
+    assert_eq!(parents[43], Parents { children: cells(&[67, 75, 84]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Down, head: (9, 1) });
+    assert_eq!(parents[44], Parents { children: cells(&[68, 76, 85]), sum: 12, value_size: "12-3".to_string(), combinations: vec![digits(&[1, 2, 9]), digits(&[1, 3, 8]), digits(&[1, 4, 7]), digits(&[1, 5, 6]), digits(&[2, 3, 7]), digits(&[2, 4, 6]), digits(&[3, 4, 5])], direction: Direction::Down, head: (9, 2) });
+    assert_eq!(parents[45], Parents { children: cells(&[62, 63, 64]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (9, 2) });
+    assert_eq!(parents[46], Parents { children: cells(&[71, 79, 88]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (9, 6) });
+    assert_eq!(parents[47], Parents { children: cells(&[72, 80]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Down, head: (9, 7) });
+    assert_eq!(parents[48], Parents { children: cells(&[65, 66]), sum: 7, value_size: "7-2".to_string(), combinations: vec![digits(&[1, 6]), digits(&[2, 5]), digits(&[3, 4])], direction: Direction::Across, head: (9, 7) });
+    assert_eq!(parents[49], Parents { children: cells(&[74, 82, 91, 96]), sum: 29, value_size: "29-4".to_string(), combinations: vec![digits(&[5, 7, 8, 9])], direction: Direction::Down, head: (9, 10) });
+    assert_eq!(parents[50], Parents { children: cells(&[67, 68, 69]), sum: 12, value_size: "12-3".to_string(), combinations: vec![digits(&[1, 2, 9]), digits(&[1, 3, 8]), digits(&[1, 4, 7]), digits(&[1, 5, 6]), digits(&[2, 3, 7]), digits(&[2, 4, 6]), digits(&[3, 4, 5])], direction: Direction::Across, head: (10, 0) });
+    assert_eq!(parents[51], Parents { children: cells(&[78, 86, 93]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Down, head: (10, 4) });
+    assert_eq!(parents[52], Parents { children: cells(&[70, 71, 72]), sum: 18, value_size: "18-3".to_string(), combinations: vec![digits(&[1, 8, 9]), digits(&[2, 7, 9]), digits(&[3, 6, 9]), digits(&[3, 7, 8]), digits(&[4, 5, 9]), digits(&[4, 6, 8]), digits(&[5, 6, 7])], direction: Direction::Across, head: (10, 4) });
+    assert_eq!(parents[53], Parents { children: cells(&[81, 89]), sum: 9, value_size: "9-2".to_string(), combinations: vec![digits(&[1, 8]), digits(&[2, 7]), digits(&[3, 6]), digits(&[4, 5])], direction: Direction::Down, head: (10, 8) });
+    assert_eq!(parents[54], Parents { children: cells(&[73, 74]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Across, head: (10, 8) });
+    assert_eq!(parents[55], Parents { children: cells(&[83, 92]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Down, head: (10, 11) });
+    assert_eq!(parents[56], Parents { children: cells(&[75, 76, 77, 78]), sum: 29, value_size: "29-4".to_string(), combinations: vec![digits(&[5, 7, 8, 9])], direction: Direction::Across, head: (11, 0) });
+    assert_eq!(parents[57], Parents { children: cells(&[87, 94]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (11, 5) });
+    assert_eq!(parents[58], Parents { children: cells(&[79, 80, 81]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Across, head: (11, 5) });
+    assert_eq!(parents[59], Parents { children: cells(&[90, 95]), sum: 10, value_size: "10-2".to_string(), combinations: vec![digits(&[1, 9]), digits(&[2, 8]), digits(&[3, 7]), digits(&[4, 6])], direction: Direction::Down, head: (11, 9) });
+    assert_eq!(parents[60], Parents { children: cells(&[82, 83]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Across, head: (11, 9) });
+    assert_eq!(parents[61], Parents { children: cells(&[84, 85]), sum: 8, value_size: "8-2".to_string(), combinations: vec![digits(&[1, 7]), digits(&[2, 6]), digits(&[3, 5])], direction: Direction::Across, head: (12, 0) });
+    assert_eq!(parents[62], Parents { children: cells(&[86, 87, 88]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Across, head: (12, 3) });
+    assert_eq!(parents[63], Parents { children: cells(&[89, 90, 91, 92]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Across, head: (12, 7) });
+    assert_eq!(parents[64], Parents { children: cells(&[93, 94]), sum: 12, value_size: "12-2".to_string(), combinations: vec![digits(&[3, 9]), digits(&[4, 8]), digits(&[5, 7])], direction: Direction::Across, head: (13, 3) });
+    assert_eq!(parents[65], Parents { children: cells(&[95, 96]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (13, 8) });
   
-    assert_eq!(children[0], Children { parents: (0, 5), siblings: vec![1, 5, 14, 22], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[1], Children { parents: (1, 5), siblings: vec![0, 6], value: 0, possible_values: vec![3] });
-    assert_eq!(children[2], Children { parents: (2, 8), siblings: vec![3, 9], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[3], Children { parents: (3, 8), siblings: vec![2, 10, 18], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[4], Children { parents: (4, 11), siblings: vec![5, 6, 7, 13], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[5], Children { parents: (0, 11), siblings: vec![0, 4, 6, 7, 14, 22], value: 0, possible_values: vec![6, 7] });
-    assert_eq!(children[6], Children { parents: (1, 11), siblings: vec![1, 4, 5, 7], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[7], Children { parents: (6, 11), siblings: vec![4, 5, 6, 15], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[8], Children { parents: (7, 13), siblings: vec![9, 10, 17, 25], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[9], Children { parents: (2, 13), siblings: vec![2, 8, 10], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[10], Children { parents: (3, 13), siblings: vec![3, 8, 9, 18], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[11], Children { parents: (9, 15), siblings: vec![12, 20, 28], value: 0, possible_values: vec![7] });
-    assert_eq!(children[12], Children { parents: (10, 15), siblings: vec![11, 21, 29], value: 0, possible_values: vec![9] });
-    assert_eq!(children[13], Children { parents: (4, 16), siblings: vec![4, 14], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[14], Children { parents: (0, 16), siblings: vec![0, 5, 13, 22], value: 0, possible_values: vec![3, 4, 5, 7] });
-    assert_eq!(children[15], Children { parents: (6, 18), siblings: vec![7, 16, 17], value: 0, possible_values: vec![4, 5, 6, 7] });
-    assert_eq!(children[16], Children { parents: (12, 18), siblings: vec![15, 17, 24], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[17], Children { parents: (7, 18), siblings: vec![8, 15, 16, 25], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[18], Children { parents: (3, 20), siblings: vec![3, 10, 19, 20, 21], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[19], Children { parents: (14, 20), siblings: vec![18, 20, 21, 27, 34, 41], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[20], Children { parents: (9, 20), siblings: vec![11, 18, 19, 21, 28], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[21], Children { parents: (10, 20), siblings: vec![12, 18, 19, 20, 29], value: 0, possible_values: vec![6] });
-    assert_eq!(children[22], Children { parents: (0, 21), siblings: vec![0, 5, 14, 23], value: 0, possible_values: vec![1, 2, 3, 4, 6, 7] });
-    assert_eq!(children[23], Children { parents: (17, 21), siblings: vec![22, 30, 36], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[24], Children { parents: (12, 23), siblings: vec![16, 25, 26], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[25], Children { parents: (7, 23), siblings: vec![8, 17, 24, 26], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[26], Children { parents: (19, 23), siblings: vec![24, 25, 32, 39, 49], value: 0, possible_values: vec![6, 7] });
-    assert_eq!(children[27], Children { parents: (14, 25), siblings: vec![19, 28, 29, 34, 41], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[28], Children { parents: (9, 25), siblings: vec![11, 20, 27, 29], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[29], Children { parents: (10, 25), siblings: vec![12, 21, 27, 28], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[30], Children { parents: (17, 27), siblings: vec![23, 31, 36], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[31], Children { parents: (22, 27), siblings: vec![30, 37, 46, 56, 63], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[32], Children { parents: (19, 29), siblings: vec![26, 33, 34, 39, 49], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[33], Children { parents: (24, 29), siblings: vec![32, 34, 40, 50, 59, 65], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[34], Children { parents: (14, 29), siblings: vec![19, 27, 32, 33, 41], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[35], Children { parents: (26, 33), siblings: vec![36, 37, 45, 54], value: 0, possible_values: vec![6] });
-    assert_eq!(children[36], Children { parents: (17, 33), siblings: vec![23, 30, 35, 37], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[37], Children { parents: (22, 33), siblings: vec![31, 35, 36, 46, 56, 63], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[38], Children { parents: (28, 35), siblings: vec![39, 40, 41, 42, 43, 48, 58], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[39], Children { parents: (19, 35), siblings: vec![26, 32, 38, 40, 41, 42, 43, 49], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[40], Children { parents: (24, 35), siblings: vec![33, 38, 39, 41, 42, 43, 50, 59, 65], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[41], Children { parents: (14, 35), siblings: vec![19, 27, 34, 38, 39, 40, 42, 43], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[42], Children { parents: (30, 35), siblings: vec![38, 39, 40, 41, 43, 51, 61], value: 0, possible_values: vec![4] });
-    assert_eq!(children[43], Children { parents: (31, 35), siblings: vec![38, 39, 40, 41, 42, 52], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[44], Children { parents: (32, 36), siblings: vec![45, 53], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[45], Children { parents: (26, 36), siblings: vec![35, 44, 54], value: 0, possible_values: vec![2, 3, 4, 5, 6] });
-    assert_eq!(children[46], Children { parents: (22, 38), siblings: vec![31, 37, 47, 48, 49, 50, 56, 63], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[47], Children { parents: (34, 38), siblings: vec![46, 48, 49, 50, 57, 64, 70], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[48], Children { parents: (28, 38), siblings: vec![38, 46, 47, 49, 50, 58], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[49], Children { parents: (19, 38), siblings: vec![26, 32, 39, 46, 47, 48, 50], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[50], Children { parents: (24, 38), siblings: vec![33, 40, 46, 47, 48, 49, 59, 65], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[51], Children { parents: (30, 40), siblings: vec![42, 52, 61], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[52], Children { parents: (31, 40), siblings: vec![43, 51], value: 0, possible_values: vec![7] });
-    assert_eq!(children[53], Children { parents: (32, 41), siblings: vec![44, 54, 55, 56, 57, 58], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[54], Children { parents: (26, 41), siblings: vec![35, 45, 53, 55, 56, 57, 58], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[55], Children { parents: (37, 41), siblings: vec![53, 54, 56, 57, 58, 62, 69, 77], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[56], Children { parents: (22, 41), siblings: vec![31, 37, 46, 53, 54, 55, 57, 58, 63], value: 0, possible_values: vec![4, 6, 7, 8, 9] });
-    assert_eq!(children[57], Children { parents: (34, 41), siblings: vec![47, 53, 54, 55, 56, 58, 64, 70], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[58], Children { parents: (28, 41), siblings: vec![38, 48, 53, 54, 55, 56, 57], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[59], Children { parents: (24, 42), siblings: vec![33, 40, 50, 60, 61, 65], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[60], Children { parents: (39, 42), siblings: vec![59, 61, 66, 73], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[61], Children { parents: (30, 42), siblings: vec![42, 51, 59, 60], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[62], Children { parents: (37, 45), siblings: vec![55, 63, 64, 69, 77], value: 0, possible_values: vec![1, 2]});
-    assert_eq!(children[63], Children { parents: (22, 45), siblings: vec![31, 37, 46, 56, 62, 64], value: 0, possible_values: vec![4] });
-    assert_eq!(children[64], Children { parents: (34, 45), siblings: vec![47, 57, 62, 63, 70], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[65], Children { parents: (24, 48), siblings: vec![33, 40, 50, 59, 66], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[66], Children { parents: (39, 48), siblings: vec![60, 65, 73], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[67], Children { parents: (43, 50), siblings: vec![68, 69, 75, 84], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[68], Children { parents: (44, 50), siblings: vec![67, 69, 76, 85], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[69], Children { parents: (37, 50), siblings: vec![55, 62, 67, 68, 77], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[70], Children { parents: (34, 52), siblings: vec![47, 57, 64, 71, 72], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[71], Children { parents: (46, 52), siblings: vec![70, 72, 79, 88], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[72], Children { parents: (47, 52), siblings: vec![70, 71, 80], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[73], Children { parents: (39, 54), siblings: vec![60, 66, 74], value: 0, possible_values: vec![2, 4] });
-    assert_eq!(children[74], Children { parents: (49, 54), siblings: vec![73, 82, 91, 96], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[75], Children { parents: (43, 56), siblings: vec![67, 76, 77, 78, 84], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[76], Children { parents: (44, 56), siblings: vec![68, 75, 77, 78, 85], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[77], Children { parents: (37, 56), siblings: vec![55, 62, 69, 75, 76, 78], value: 0, possible_values: vec![5] });
-    assert_eq!(children[78], Children { parents: (51, 56), siblings: vec![75, 76, 77, 86, 93], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[79], Children { parents: (46, 58), siblings: vec![71, 80, 81, 88], value: 0, possible_values: vec![7, 8] });
-    assert_eq!(children[80], Children { parents: (47, 58), siblings: vec![72, 79, 81], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[81], Children { parents: (53, 58), siblings: vec![79, 80, 89], value: 0, possible_values: vec![7, 8] });
-    assert_eq!(children[82], Children { parents: (49, 60), siblings: vec![74, 83, 91, 96], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[83], Children { parents: (55, 60), siblings: vec![82, 92], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[84], Children { parents: (43, 61), siblings: vec![67, 75, 85], value: 0, possible_values: vec![6] });
-    assert_eq!(children[85], Children { parents: (44, 61), siblings: vec![68, 76, 84], value: 0, possible_values: vec![1, 2, 3, 5, 6, 7] });
-    assert_eq!(children[86], Children { parents: (51, 62), siblings: vec![78, 87, 88, 93], value: 0, possible_values: vec![7, 8] });
-    assert_eq!(children[87], Children { parents: (57, 62), siblings: vec![86, 88, 94], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[88], Children { parents: (46, 62), siblings: vec![71, 79, 86, 87], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[89], Children { parents: (53, 63), siblings: vec![81, 90, 91, 92], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[90], Children { parents: (59, 63), siblings: vec![89, 91, 92, 95], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[91], Children { parents: (49, 63), siblings: vec![74, 82, 89, 90, 92, 96], value: 0, possible_values: vec![5] });
-    assert_eq!(children[92], Children { parents: (55, 63), siblings: vec![83, 89, 90, 91], value: 0, possible_values: vec![2, 3, 5] });
-    assert_eq!(children[93], Children { parents: (51, 64), siblings: vec![78, 86, 94], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[94], Children { parents: (57, 64), siblings: vec![87, 93], value: 0, possible_values: vec![3] });
-    assert_eq!(children[95], Children { parents: (59, 65), siblings: vec![90, 96], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[96], Children { parents: (49, 65), siblings: vec![74, 82, 91, 95], value: 0, possible_values: vec![7, 9] });
+    assert_eq!(children[0], Children { parents: runs(0, 5), siblings: cells(&[1, 5, 14, 22]), value: 0, possible_values: digits(&[1, 3]), position: (1, 2) });
+    assert_eq!(children[1], Children { parents: runs(1, 5), siblings: cells(&[0, 6]), value: 0, possible_values: digits(&[3]), position: (1, 3) });
+    assert_eq!(children[2], Children { parents: runs(2, 8), siblings: cells(&[3, 9]), value: 0, possible_values: digits(&[1, 2]), position: (1, 7) });
+    assert_eq!(children[3], Children { parents: runs(3, 8), siblings: cells(&[2, 10, 18]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (1, 8) });
+    assert_eq!(children[4], Children { parents: runs(4, 11), siblings: cells(&[5, 6, 7, 13]), value: 0, possible_values: digits(&[8, 9]), position: (2, 1) });
+    assert_eq!(children[5], Children { parents: runs(0, 11), siblings: cells(&[0, 4, 6, 7, 14, 22]), value: 0, possible_values: digits(&[6, 7]), position: (2, 2) });
+    assert_eq!(children[6], Children { parents: runs(1, 11), siblings: cells(&[1, 4, 5, 7]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (2, 3) });
+    assert_eq!(children[7], Children { parents: runs(6, 11), siblings: cells(&[4, 5, 6, 15]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (2, 4) });
+    assert_eq!(children[8], Children { parents: runs(7, 13), siblings: cells(&[9, 10, 17, 25]), value: 0, possible_values: digits(&[1, 2]), position: (2, 6) });
+    assert_eq!(children[9], Children { parents: runs(2, 13), siblings: cells(&[2, 8, 10]), value: 0, possible_values: digits(&[1, 2]), position: (2, 7) });
+    assert_eq!(children[10], Children { parents: runs(3, 13), siblings: cells(&[3, 8, 9, 18]), value: 0, possible_values: digits(&[1, 2, 4]), position: (2, 8) });
+    assert_eq!(children[11], Children { parents: runs(9, 15), siblings: cells(&[12, 20, 28]), value: 0, possible_values: digits(&[7]), position: (2, 10) });
+    assert_eq!(children[12], Children { parents: runs(10, 15), siblings: cells(&[11, 21, 29]), value: 0, possible_values: digits(&[9]), position: (2, 11) });
+    assert_eq!(children[13], Children { parents: runs(4, 16), siblings: cells(&[4, 14]), value: 0, possible_values: digits(&[8, 9]), position: (3, 1) });
+    assert_eq!(children[14], Children { parents: runs(0, 16), siblings: cells(&[0, 5, 13, 22]), value: 0, possible_values: digits(&[3, 4, 5, 7]), position: (3, 2) });
+    assert_eq!(children[15], Children { parents: runs(6, 18), siblings: cells(&[7, 16, 17]), value: 0, possible_values: digits(&[4, 5, 6, 7]), position: (3, 4) });
+    assert_eq!(children[16], Children { parents: runs(12, 18), siblings: cells(&[15, 17, 24]), value: 0, possible_values: digits(&[1, 2]), position: (3, 5) });
+    assert_eq!(children[17], Children { parents: runs(7, 18), siblings: cells(&[8, 15, 16, 25]), value: 0, possible_values: digits(&[1, 2, 3]), position: (3, 6) });
+    assert_eq!(children[18], Children { parents: runs(3, 20), siblings: cells(&[3, 10, 19, 20, 21]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (3, 8) });
+    assert_eq!(children[19], Children { parents: runs(14, 20), siblings: cells(&[18, 20, 21, 27, 34, 41]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (3, 9) });
+    assert_eq!(children[20], Children { parents: runs(9, 20), siblings: cells(&[11, 18, 19, 21, 28]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (3, 10) });
+    assert_eq!(children[21], Children { parents: runs(10, 20), siblings: cells(&[12, 18, 19, 20, 29]), value: 0, possible_values: digits(&[6]), position: (3, 11) });
+    assert_eq!(children[22], Children { parents: runs(0, 21), siblings: cells(&[0, 5, 14, 23]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6, 7]), position: (4, 2) });
+    assert_eq!(children[23], Children { parents: runs(17, 21), siblings: cells(&[22, 30, 36]), value: 0, possible_values: digits(&[7, 8, 9]), position: (4, 3) });
+    assert_eq!(children[24], Children { parents: runs(12, 23), siblings: cells(&[16, 25, 26]), value: 0, possible_values: digits(&[1, 2]), position: (4, 5) });
+    assert_eq!(children[25], Children { parents: runs(7, 23), siblings: cells(&[8, 17, 24, 26]), value: 0, possible_values: digits(&[1, 2, 3]), position: (4, 6) });
+    assert_eq!(children[26], Children { parents: runs(19, 23), siblings: cells(&[24, 25, 32, 39, 49]), value: 0, possible_values: digits(&[6, 7]), position: (4, 7) });
+    assert_eq!(children[27], Children { parents: runs(14, 25), siblings: cells(&[19, 28, 29, 34, 41]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (4, 9) });
+    assert_eq!(children[28], Children { parents: runs(9, 25), siblings: cells(&[11, 20, 27, 29]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (4, 10) });
+    assert_eq!(children[29], Children { parents: runs(10, 25), siblings: cells(&[12, 21, 27, 28]), value: 0, possible_values: digits(&[6, 8, 9]), position: (4, 11) });
+    assert_eq!(children[30], Children { parents: runs(17, 27), siblings: cells(&[23, 31, 36]), value: 0, possible_values: digits(&[7, 9]), position: (5, 3) });
+    assert_eq!(children[31], Children { parents: runs(22, 27), siblings: cells(&[30, 37, 46, 56, 63]), value: 0, possible_values: digits(&[7, 9]), position: (5, 4) });
+    assert_eq!(children[32], Children { parents: runs(19, 29), siblings: cells(&[26, 33, 34, 39, 49]), value: 0, possible_values: digits(&[6, 8, 9]), position: (5, 7) });
+    assert_eq!(children[33], Children { parents: runs(24, 29), siblings: cells(&[32, 34, 40, 50, 59, 65]), value: 0, possible_values: digits(&[6, 8, 9]), position: (5, 8) });
+    assert_eq!(children[34], Children { parents: runs(14, 29), siblings: cells(&[19, 27, 32, 33, 41]), value: 0, possible_values: digits(&[6, 8, 9]), position: (5, 9) });
+    assert_eq!(children[35], Children { parents: runs(26, 33), siblings: cells(&[36, 37, 45, 54]), value: 0, possible_values: digits(&[6]), position: (6, 2) });
+    assert_eq!(children[36], Children { parents: runs(17, 33), siblings: cells(&[23, 30, 35, 37]), value: 0, possible_values: digits(&[8, 9]), position: (6, 3) });
+    assert_eq!(children[37], Children { parents: runs(22, 33), siblings: cells(&[31, 35, 36, 46, 56, 63]), value: 0, possible_values: digits(&[6, 8, 9]), position: (6, 4) });
+    assert_eq!(children[38], Children { parents: runs(28, 35), siblings: cells(&[39, 40, 41, 42, 43, 48, 58]), value: 0, possible_values: digits(&[7, 8, 9]), position: (6, 6) });
+    assert_eq!(children[39], Children { parents: runs(19, 35), siblings: cells(&[26, 32, 38, 40, 41, 42, 43, 49]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (6, 7) });
+    assert_eq!(children[40], Children { parents: runs(24, 35), siblings: cells(&[33, 38, 39, 41, 42, 43, 50, 59, 65]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (6, 8) });
+    assert_eq!(children[41], Children { parents: runs(14, 35), siblings: cells(&[19, 27, 34, 38, 39, 40, 42, 43]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (6, 9) });
+    assert_eq!(children[42], Children { parents: runs(30, 35), siblings: cells(&[38, 39, 40, 41, 43, 51, 61]), value: 0, possible_values: digits(&[4]), position: (6, 10) });
+    assert_eq!(children[43], Children { parents: runs(31, 35), siblings: cells(&[38, 39, 40, 41, 42, 52]), value: 0, possible_values: digits(&[7, 9]), position: (6, 11) });
+    assert_eq!(children[44], Children { parents: runs(32, 36), siblings: cells(&[45, 53]), value: 0, possible_values: digits(&[7, 9]), position: (7, 1) });
+    assert_eq!(children[45], Children { parents: runs(26, 36), siblings: cells(&[35, 44, 54]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6]), position: (7, 2) });
+    assert_eq!(children[46], Children { parents: runs(22, 38), siblings: cells(&[31, 37, 47, 48, 49, 50, 56, 63]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (7, 4) });
+    assert_eq!(children[47], Children { parents: runs(34, 38), siblings: cells(&[46, 48, 49, 50, 57, 64, 70]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (7, 5) });
+    assert_eq!(children[48], Children { parents: runs(28, 38), siblings: cells(&[38, 46, 47, 49, 50, 58]), value: 0, possible_values: digits(&[7, 8, 9]), position: (7, 6) });
+    assert_eq!(children[49], Children { parents: runs(19, 38), siblings: cells(&[26, 32, 39, 46, 47, 48, 50]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (7, 7) });
+    assert_eq!(children[50], Children { parents: runs(24, 38), siblings: cells(&[33, 40, 46, 47, 48, 49, 59, 65]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (7, 8) });
+    assert_eq!(children[51], Children { parents: runs(30, 40), siblings: cells(&[42, 52, 61]), value: 0, possible_values: digits(&[1, 2, 4]), position: (7, 10) });
+    assert_eq!(children[52], Children { parents: runs(31, 40), siblings: cells(&[43, 51]), value: 0, possible_values: digits(&[7]), position: (7, 11) });
+    assert_eq!(children[53], Children { parents: runs(32, 41), siblings: cells(&[44, 54, 55, 56, 57, 58]), value: 0, possible_values: digits(&[7, 9]), position: (8, 1) });
+    assert_eq!(children[54], Children { parents: runs(26, 41), siblings: cells(&[35, 45, 53, 55, 56, 57, 58]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (8, 2) });
+    assert_eq!(children[55], Children { parents: runs(37, 41), siblings: cells(&[53, 54, 56, 57, 58, 62, 69, 77]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (8, 3) });
+    assert_eq!(children[56], Children { parents: runs(22, 41), siblings: cells(&[31, 37, 46, 53, 54, 55, 57, 58, 63]), value: 0, possible_values: digits(&[4, 6, 7, 8, 9]), position: (8, 4) });
+    assert_eq!(children[57], Children { parents: runs(34, 41), siblings: cells(&[47, 53, 54, 55, 56, 58, 64, 70]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (8, 5) });
+    assert_eq!(children[58], Children { parents: runs(28, 41), siblings: cells(&[38, 48, 53, 54, 55, 56, 57]), value: 0, possible_values: digits(&[7, 8, 9]), position: (8, 6) });
+    assert_eq!(children[59], Children { parents: runs(24, 42), siblings: cells(&[33, 40, 50, 60, 61, 65]), value: 0, possible_values: digits(&[1, 2, 3]), position: (8, 8) });
+    assert_eq!(children[60], Children { parents: runs(39, 42), siblings: cells(&[59, 61, 66, 73]), value: 0, possible_values: digits(&[1, 2]), position: (8, 9) });
+    assert_eq!(children[61], Children { parents: runs(30, 42), siblings: cells(&[42, 51, 59, 60]), value: 0, possible_values: digits(&[1, 2]), position: (8, 10) });
+    assert_eq!(children[62], Children { parents: runs(37, 45), siblings: cells(&[55, 63, 64, 69, 77]), value: 0, possible_values: digits(&[1, 2]), position: (9, 3) });
+    assert_eq!(children[63], Children { parents: runs(22, 45), siblings: cells(&[31, 37, 46, 56, 62, 64]), value: 0, possible_values: digits(&[4]), position: (9, 4) });
+    assert_eq!(children[64], Children { parents: runs(34, 45), siblings: cells(&[47, 57, 62, 63, 70]), value: 0, possible_values: digits(&[1, 2, 4]), position: (9, 5) });
+    assert_eq!(children[65], Children { parents: runs(24, 48), siblings: cells(&[33, 40, 50, 59, 66]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (9, 8) });
+    assert_eq!(children[66], Children { parents: runs(39, 48), siblings: cells(&[60, 65, 73]), value: 0, possible_values: digits(&[1, 2, 4]), position: (9, 9) });
+    assert_eq!(children[67], Children { parents: runs(43, 50), siblings: cells(&[68, 69, 75, 84]), value: 0, possible_values: digits(&[6, 8, 9]), position: (10, 1) });
+    assert_eq!(children[68], Children { parents: runs(44, 50), siblings: cells(&[67, 69, 76, 85]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (10, 2) });
+    assert_eq!(children[69], Children { parents: runs(37, 50), siblings: cells(&[55, 62, 67, 68, 77]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (10, 3) });
+    assert_eq!(children[70], Children { parents: runs(34, 52), siblings: cells(&[47, 57, 64, 71, 72]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (10, 5) });
+    assert_eq!(children[71], Children { parents: runs(46, 52), siblings: cells(&[70, 72, 79, 88]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (10, 6) });
+    assert_eq!(children[72], Children { parents: runs(47, 52), siblings: cells(&[70, 71, 80]), value: 0, possible_values: digits(&[8, 9]), position: (10, 7) });
+    assert_eq!(children[73], Children { parents: runs(39, 54), siblings: cells(&[60, 66, 74]), value: 0, possible_values: digits(&[2, 4]), position: (10, 9) });
+    assert_eq!(children[74], Children { parents: runs(49, 54), siblings: cells(&[73, 82, 91, 96]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (10, 10) });
+    assert_eq!(children[75], Children { parents: runs(43, 56), siblings: cells(&[67, 76, 77, 78, 84]), value: 0, possible_values: digits(&[8, 9]), position: (11, 1) });
+    assert_eq!(children[76], Children { parents: runs(44, 56), siblings: cells(&[68, 75, 77, 78, 85]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (11, 2) });
+    assert_eq!(children[77], Children { parents: runs(37, 56), siblings: cells(&[55, 62, 69, 75, 76, 78]), value: 0, possible_values: digits(&[5]), position: (11, 3) });
+    assert_eq!(children[78], Children { parents: runs(51, 56), siblings: cells(&[75, 76, 77, 86, 93]), value: 0, possible_values: digits(&[7, 8, 9]), position: (11, 4) });
+    assert_eq!(children[79], Children { parents: runs(46, 58), siblings: cells(&[71, 80, 81, 88]), value: 0, possible_values: digits(&[7, 8]), position: (11, 6) });
+    assert_eq!(children[80], Children { parents: runs(47, 58), siblings: cells(&[72, 79, 81]), value: 0, possible_values: digits(&[8, 9]), position: (11, 7) });
+    assert_eq!(children[81], Children { parents: runs(53, 58), siblings: cells(&[79, 80, 89]), value: 0, possible_values: digits(&[7, 8]), position: (11, 8) });
+    assert_eq!(children[82], Children { parents: runs(49, 60), siblings: cells(&[74, 83, 91, 96]), value: 0, possible_values: digits(&[8, 9]), position: (11, 10) });
+    assert_eq!(children[83], Children { parents: runs(55, 60), siblings: cells(&[82, 92]), value: 0, possible_values: digits(&[8, 9]), position: (11, 11) });
+    assert_eq!(children[84], Children { parents: runs(43, 61), siblings: cells(&[67, 75, 85]), value: 0, possible_values: digits(&[6]), position: (12, 1) });
+    assert_eq!(children[85], Children { parents: runs(44, 61), siblings: cells(&[68, 76, 84]), value: 0, possible_values: digits(&[1, 2, 3, 5, 6, 7]), position: (12, 2) });
+    assert_eq!(children[86], Children { parents: runs(51, 62), siblings: cells(&[78, 87, 88, 93]), value: 0, possible_values: digits(&[7, 8]), position: (12, 4) });
+    assert_eq!(children[87], Children { parents: runs(57, 62), siblings: cells(&[86, 88, 94]), value: 0, possible_values: digits(&[1, 3]), position: (12, 5) });
+    assert_eq!(children[88], Children { parents: runs(46, 62), siblings: cells(&[71, 79, 86, 87]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (12, 6) });
+    assert_eq!(children[89], Children { parents: runs(53, 63), siblings: cells(&[81, 90, 91, 92]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (12, 8) });
+    assert_eq!(children[90], Children { parents: runs(59, 63), siblings: cells(&[89, 91, 92, 95]), value: 0, possible_values: digits(&[1, 2, 3]), position: (12, 9) });
+    assert_eq!(children[91], Children { parents: runs(49, 63), siblings: cells(&[74, 82, 89, 90, 92, 96]), value: 0, possible_values: digits(&[5]), position: (12, 10) });
+    assert_eq!(children[92], Children { parents: runs(55, 63), siblings: cells(&[83, 89, 90, 91]), value: 0, possible_values: digits(&[2, 3, 5]), position: (12, 11) });
+    assert_eq!(children[93], Children { parents: runs(51, 64), siblings: cells(&[78, 86, 94]), value: 0, possible_values: digits(&[7, 8, 9]), position: (13, 4) });
+    assert_eq!(children[94], Children { parents: runs(57, 64), siblings: cells(&[87, 93]), value: 0, possible_values: digits(&[3]), position: (13, 5) });
+    assert_eq!(children[95], Children { parents: runs(59, 65), siblings: cells(&[90, 96]), value: 0, possible_values: digits(&[7, 9]), position: (13, 9) });
+    assert_eq!(children[96], Children { parents: runs(49, 65), siblings: cells(&[74, 82, 91, 95]), value: 0, possible_values: digits(&[7, 9]), position: (13, 10) });
   }
 
   #[test]
   fn test_puzzle_setup_with_input_file_3() {
-    let mut parents_and_children: (Vec<Parents>, Vec<Children>) = (Vec::new(), Vec::new());
-    let puzzle_file = "puzzles\\Kakuro_input3.txt".to_string();
+    let puzzle_file = puzzle_path("Kakuro_input3.txt");
 
-    puzzle_setup(&mut parents_and_children, puzzle_file);
+    let puzzle = Puzzle::load(&puzzle_file).unwrap();
 
-    let parents = parents_and_children.0;
-    let children = parents_and_children.1;
+    let parents = puzzle.parents;
+    let children = puzzle.children;
 
     assert_eq!(parents.len(), 56);
     assert_eq!(children.len(), 112);
 
-    assert_eq!(parents[0], Parents { children: vec![0, 9, 19, 28, 37, 46], sum: 38, value_size: "38-6".to_string(), combinations: vec![vec![3, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[1], Parents { children: vec![1, 10], sum: 8, value_size: "8-2".to_string(), combinations: vec![vec![1, 7], vec![2, 6], vec![3, 5]] });
-    assert_eq!(parents[2], Parents { children: vec![2, 11, 20], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[3], Parents { children: vec![3, 12, 21, 30, 40, 49], sum: 23, value_size: "23-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 8], vec![1, 2, 3, 4, 6, 7]] });
-    assert_eq!(parents[4], Parents { children: vec![4, 13, 23, 32, 41, 50], sum: 27, value_size: "27-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 8, 9], vec![1, 2, 3, 5, 7, 9], vec![1, 2, 3, 6, 7, 8], vec![1, 2, 4, 5, 6, 9], vec![1, 2, 4, 5, 7, 8], vec![1, 3, 4, 5, 6, 8], vec![2, 3, 4, 5, 6, 7]] });
-    assert_eq!(parents[5], Parents { children: vec![5, 14, 24], sum: 22, value_size: "22-3".to_string(), combinations: vec![vec![5, 8, 9], vec![6, 7, 9]] });
-    assert_eq!(parents[6], Parents { children: vec![6, 15], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[7], Parents { children: vec![7, 16, 25, 34, 44, 53], sum: 37, value_size: "37-6".to_string(), combinations: vec![vec![2, 5, 6, 7, 8, 9], vec![3, 4, 6, 7, 8, 9]] });
-    assert_eq!(parents[8], Parents { children: vec![8, 18, 27, 36], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[9], Parents { children: vec![0, 1, 2, 3], sum: 10, value_size: "10-4".to_string(), combinations: vec![vec![1, 2, 3, 4]] });
-    assert_eq!(parents[10], Parents { children: vec![4, 5, 6, 7], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[11], Parents { children: vec![17, 26, 35, 45], sum: 29, value_size: "29-4".to_string(), combinations: vec![vec![5, 7, 8, 9]] });
-    assert_eq!(parents[12], Parents { children: vec![8, 9, 10, 11, 12], sum: 17, value_size: "17-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 7], vec![1, 2, 3, 5, 6]] });
-    assert_eq!(parents[13], Parents { children: vec![22, 31], sum: 14, value_size: "14-2".to_string(), combinations: vec![vec![5, 9], vec![6, 8]] });
-    assert_eq!(parents[14], Parents { children: vec![13, 14, 15, 16, 17], sum: 35, value_size: "35-5".to_string(), combinations: vec![vec![5, 6, 7, 8, 9]] });
-    assert_eq!(parents[15], Parents { children: vec![18, 19], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[16], Parents { children: vec![29, 38, 47, 54, 59, 68, 78], sum: 29, value_size: "29-7".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 8]] });
-    assert_eq!(parents[17], Parents { children: vec![20, 21, 22, 23, 24], sum: 34, value_size: "34-5".to_string(), combinations: vec![vec![4, 6, 7, 8, 9]] });
-    assert_eq!(parents[18], Parents { children: vec![33, 43, 52, 57, 64, 73, 82], sum: 41, value_size: "41-7".to_string(), combinations: vec![vec![2, 4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[19], Parents { children: vec![25, 26], sum: 15, value_size: "15-2".to_string(), combinations: vec![vec![6, 9], vec![7, 8]] });
-    assert_eq!(parents[20], Parents { children: vec![27, 28, 29], sum: 22, value_size: "22-3".to_string(), combinations: vec![vec![5, 8, 9], vec![6, 7, 9]] });
-    assert_eq!(parents[21], Parents { children: vec![39, 48, 55, 60, 69], sum: 34, value_size: "34-5".to_string(), combinations: vec![vec![4, 6, 7, 8, 9]] });
-    assert_eq!(parents[22], Parents { children: vec![30, 31, 32], sum: 18, value_size: "18-3".to_string(), combinations: vec![vec![1, 8, 9], vec![2, 7, 9], vec![3, 6, 9], vec![3, 7, 8], vec![4, 5, 9], vec![4, 6, 8], vec![5, 6, 7]] });
-    assert_eq!(parents[23], Parents { children: vec![42, 51, 56, 63, 72], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[24], Parents { children: vec![33, 34, 35], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[25], Parents { children: vec![36, 37, 38, 39, 40], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[26], Parents { children: vec![41, 42, 43, 44, 45], sum: 20, value_size: "20-5".to_string(), combinations: vec![vec![1, 2, 3, 5, 9], vec![1, 2, 3, 6, 8], vec![1, 2, 4, 5, 8], vec![1, 2, 4, 6, 7], vec![1, 3, 4, 5, 7], vec![2, 3, 4, 5, 6]] });
-    assert_eq!(parents[27], Parents { children: vec![46, 47, 48, 49], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[28], Parents { children: vec![50, 51, 52, 53], sum: 19, value_size: "19-4".to_string(), combinations: vec![vec![1, 2, 7, 9], vec![1, 3, 6, 9], vec![1, 3, 7, 8], vec![1, 4, 5, 9], vec![1, 4, 6, 8], vec![1, 5, 6, 7], vec![2, 3, 5, 9], vec![2, 3, 6, 8], vec![2, 4, 5, 8], vec![2, 4, 6, 7], vec![3, 4, 5, 7]] });
-    assert_eq!(parents[29], Parents { children: vec![58, 67, 77, 86, 95, 104], sum: 38, value_size: "38-6".to_string(), combinations: vec![vec![3, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[30], Parents { children: vec![54, 55], sum: 8, value_size: "8-2".to_string(), combinations: vec![vec![1, 7], vec![2, 6], vec![3, 5]] });
-    assert_eq!(parents[31], Parents { children: vec![61, 70, 79, 88, 98, 107], sum: 21, value_size: "21-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6]] });
-    assert_eq!(parents[32], Parents { children: vec![62, 71, 81, 90, 99, 108], sum: 38, value_size: "38-6".to_string(), combinations: vec![vec![3, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[33], Parents { children: vec![56, 57], sum: 14, value_size: "14-2".to_string(), combinations: vec![vec![5, 9], vec![6, 8]] });
-    assert_eq!(parents[34], Parents { children: vec![65, 74, 83, 92, 102, 111], sum: 21, value_size: "21-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6]] });
-    assert_eq!(parents[35], Parents { children: vec![66, 76, 85, 94], sum: 25, value_size: "25-4".to_string(), combinations: vec![vec![1, 7, 8, 9], vec![2, 6, 8, 9], vec![3, 5, 8, 9], vec![3, 6, 7, 9], vec![4, 5, 7, 9], vec![4, 6, 7, 8]] });
-    assert_eq!(parents[36], Parents { children: vec![58, 59, 60, 61], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[37], Parents { children: vec![62, 63, 64, 65], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[38], Parents { children: vec![75, 84, 93, 103], sum: 14, value_size: "14-4".to_string(), combinations: vec![vec![1, 2, 3, 8], vec![1, 2, 4, 7], vec![1, 2, 5, 6], vec![1, 3, 4, 6], vec![2, 3, 4, 5]] });
-    assert_eq!(parents[39], Parents { children: vec![66, 67, 68, 69, 70], sum: 25, value_size: "25-5".to_string(), combinations: vec![vec![1, 2, 5, 8, 9], vec![1, 2, 6, 7, 9], vec![1, 3, 4, 8, 9], vec![1, 3, 5, 7, 9], vec![1, 3, 6, 7, 8], vec![1, 4, 5, 6, 9], vec![1, 4, 5, 7, 8], vec![2, 3, 4, 7, 9], vec![2, 3, 5, 6, 9], vec![2, 3, 5, 7, 8], vec![2, 4, 5, 6, 8], vec![3, 4, 5, 6, 7]] });
-    assert_eq!(parents[40], Parents { children: vec![80, 89], sum: 14, value_size: "14-2".to_string(), combinations: vec![vec![5, 9], vec![6, 8]] });
-    assert_eq!(parents[41], Parents { children: vec![71, 72, 73, 74, 75], sum: 34, value_size: "34-5".to_string(), combinations: vec![vec![4, 6, 7, 8, 9]] });
-    assert_eq!(parents[42], Parents { children: vec![76, 77, 78], sum: 22, value_size: "22-3".to_string(), combinations: vec![vec![5, 8, 9], vec![6, 7, 9]] });
-    assert_eq!(parents[43], Parents { children: vec![87, 97, 106], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[44], Parents { children: vec![79, 80, 81], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[45], Parents { children: vec![91, 100, 109], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[46], Parents { children: vec![82, 83, 84], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[47], Parents { children: vec![85, 86], sum: 12, value_size: "12-2".to_string(), combinations: vec![vec![3, 9], vec![4, 8], vec![5, 7]] });
-    assert_eq!(parents[48], Parents { children: vec![96, 105], sum: 14, value_size: "14-2".to_string(), combinations: vec![vec![5, 9], vec![6, 8]] });
-    assert_eq!(parents[49], Parents { children: vec![87, 88, 89, 90, 91], sum: 18, value_size: "18-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 8], vec![1, 2, 3, 5, 7], vec![1, 2, 4, 5, 6]] });
-    assert_eq!(parents[50], Parents { children: vec![101, 110], sum: 5, value_size: "5-2".to_string(), combinations: vec![vec![1, 4], vec![2, 3]] });
-    assert_eq!(parents[51], Parents { children: vec![92, 93], sum: 6, value_size: "6-2".to_string(), combinations: vec![vec![1, 5], vec![2, 4]] });
-    assert_eq!(parents[52], Parents { children: vec![94, 95, 96, 97, 98], sum: 35, value_size: "35-5".to_string(), combinations: vec![vec![5, 6, 7, 8, 9]] });
-    assert_eq!(parents[53], Parents { children: vec![99, 100, 101, 102, 103], sum: 17, value_size: "17-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 7], vec![1, 2, 3, 5, 6]] });
-    assert_eq!(parents[54], Parents { children: vec![104, 105, 106, 107], sum: 17, value_size: "17-4".to_string(), combinations: vec![vec![1, 2, 5, 9], vec![1, 2, 6, 8], vec![1, 3, 4, 9], vec![1, 3, 5, 8], vec![1, 3, 6, 7], vec![1, 4, 5, 7], vec![2, 3, 4, 8], vec![2, 3, 5, 7], vec![2, 4, 5, 6]] });
-    assert_eq!(parents[55], Parents { children: vec![108, 109, 110, 111], sum: 18, value_size: "18-4".to_string(), combinations: vec![vec![1, 2, 6, 9], vec![1, 2, 7, 8], vec![1, 3, 5, 9], vec![1, 3, 6, 8], vec![1, 4, 5, 8], vec![1, 4, 6, 7], vec![2, 3, 4, 9], vec![2, 3, 5, 8], vec![2, 3, 6, 7], vec![2, 4, 5, 7], vec![3, 4, 5, 6]] });
+    assert_eq!(parents[0], Parents { children: cells(&[0, 9, 19, 28, 37, 46]), sum: 38, value_size: "38-6".to_string(), combinations: vec![digits(&[3, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (0, 2) });
+    assert_eq!(parents[1], Parents { children: cells(&[1, 10]), sum: 8, value_size: "8-2".to_string(), combinations: vec![digits(&[1, 7]), digits(&[2, 6]), digits(&[3, 5])], direction: Direction::Down, head: (0, 3) });
+    assert_eq!(parents[2], Parents { children: cells(&[2, 11, 20]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (0, 4) });
+    assert_eq!(parents[3], Parents { children: cells(&[3, 12, 21, 30, 40, 49]), sum: 23, value_size: "23-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 8]), digits(&[1, 2, 3, 4, 6, 7])], direction: Direction::Down, head: (0, 5) });
+    assert_eq!(parents[4], Parents { children: cells(&[4, 13, 23, 32, 41, 50]), sum: 27, value_size: "27-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 8, 9]), digits(&[1, 2, 3, 5, 7, 9]), digits(&[1, 2, 3, 6, 7, 8]), digits(&[1, 2, 4, 5, 6, 9]), digits(&[1, 2, 4, 5, 7, 8]), digits(&[1, 3, 4, 5, 6, 8]), digits(&[2, 3, 4, 5, 6, 7])], direction: Direction::Down, head: (0, 7) });
+    assert_eq!(parents[5], Parents { children: cells(&[5, 14, 24]), sum: 22, value_size: "22-3".to_string(), combinations: vec![digits(&[5, 8, 9]), digits(&[6, 7, 9])], direction: Direction::Down, head: (0, 8) });
+    assert_eq!(parents[6], Parents { children: cells(&[6, 15]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Down, head: (0, 9) });
+    assert_eq!(parents[7], Parents { children: cells(&[7, 16, 25, 34, 44, 53]), sum: 37, value_size: "37-6".to_string(), combinations: vec![digits(&[2, 5, 6, 7, 8, 9]), digits(&[3, 4, 6, 7, 8, 9])], direction: Direction::Down, head: (0, 10) });
+    assert_eq!(parents[8], Parents { children: cells(&[8, 18, 27, 36]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Down, head: (1, 1) });
+    assert_eq!(parents[9], Parents { children: cells(&[0, 1, 2, 3]), sum: 10, value_size: "10-4".to_string(), combinations: vec![digits(&[1, 2, 3, 4])], direction: Direction::Across, head: (1, 1) });
+    assert_eq!(parents[10], Parents { children: cells(&[4, 5, 6, 7]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Across, head: (1, 6) });
+    assert_eq!(parents[11], Parents { children: cells(&[17, 26, 35, 45]), sum: 29, value_size: "29-4".to_string(), combinations: vec![digits(&[5, 7, 8, 9])], direction: Direction::Down, head: (1, 11) });
+    assert_eq!(parents[12], Parents { children: cells(&[8, 9, 10, 11, 12]), sum: 17, value_size: "17-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 7]), digits(&[1, 2, 3, 5, 6])], direction: Direction::Across, head: (2, 0) });
+    assert_eq!(parents[13], Parents { children: cells(&[22, 31]), sum: 14, value_size: "14-2".to_string(), combinations: vec![digits(&[5, 9]), digits(&[6, 8])], direction: Direction::Down, head: (2, 6) });
+    assert_eq!(parents[14], Parents { children: cells(&[13, 14, 15, 16, 17]), sum: 35, value_size: "35-5".to_string(), combinations: vec![digits(&[5, 6, 7, 8, 9])], direction: Direction::Across, head: (2, 6) });
+    assert_eq!(parents[15], Parents { children: cells(&[18, 19]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Across, head: (3, 0) });
+    assert_eq!(parents[16], Parents { children: cells(&[29, 38, 47, 54, 59, 68, 78]), sum: 29, value_size: "29-7".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 8])], direction: Direction::Down, head: (3, 3) });
+    assert_eq!(parents[17], Parents { children: cells(&[20, 21, 22, 23, 24]), sum: 34, value_size: "34-5".to_string(), combinations: vec![digits(&[4, 6, 7, 8, 9])], direction: Direction::Across, head: (3, 3) });
+    assert_eq!(parents[18], Parents { children: cells(&[33, 43, 52, 57, 64, 73, 82]), sum: 41, value_size: "41-7".to_string(), combinations: vec![digits(&[2, 4, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (3, 9) });
+    assert_eq!(parents[19], Parents { children: cells(&[25, 26]), sum: 15, value_size: "15-2".to_string(), combinations: vec![digits(&[6, 9]), digits(&[7, 8])], direction: Direction::Across, head: (3, 9) });
+    assert_eq!(parents[20], Parents { children: cells(&[27, 28, 29]), sum: 22, value_size: "22-3".to_string(), combinations: vec![digits(&[5, 8, 9]), digits(&[6, 7, 9])], direction: Direction::Across, head: (4, 0) });
+    assert_eq!(parents[21], Parents { children: cells(&[39, 48, 55, 60, 69]), sum: 34, value_size: "34-5".to_string(), combinations: vec![digits(&[4, 6, 7, 8, 9])], direction: Direction::Down, head: (4, 4) });
+    assert_eq!(parents[22], Parents { children: cells(&[30, 31, 32]), sum: 18, value_size: "18-3".to_string(), combinations: vec![digits(&[1, 8, 9]), digits(&[2, 7, 9]), digits(&[3, 6, 9]), digits(&[3, 7, 8]), digits(&[4, 5, 9]), digits(&[4, 6, 8]), digits(&[5, 6, 7])], direction: Direction::Across, head: (4, 4) });
+    assert_eq!(parents[23], Parents { children: cells(&[42, 51, 56, 63, 72]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Down, head: (4, 8) });
+    assert_eq!(parents[24], Parents { children: cells(&[33, 34, 35]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (4, 8) });
+    assert_eq!(parents[25], Parents { children: cells(&[36, 37, 38, 39, 40]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Across, head: (5, 0) });
+    assert_eq!(parents[26], Parents { children: cells(&[41, 42, 43, 44, 45]), sum: 20, value_size: "20-5".to_string(), combinations: vec![digits(&[1, 2, 3, 5, 9]), digits(&[1, 2, 3, 6, 8]), digits(&[1, 2, 4, 5, 8]), digits(&[1, 2, 4, 6, 7]), digits(&[1, 3, 4, 5, 7]), digits(&[2, 3, 4, 5, 6])], direction: Direction::Across, head: (5, 6) });
+    assert_eq!(parents[27], Parents { children: cells(&[46, 47, 48, 49]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Across, head: (6, 1) });
+    assert_eq!(parents[28], Parents { children: cells(&[50, 51, 52, 53]), sum: 19, value_size: "19-4".to_string(), combinations: vec![digits(&[1, 2, 7, 9]), digits(&[1, 3, 6, 9]), digits(&[1, 3, 7, 8]), digits(&[1, 4, 5, 9]), digits(&[1, 4, 6, 8]), digits(&[1, 5, 6, 7]), digits(&[2, 3, 5, 9]), digits(&[2, 3, 6, 8]), digits(&[2, 4, 5, 8]), digits(&[2, 4, 6, 7]), digits(&[3, 4, 5, 7])], direction: Direction::Across, head: (6, 6) });
+    assert_eq!(parents[29], Parents { children: cells(&[58, 67, 77, 86, 95, 104]), sum: 38, value_size: "38-6".to_string(), combinations: vec![digits(&[3, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (7, 2) });
+    assert_eq!(parents[30], Parents { children: cells(&[54, 55]), sum: 8, value_size: "8-2".to_string(), combinations: vec![digits(&[1, 7]), digits(&[2, 6]), digits(&[3, 5])], direction: Direction::Across, head: (7, 2) });
+    assert_eq!(parents[31], Parents { children: cells(&[61, 70, 79, 88, 98, 107]), sum: 21, value_size: "21-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6])], direction: Direction::Down, head: (7, 5) });
+    assert_eq!(parents[32], Parents { children: cells(&[62, 71, 81, 90, 99, 108]), sum: 38, value_size: "38-6".to_string(), combinations: vec![digits(&[3, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (7, 7) });
+    assert_eq!(parents[33], Parents { children: cells(&[56, 57]), sum: 14, value_size: "14-2".to_string(), combinations: vec![digits(&[5, 9]), digits(&[6, 8])], direction: Direction::Across, head: (7, 7) });
+    assert_eq!(parents[34], Parents { children: cells(&[65, 74, 83, 92, 102, 111]), sum: 21, value_size: "21-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6])], direction: Direction::Down, head: (7, 10) });
+    assert_eq!(parents[35], Parents { children: cells(&[66, 76, 85, 94]), sum: 25, value_size: "25-4".to_string(), combinations: vec![digits(&[1, 7, 8, 9]), digits(&[2, 6, 8, 9]), digits(&[3, 5, 8, 9]), digits(&[3, 6, 7, 9]), digits(&[4, 5, 7, 9]), digits(&[4, 6, 7, 8])], direction: Direction::Down, head: (8, 1) });
+    assert_eq!(parents[36], Parents { children: cells(&[58, 59, 60, 61]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Across, head: (8, 1) });
+    assert_eq!(parents[37], Parents { children: cells(&[62, 63, 64, 65]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Across, head: (8, 6) });
+    assert_eq!(parents[38], Parents { children: cells(&[75, 84, 93, 103]), sum: 14, value_size: "14-4".to_string(), combinations: vec![digits(&[1, 2, 3, 8]), digits(&[1, 2, 4, 7]), digits(&[1, 2, 5, 6]), digits(&[1, 3, 4, 6]), digits(&[2, 3, 4, 5])], direction: Direction::Down, head: (8, 11) });
+    assert_eq!(parents[39], Parents { children: cells(&[66, 67, 68, 69, 70]), sum: 25, value_size: "25-5".to_string(), combinations: vec![digits(&[1, 2, 5, 8, 9]), digits(&[1, 2, 6, 7, 9]), digits(&[1, 3, 4, 8, 9]), digits(&[1, 3, 5, 7, 9]), digits(&[1, 3, 6, 7, 8]), digits(&[1, 4, 5, 6, 9]), digits(&[1, 4, 5, 7, 8]), digits(&[2, 3, 4, 7, 9]), digits(&[2, 3, 5, 6, 9]), digits(&[2, 3, 5, 7, 8]), digits(&[2, 4, 5, 6, 8]), digits(&[3, 4, 5, 6, 7])], direction: Direction::Across, head: (9, 0) });
+    assert_eq!(parents[40], Parents { children: cells(&[80, 89]), sum: 14, value_size: "14-2".to_string(), combinations: vec![digits(&[5, 9]), digits(&[6, 8])], direction: Direction::Down, head: (9, 6) });
+    assert_eq!(parents[41], Parents { children: cells(&[71, 72, 73, 74, 75]), sum: 34, value_size: "34-5".to_string(), combinations: vec![digits(&[4, 6, 7, 8, 9])], direction: Direction::Across, head: (9, 6) });
+    assert_eq!(parents[42], Parents { children: cells(&[76, 77, 78]), sum: 22, value_size: "22-3".to_string(), combinations: vec![digits(&[5, 8, 9]), digits(&[6, 7, 9])], direction: Direction::Across, head: (10, 0) });
+    assert_eq!(parents[43], Parents { children: cells(&[87, 97, 106]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Down, head: (10, 4) });
+    assert_eq!(parents[44], Parents { children: cells(&[79, 80, 81]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (10, 4) });
+    assert_eq!(parents[45], Parents { children: cells(&[91, 100, 109]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (10, 8) });
+    assert_eq!(parents[46], Parents { children: cells(&[82, 83, 84]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (10, 8) });
+    assert_eq!(parents[47], Parents { children: cells(&[85, 86]), sum: 12, value_size: "12-2".to_string(), combinations: vec![digits(&[3, 9]), digits(&[4, 8]), digits(&[5, 7])], direction: Direction::Across, head: (11, 0) });
+    assert_eq!(parents[48], Parents { children: cells(&[96, 105]), sum: 14, value_size: "14-2".to_string(), combinations: vec![digits(&[5, 9]), digits(&[6, 8])], direction: Direction::Down, head: (11, 3) });
+    assert_eq!(parents[49], Parents { children: cells(&[87, 88, 89, 90, 91]), sum: 18, value_size: "18-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 8]), digits(&[1, 2, 3, 5, 7]), digits(&[1, 2, 4, 5, 6])], direction: Direction::Across, head: (11, 3) });
+    assert_eq!(parents[50], Parents { children: cells(&[101, 110]), sum: 5, value_size: "5-2".to_string(), combinations: vec![digits(&[1, 4]), digits(&[2, 3])], direction: Direction::Down, head: (11, 9) });
+    assert_eq!(parents[51], Parents { children: cells(&[92, 93]), sum: 6, value_size: "6-2".to_string(), combinations: vec![digits(&[1, 5]), digits(&[2, 4])], direction: Direction::Across, head: (11, 9) });
+    assert_eq!(parents[52], Parents { children: cells(&[94, 95, 96, 97, 98]), sum: 35, value_size: "35-5".to_string(), combinations: vec![digits(&[5, 6, 7, 8, 9])], direction: Direction::Across, head: (12, 0) });
+    assert_eq!(parents[53], Parents { children: cells(&[99, 100, 101, 102, 103]), sum: 17, value_size: "17-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 7]), digits(&[1, 2, 3, 5, 6])], direction: Direction::Across, head: (12, 6) });
+    assert_eq!(parents[54], Parents { children: cells(&[104, 105, 106, 107]), sum: 17, value_size: "17-4".to_string(), combinations: vec![digits(&[1, 2, 5, 9]), digits(&[1, 2, 6, 8]), digits(&[1, 3, 4, 9]), digits(&[1, 3, 5, 8]), digits(&[1, 3, 6, 7]), digits(&[1, 4, 5, 7]), digits(&[2, 3, 4, 8]), digits(&[2, 3, 5, 7]), digits(&[2, 4, 5, 6])], direction: Direction::Across, head: (13, 1) });
+    assert_eq!(parents[55], Parents { children: cells(&[108, 109, 110, 111]), sum: 18, value_size: "18-4".to_string(), combinations: vec![digits(&[1, 2, 6, 9]), digits(&[1, 2, 7, 8]), digits(&[1, 3, 5, 9]), digits(&[1, 3, 6, 8]), digits(&[1, 4, 5, 8]), digits(&[1, 4, 6, 7]), digits(&[2, 3, 4, 9]), digits(&[2, 3, 5, 8]), digits(&[2, 3, 6, 7]), digits(&[2, 4, 5, 7]), digits(&[3, 4, 5, 6])], direction: Direction::Across, head: (13, 6) });
   
-    assert_eq!(children[0], Children { parents: (0, 9), siblings: vec![1, 2, 3, 9, 19, 28, 37, 46], value: 0, possible_values: vec![3] });
-    assert_eq!(children[1], Children { parents: (1, 9), siblings: vec![0, 2, 3, 10], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[2], Children { parents: (2, 9), siblings: vec![0, 1, 3, 11, 20], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[3], Children { parents: (3, 9), siblings: vec![0, 1, 2, 12, 21, 30, 40, 49], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[4], Children { parents: (4, 10), siblings: vec![5, 6, 7, 13, 23, 32, 41, 50], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[5], Children { parents: (5, 10), siblings: vec![4, 6, 7, 14, 24], value: 0, possible_values: vec![5, 6] });
-    assert_eq!(children[6], Children { parents: (6, 10), siblings: vec![4, 5, 7, 15], value: 0, possible_values: vec![2, 3, 4, 5, 6] });
-    assert_eq!(children[7], Children { parents: (7, 10), siblings: vec![4, 5, 6, 16, 25, 34, 44, 53], value: 0, possible_values: vec![2, 3, 4, 5, 6] });
-    assert_eq!(children[8], Children { parents: (8, 12), siblings: vec![9, 10, 11, 12, 18, 27, 36], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[9], Children { parents: (0, 12), siblings: vec![0, 8, 10, 11, 12, 19, 28, 37, 46], value: 0, possible_values: vec![3, 5, 6, 7] });
-    assert_eq!(children[10], Children { parents: (1, 12), siblings: vec![1, 8, 9, 11, 12], value: 0, possible_values: vec![1, 2, 3, 5, 6, 7] });
-    assert_eq!(children[11], Children { parents: (2, 12), siblings: vec![2, 8, 9, 10, 12, 20], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[12], Children { parents: (3, 12), siblings: vec![3, 8, 9, 10, 11, 21, 30, 40, 49], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[13], Children { parents: (4, 14), siblings: vec![4, 14, 15, 16, 17, 23, 32, 41, 50], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[14], Children { parents: (5, 14), siblings: vec![5, 13, 15, 16, 17, 24], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[15], Children { parents: (6, 14), siblings: vec![6, 13, 14, 16, 17], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[16], Children { parents: (7, 14), siblings: vec![7, 13, 14, 15, 17, 25, 34, 44, 53], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[17], Children { parents: (11, 14), siblings: vec![13, 14, 15, 16, 26, 35, 45], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[18], Children { parents: (8, 15), siblings: vec![8, 19, 27, 36], value: 0, possible_values: vec![2, 3, 5] });
-    assert_eq!(children[19], Children { parents: (0, 15), siblings: vec![0, 9, 18, 28, 37, 46], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[20], Children { parents: (2, 17), siblings: vec![2, 11, 21, 22, 23, 24], value: 0, possible_values: vec![4, 6, 7, 8] });
-    assert_eq!(children[21], Children { parents: (3, 17), siblings: vec![3, 12, 20, 22, 23, 24, 30, 40, 49], value: 0, possible_values: vec![4, 6, 7, 8] });
-    assert_eq!(children[22], Children { parents: (13, 17), siblings: vec![20, 21, 23, 24, 31], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[23], Children { parents: (4, 17), siblings: vec![4, 13, 20, 21, 22, 24, 32, 41, 50], value: 0, possible_values: vec![4, 6, 7, 8, 9] });
-    assert_eq!(children[24], Children { parents: (5, 17), siblings: vec![5, 14, 20, 21, 22, 23], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[25], Children { parents: (7, 19), siblings: vec![7, 16, 26, 34, 44, 53], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[26], Children { parents: (11, 19), siblings: vec![17, 25, 35, 45], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[27], Children { parents: (8, 20), siblings: vec![8, 18, 28, 29, 36], value: 0, possible_values: vec![5] });
-    assert_eq!(children[28], Children { parents: (0, 20), siblings: vec![0, 9, 19, 27, 29, 37, 46], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[29], Children { parents: (16, 20), siblings: vec![27, 28, 38, 47, 54, 59, 68, 78], value: 0, possible_values: vec![5, 6, 8] });
-    assert_eq!(children[30], Children { parents: (3, 22), siblings: vec![3, 12, 21, 31, 32, 40, 49], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[31], Children { parents: (13, 22), siblings: vec![22, 30, 32], value: 0, possible_values: vec![5, 6, 8, 9] });
-    assert_eq!(children[32], Children { parents: (4, 22), siblings: vec![4, 13, 23, 30, 31, 41, 50], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[33], Children { parents: (18, 24), siblings: vec![34, 35, 43, 52, 57, 64, 73, 82], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[34], Children { parents: (7, 24), siblings: vec![7, 16, 25, 33, 35, 44, 53], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[35], Children { parents: (11, 24), siblings: vec![17, 26, 33, 34, 45], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[36], Children { parents: (8, 25), siblings: vec![8, 18, 27, 37, 38, 39, 40], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[37], Children { parents: (0, 25), siblings: vec![0, 9, 19, 28, 36, 38, 39, 40, 46], value: 0, possible_values: vec![3, 6] });
-    assert_eq!(children[38], Children { parents: (16, 25), siblings: vec![29, 36, 37, 39, 40, 47, 54, 59, 68, 78], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[39], Children { parents: (21, 25), siblings: vec![36, 37, 38, 40, 48, 55, 60, 69], value: 0, possible_values: vec![4, 6] });
-    assert_eq!(children[40], Children { parents: (3, 25), siblings: vec![3, 12, 21, 30, 36, 37, 38, 39, 49], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[41], Children { parents: (4, 26), siblings: vec![4, 13, 23, 32, 42, 43, 44, 45, 50], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[42], Children { parents: (23, 26), siblings: vec![41, 43, 44, 45, 51, 56, 63, 72], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[43], Children { parents: (18, 26), siblings: vec![33, 41, 42, 44, 45, 52, 57, 64, 73, 82], value: 0, possible_values: vec![2, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[44], Children { parents: (7, 26), siblings: vec![7, 16, 25, 34, 41, 42, 43, 45, 53], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[45], Children { parents: (11, 26), siblings: vec![17, 26, 35, 41, 42, 43, 44], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[46], Children { parents: (0, 27), siblings: vec![0, 9, 19, 28, 37, 47, 48, 49], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[47], Children { parents: (16, 27), siblings: vec![29, 38, 46, 48, 49, 54, 59, 68, 78], value: 0, possible_values: vec![6, 8] });
-    assert_eq!(children[48], Children { parents: (21, 27), siblings: vec![39, 46, 47, 49, 55, 60, 69], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[49], Children { parents: (3, 27), siblings: vec![3, 12, 21, 30, 40, 46, 47, 48], value: 0, possible_values: vec![6, 7, 8] });
-    assert_eq!(children[50], Children { parents: (4, 28), siblings: vec![4, 13, 23, 32, 41, 51, 52, 53], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[51], Children { parents: (23, 28), siblings: vec![42, 50, 52, 53, 56, 63, 72], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[52], Children { parents: (18, 28), siblings: vec![33, 43, 50, 51, 53, 57, 64, 73, 82], value: 0, possible_values: vec![2, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[53], Children { parents: (7, 28), siblings: vec![7, 16, 25, 34, 44, 50, 51, 52], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[54], Children { parents: (16, 30), siblings: vec![29, 38, 47, 55, 59, 68, 78], value: 0, possible_values: vec![1, 2, 3, 5, 6] });
-    assert_eq!(children[55], Children { parents: (21, 30), siblings: vec![39, 48, 54, 60, 69], value: 0, possible_values: vec![6, 7] });
-    assert_eq!(children[56], Children { parents: (23, 33), siblings: vec![42, 51, 57, 63, 72], value: 0, possible_values: vec![6] });
-    assert_eq!(children[57], Children { parents: (18, 33), siblings: vec![33, 43, 52, 56, 64, 73, 82], value: 0, possible_values: vec![5, 6, 8, 9] });
-    assert_eq!(children[58], Children { parents: (29, 36), siblings: vec![59, 60, 61, 67, 77, 86, 95, 104], value: 0, possible_values: vec![3, 5, 6] });
-    assert_eq!(children[59], Children { parents: (16, 36), siblings: vec![29, 38, 47, 54, 58, 60, 61, 68, 78], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[60], Children { parents: (21, 36), siblings: vec![39, 48, 55, 58, 59, 61, 69], value: 0, possible_values: vec![4, 6] });
-    assert_eq!(children[61], Children { parents: (31, 36), siblings: vec![58, 59, 60, 70, 79, 88, 98, 107], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[62], Children { parents: (32, 37), siblings: vec![63, 64, 65, 71, 81, 90, 99, 108], value: 0, possible_values: vec![3, 5] });
-    assert_eq!(children[63], Children { parents: (23, 37), siblings: vec![42, 51, 56, 62, 64, 65, 72], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[64], Children { parents: (18, 37), siblings: vec![33, 43, 52, 57, 62, 63, 65, 73, 82], value: 0, possible_values: vec![2, 5] });
-    assert_eq!(children[65], Children { parents: (34, 37), siblings: vec![62, 63, 64, 74, 83, 92, 102, 111], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[66], Children { parents: (35, 39), siblings: vec![67, 68, 69, 70, 76, 85, 94], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[67], Children { parents: (29, 39), siblings: vec![58, 66, 68, 69, 70, 77, 86, 95, 104], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[68], Children { parents: (16, 39), siblings: vec![29, 38, 47, 54, 59, 66, 67, 69, 70, 78], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 8] });
-    assert_eq!(children[69], Children { parents: (21, 39), siblings: vec![39, 48, 55, 60, 66, 67, 68, 70], value: 0, possible_values: vec![4, 6, 7, 8, 9] });
-    assert_eq!(children[70], Children { parents: (31, 39), siblings: vec![61, 66, 67, 68, 69, 79, 88, 98, 107], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[71], Children { parents: (32, 41), siblings: vec![62, 72, 73, 74, 75, 81, 90, 99, 108], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[72], Children { parents: (23, 41), siblings: vec![42, 51, 56, 63, 71, 73, 74, 75], value: 0, possible_values: vec![4, 6] });
-    assert_eq!(children[73], Children { parents: (18, 41), siblings: vec![33, 43, 52, 57, 64, 71, 72, 74, 75, 82], value: 0, possible_values: vec![4, 6, 7, 8, 9] });
-    assert_eq!(children[74], Children { parents: (34, 41), siblings: vec![65, 71, 72, 73, 75, 83, 92, 102, 111], value: 0, possible_values: vec![4, 6] });
-    assert_eq!(children[75], Children { parents: (38, 41), siblings: vec![71, 72, 73, 74, 84, 93, 103], value: 0, possible_values: vec![4, 6, 7, 8] });
-    assert_eq!(children[76], Children { parents: (35, 42), siblings: vec![66, 77, 78, 85, 94], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[77], Children { parents: (29, 42), siblings: vec![58, 67, 76, 78, 86, 95, 104], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[78], Children { parents: (16, 42), siblings: vec![29, 38, 47, 54, 59, 68, 76, 77], value: 0, possible_values: vec![5, 6, 8] });
-    assert_eq!(children[79], Children { parents: (31, 44), siblings: vec![61, 70, 80, 81, 88, 98, 107], value: 0, possible_values: vec![6] });
-    assert_eq!(children[80], Children { parents: (40, 44), siblings: vec![79, 81, 89], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[81], Children { parents: (32, 44), siblings: vec![62, 71, 79, 80, 90, 99, 108], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[82], Children { parents: (18, 46), siblings: vec![33, 43, 52, 57, 64, 73, 83, 84], value: 0, possible_values: vec![2, 4] });
-    assert_eq!(children[83], Children { parents: (34, 46), siblings: vec![65, 74, 82, 84, 92, 102, 111], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[84], Children { parents: (38, 46), siblings: vec![75, 82, 83, 93, 103], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[85], Children { parents: (35, 47), siblings: vec![66, 76, 86, 94], value: 0, possible_values: vec![3, 4, 5, 7, 8, 9] });
-    assert_eq!(children[86], Children { parents: (29, 47), siblings: vec![58, 67, 77, 85, 95, 104], value: 0, possible_values: vec![3, 5, 7, 8, 9] });
-    assert_eq!(children[87], Children { parents: (43, 49), siblings: vec![88, 89, 90, 91, 97, 106], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[88], Children { parents: (31, 49), siblings: vec![61, 70, 79, 87, 89, 90, 91, 98, 107], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[89], Children { parents: (40, 49), siblings: vec![80, 87, 88, 90, 91], value: 0, possible_values: vec![5, 6, 8] });
-    assert_eq!(children[90], Children { parents: (32, 49), siblings: vec![62, 71, 81, 87, 88, 89, 91, 99, 108], value: 0, possible_values: vec![3, 5, 6, 7, 8] });
-    assert_eq!(children[91], Children { parents: (45, 49), siblings: vec![87, 88, 89, 90, 100, 109], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[92], Children { parents: (34, 51), siblings: vec![65, 74, 83, 93, 102, 111], value: 0, possible_values: vec![1, 2, 4, 5] });
-    assert_eq!(children[93], Children { parents: (38, 51), siblings: vec![75, 84, 92, 103], value: 0, possible_values: vec![1, 2, 4, 5] });
-    assert_eq!(children[94], Children { parents: (35, 52), siblings: vec![66, 76, 85, 95, 96, 97, 98], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[95], Children { parents: (29, 52), siblings: vec![58, 67, 77, 86, 94, 96, 97, 98, 104], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[96], Children { parents: (48, 52), siblings: vec![94, 95, 97, 98, 105], value: 0, possible_values: vec![5, 6, 8, 9] });
-    assert_eq!(children[97], Children { parents: (43, 52), siblings: vec![87, 94, 95, 96, 98, 106], value: 0, possible_values: vec![5, 6] });
-    assert_eq!(children[98], Children { parents: (31, 52), siblings: vec![61, 70, 79, 88, 94, 95, 96, 97, 107], value: 0, possible_values: vec![5, 6] });
-    assert_eq!(children[99], Children { parents: (32, 53), siblings: vec![62, 71, 81, 90, 100, 101, 102, 103, 108], value: 0, possible_values: vec![3, 5, 6, 7] });
-    assert_eq!(children[100], Children { parents: (45, 53), siblings: vec![91, 99, 101, 102, 103, 109], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[101], Children { parents: (50, 53), siblings: vec![99, 100, 102, 103, 110], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[102], Children { parents: (34, 53), siblings: vec![65, 74, 83, 92, 99, 100, 101, 103, 111], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[103], Children { parents: (38, 53), siblings: vec![75, 84, 93, 99, 100, 101, 102], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[104], Children { parents: (29, 54), siblings: vec![58, 67, 77, 86, 95, 105, 106, 107], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[105], Children { parents: (48, 54), siblings: vec![96, 104, 106, 107], value: 0, possible_values: vec![5, 6, 8, 9] });
-    assert_eq!(children[106], Children { parents: (43, 54), siblings: vec![87, 97, 104, 105, 107], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[107], Children { parents: (31, 54), siblings: vec![61, 70, 79, 88, 98, 104, 105, 106], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[108], Children { parents: (32, 55), siblings: vec![62, 71, 81, 90, 99, 109, 110, 111], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[109], Children { parents: (45, 55), siblings: vec![91, 100, 108, 110, 111], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[110], Children { parents: (50, 55), siblings: vec![101, 108, 109, 111], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[111], Children { parents: (34, 55), siblings: vec![65, 74, 83, 92, 102, 108, 109, 110], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
+    assert_eq!(children[0], Children { parents: runs(0, 9), siblings: cells(&[1, 2, 3, 9, 19, 28, 37, 46]), value: 0, possible_values: digits(&[3]), position: (1, 2) });
+    assert_eq!(children[1], Children { parents: runs(1, 9), siblings: cells(&[0, 2, 3, 10]), value: 0, possible_values: digits(&[1, 2, 3]), position: (1, 3) });
+    assert_eq!(children[2], Children { parents: runs(2, 9), siblings: cells(&[0, 1, 3, 11, 20]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (1, 4) });
+    assert_eq!(children[3], Children { parents: runs(3, 9), siblings: cells(&[0, 1, 2, 12, 21, 30, 40, 49]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (1, 5) });
+    assert_eq!(children[4], Children { parents: runs(4, 10), siblings: cells(&[5, 6, 7, 13, 23, 32, 41, 50]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (1, 7) });
+    assert_eq!(children[5], Children { parents: runs(5, 10), siblings: cells(&[4, 6, 7, 14, 24]), value: 0, possible_values: digits(&[5, 6]), position: (1, 8) });
+    assert_eq!(children[6], Children { parents: runs(6, 10), siblings: cells(&[4, 5, 7, 15]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6]), position: (1, 9) });
+    assert_eq!(children[7], Children { parents: runs(7, 10), siblings: cells(&[4, 5, 6, 16, 25, 34, 44, 53]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6]), position: (1, 10) });
+    assert_eq!(children[8], Children { parents: runs(8, 12), siblings: cells(&[9, 10, 11, 12, 18, 27, 36]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (2, 1) });
+    assert_eq!(children[9], Children { parents: runs(0, 12), siblings: cells(&[0, 8, 10, 11, 12, 19, 28, 37, 46]), value: 0, possible_values: digits(&[3, 5, 6, 7]), position: (2, 2) });
+    assert_eq!(children[10], Children { parents: runs(1, 12), siblings: cells(&[1, 8, 9, 11, 12]), value: 0, possible_values: digits(&[1, 2, 3, 5, 6, 7]), position: (2, 3) });
+    assert_eq!(children[11], Children { parents: runs(2, 12), siblings: cells(&[2, 8, 9, 10, 12, 20]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (2, 4) });
+    assert_eq!(children[12], Children { parents: runs(3, 12), siblings: cells(&[3, 8, 9, 10, 11, 21, 30, 40, 49]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (2, 5) });
+    assert_eq!(children[13], Children { parents: runs(4, 14), siblings: cells(&[4, 14, 15, 16, 17, 23, 32, 41, 50]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (2, 7) });
+    assert_eq!(children[14], Children { parents: runs(5, 14), siblings: cells(&[5, 13, 15, 16, 17, 24]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (2, 8) });
+    assert_eq!(children[15], Children { parents: runs(6, 14), siblings: cells(&[6, 13, 14, 16, 17]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (2, 9) });
+    assert_eq!(children[16], Children { parents: runs(7, 14), siblings: cells(&[7, 13, 14, 15, 17, 25, 34, 44, 53]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (2, 10) });
+    assert_eq!(children[17], Children { parents: runs(11, 14), siblings: cells(&[13, 14, 15, 16, 26, 35, 45]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (2, 11) });
+    assert_eq!(children[18], Children { parents: runs(8, 15), siblings: cells(&[8, 19, 27, 36]), value: 0, possible_values: digits(&[2, 3, 5]), position: (3, 1) });
+    assert_eq!(children[19], Children { parents: runs(0, 15), siblings: cells(&[0, 9, 18, 28, 37, 46]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (3, 2) });
+    assert_eq!(children[20], Children { parents: runs(2, 17), siblings: cells(&[2, 11, 21, 22, 23, 24]), value: 0, possible_values: digits(&[4, 6, 7, 8]), position: (3, 4) });
+    assert_eq!(children[21], Children { parents: runs(3, 17), siblings: cells(&[3, 12, 20, 22, 23, 24, 30, 40, 49]), value: 0, possible_values: digits(&[4, 6, 7, 8]), position: (3, 5) });
+    assert_eq!(children[22], Children { parents: runs(13, 17), siblings: cells(&[20, 21, 23, 24, 31]), value: 0, possible_values: digits(&[6, 8, 9]), position: (3, 6) });
+    assert_eq!(children[23], Children { parents: runs(4, 17), siblings: cells(&[4, 13, 20, 21, 22, 24, 32, 41, 50]), value: 0, possible_values: digits(&[4, 6, 7, 8, 9]), position: (3, 7) });
+    assert_eq!(children[24], Children { parents: runs(5, 17), siblings: cells(&[5, 14, 20, 21, 22, 23]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (3, 8) });
+    assert_eq!(children[25], Children { parents: runs(7, 19), siblings: cells(&[7, 16, 26, 34, 44, 53]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (3, 10) });
+    assert_eq!(children[26], Children { parents: runs(11, 19), siblings: cells(&[17, 25, 35, 45]), value: 0, possible_values: digits(&[7, 8, 9]), position: (3, 11) });
+    assert_eq!(children[27], Children { parents: runs(8, 20), siblings: cells(&[8, 18, 28, 29, 36]), value: 0, possible_values: digits(&[5]), position: (4, 1) });
+    assert_eq!(children[28], Children { parents: runs(0, 20), siblings: cells(&[0, 9, 19, 27, 29, 37, 46]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (4, 2) });
+    assert_eq!(children[29], Children { parents: runs(16, 20), siblings: cells(&[27, 28, 38, 47, 54, 59, 68, 78]), value: 0, possible_values: digits(&[5, 6, 8]), position: (4, 3) });
+    assert_eq!(children[30], Children { parents: runs(3, 22), siblings: cells(&[3, 12, 21, 31, 32, 40, 49]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (4, 5) });
+    assert_eq!(children[31], Children { parents: runs(13, 22), siblings: cells(&[22, 30, 32]), value: 0, possible_values: digits(&[5, 6, 8, 9]), position: (4, 6) });
+    assert_eq!(children[32], Children { parents: runs(4, 22), siblings: cells(&[4, 13, 23, 30, 31, 41, 50]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (4, 7) });
+    assert_eq!(children[33], Children { parents: runs(18, 24), siblings: cells(&[34, 35, 43, 52, 57, 64, 73, 82]), value: 0, possible_values: digits(&[6, 8, 9]), position: (4, 9) });
+    assert_eq!(children[34], Children { parents: runs(7, 24), siblings: cells(&[7, 16, 25, 33, 35, 44, 53]), value: 0, possible_values: digits(&[6, 8, 9]), position: (4, 10) });
+    assert_eq!(children[35], Children { parents: runs(11, 24), siblings: cells(&[17, 26, 33, 34, 45]), value: 0, possible_values: digits(&[8, 9]), position: (4, 11) });
+    assert_eq!(children[36], Children { parents: runs(8, 25), siblings: cells(&[8, 18, 27, 37, 38, 39, 40]), value: 0, possible_values: digits(&[1, 2, 3]), position: (5, 1) });
+    assert_eq!(children[37], Children { parents: runs(0, 25), siblings: cells(&[0, 9, 19, 28, 36, 38, 39, 40, 46]), value: 0, possible_values: digits(&[3, 6]), position: (5, 2) });
+    assert_eq!(children[38], Children { parents: runs(16, 25), siblings: cells(&[29, 36, 37, 39, 40, 47, 54, 59, 68, 78]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (5, 3) });
+    assert_eq!(children[39], Children { parents: runs(21, 25), siblings: cells(&[36, 37, 38, 40, 48, 55, 60, 69]), value: 0, possible_values: digits(&[4, 6]), position: (5, 4) });
+    assert_eq!(children[40], Children { parents: runs(3, 25), siblings: cells(&[3, 12, 21, 30, 36, 37, 38, 39, 49]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (5, 5) });
+    assert_eq!(children[41], Children { parents: runs(4, 26), siblings: cells(&[4, 13, 23, 32, 42, 43, 44, 45, 50]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (5, 7) });
+    assert_eq!(children[42], Children { parents: runs(23, 26), siblings: cells(&[41, 43, 44, 45, 51, 56, 63, 72]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (5, 8) });
+    assert_eq!(children[43], Children { parents: runs(18, 26), siblings: cells(&[33, 41, 42, 44, 45, 52, 57, 64, 73, 82]), value: 0, possible_values: digits(&[2, 4, 5, 6, 7, 8, 9]), position: (5, 9) });
+    assert_eq!(children[44], Children { parents: runs(7, 26), siblings: cells(&[7, 16, 25, 34, 41, 42, 43, 45, 53]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8, 9]), position: (5, 10) });
+    assert_eq!(children[45], Children { parents: runs(11, 26), siblings: cells(&[17, 26, 35, 41, 42, 43, 44]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (5, 11) });
+    assert_eq!(children[46], Children { parents: runs(0, 27), siblings: cells(&[0, 9, 19, 28, 37, 47, 48, 49]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (6, 2) });
+    assert_eq!(children[47], Children { parents: runs(16, 27), siblings: cells(&[29, 38, 46, 48, 49, 54, 59, 68, 78]), value: 0, possible_values: digits(&[6, 8]), position: (6, 3) });
+    assert_eq!(children[48], Children { parents: runs(21, 27), siblings: cells(&[39, 46, 47, 49, 55, 60, 69]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (6, 4) });
+    assert_eq!(children[49], Children { parents: runs(3, 27), siblings: cells(&[3, 12, 21, 30, 40, 46, 47, 48]), value: 0, possible_values: digits(&[6, 7, 8]), position: (6, 5) });
+    assert_eq!(children[50], Children { parents: runs(4, 28), siblings: cells(&[4, 13, 23, 32, 41, 51, 52, 53]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (6, 7) });
+    assert_eq!(children[51], Children { parents: runs(23, 28), siblings: cells(&[42, 50, 52, 53, 56, 63, 72]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (6, 8) });
+    assert_eq!(children[52], Children { parents: runs(18, 28), siblings: cells(&[33, 43, 50, 51, 53, 57, 64, 73, 82]), value: 0, possible_values: digits(&[2, 4, 5, 6, 7, 8, 9]), position: (6, 9) });
+    assert_eq!(children[53], Children { parents: runs(7, 28), siblings: cells(&[7, 16, 25, 34, 44, 50, 51, 52]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8, 9]), position: (6, 10) });
+    assert_eq!(children[54], Children { parents: runs(16, 30), siblings: cells(&[29, 38, 47, 55, 59, 68, 78]), value: 0, possible_values: digits(&[1, 2, 3, 5, 6]), position: (7, 3) });
+    assert_eq!(children[55], Children { parents: runs(21, 30), siblings: cells(&[39, 48, 54, 60, 69]), value: 0, possible_values: digits(&[6, 7]), position: (7, 4) });
+    assert_eq!(children[56], Children { parents: runs(23, 33), siblings: cells(&[42, 51, 57, 63, 72]), value: 0, possible_values: digits(&[6]), position: (7, 8) });
+    assert_eq!(children[57], Children { parents: runs(18, 33), siblings: cells(&[33, 43, 52, 56, 64, 73, 82]), value: 0, possible_values: digits(&[5, 6, 8, 9]), position: (7, 9) });
+    assert_eq!(children[58], Children { parents: runs(29, 36), siblings: cells(&[59, 60, 61, 67, 77, 86, 95, 104]), value: 0, possible_values: digits(&[3, 5, 6]), position: (8, 2) });
+    assert_eq!(children[59], Children { parents: runs(16, 36), siblings: cells(&[29, 38, 47, 54, 58, 60, 61, 68, 78]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (8, 3) });
+    assert_eq!(children[60], Children { parents: runs(21, 36), siblings: cells(&[39, 48, 55, 58, 59, 61, 69]), value: 0, possible_values: digits(&[4, 6]), position: (8, 4) });
+    assert_eq!(children[61], Children { parents: runs(31, 36), siblings: cells(&[58, 59, 60, 70, 79, 88, 98, 107]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (8, 5) });
+    assert_eq!(children[62], Children { parents: runs(32, 37), siblings: cells(&[63, 64, 65, 71, 81, 90, 99, 108]), value: 0, possible_values: digits(&[3, 5]), position: (8, 7) });
+    assert_eq!(children[63], Children { parents: runs(23, 37), siblings: cells(&[42, 51, 56, 62, 64, 65, 72]), value: 0, possible_values: digits(&[1, 2, 3]), position: (8, 8) });
+    assert_eq!(children[64], Children { parents: runs(18, 37), siblings: cells(&[33, 43, 52, 57, 62, 63, 65, 73, 82]), value: 0, possible_values: digits(&[2, 5]), position: (8, 9) });
+    assert_eq!(children[65], Children { parents: runs(34, 37), siblings: cells(&[62, 63, 64, 74, 83, 92, 102, 111]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (8, 10) });
+    assert_eq!(children[66], Children { parents: runs(35, 39), siblings: cells(&[67, 68, 69, 70, 76, 85, 94]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (9, 1) });
+    assert_eq!(children[67], Children { parents: runs(29, 39), siblings: cells(&[58, 66, 68, 69, 70, 77, 86, 95, 104]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (9, 2) });
+    assert_eq!(children[68], Children { parents: runs(16, 39), siblings: cells(&[29, 38, 47, 54, 59, 66, 67, 69, 70, 78]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 8]), position: (9, 3) });
+    assert_eq!(children[69], Children { parents: runs(21, 39), siblings: cells(&[39, 48, 55, 60, 66, 67, 68, 70]), value: 0, possible_values: digits(&[4, 6, 7, 8, 9]), position: (9, 4) });
+    assert_eq!(children[70], Children { parents: runs(31, 39), siblings: cells(&[61, 66, 67, 68, 69, 79, 88, 98, 107]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (9, 5) });
+    assert_eq!(children[71], Children { parents: runs(32, 41), siblings: cells(&[62, 72, 73, 74, 75, 81, 90, 99, 108]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (9, 7) });
+    assert_eq!(children[72], Children { parents: runs(23, 41), siblings: cells(&[42, 51, 56, 63, 71, 73, 74, 75]), value: 0, possible_values: digits(&[4, 6]), position: (9, 8) });
+    assert_eq!(children[73], Children { parents: runs(18, 41), siblings: cells(&[33, 43, 52, 57, 64, 71, 72, 74, 75, 82]), value: 0, possible_values: digits(&[4, 6, 7, 8, 9]), position: (9, 9) });
+    assert_eq!(children[74], Children { parents: runs(34, 41), siblings: cells(&[65, 71, 72, 73, 75, 83, 92, 102, 111]), value: 0, possible_values: digits(&[4, 6]), position: (9, 10) });
+    assert_eq!(children[75], Children { parents: runs(38, 41), siblings: cells(&[71, 72, 73, 74, 84, 93, 103]), value: 0, possible_values: digits(&[4, 6, 7, 8]), position: (9, 11) });
+    assert_eq!(children[76], Children { parents: runs(35, 42), siblings: cells(&[66, 77, 78, 85, 94]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (10, 1) });
+    assert_eq!(children[77], Children { parents: runs(29, 42), siblings: cells(&[58, 67, 76, 78, 86, 95, 104]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (10, 2) });
+    assert_eq!(children[78], Children { parents: runs(16, 42), siblings: cells(&[29, 38, 47, 54, 59, 68, 76, 77]), value: 0, possible_values: digits(&[5, 6, 8]), position: (10, 3) });
+    assert_eq!(children[79], Children { parents: runs(31, 44), siblings: cells(&[61, 70, 80, 81, 88, 98, 107]), value: 0, possible_values: digits(&[6]), position: (10, 5) });
+    assert_eq!(children[80], Children { parents: runs(40, 44), siblings: cells(&[79, 81, 89]), value: 0, possible_values: digits(&[6, 8, 9]), position: (10, 6) });
+    assert_eq!(children[81], Children { parents: runs(32, 44), siblings: cells(&[62, 71, 79, 80, 90, 99, 108]), value: 0, possible_values: digits(&[6, 8, 9]), position: (10, 7) });
+    assert_eq!(children[82], Children { parents: runs(18, 46), siblings: cells(&[33, 43, 52, 57, 64, 73, 83, 84]), value: 0, possible_values: digits(&[2, 4]), position: (10, 9) });
+    assert_eq!(children[83], Children { parents: runs(34, 46), siblings: cells(&[65, 74, 82, 84, 92, 102, 111]), value: 0, possible_values: digits(&[1, 2, 4]), position: (10, 10) });
+    assert_eq!(children[84], Children { parents: runs(38, 46), siblings: cells(&[75, 82, 83, 93, 103]), value: 0, possible_values: digits(&[1, 2, 4]), position: (10, 11) });
+    assert_eq!(children[85], Children { parents: runs(35, 47), siblings: cells(&[66, 76, 86, 94]), value: 0, possible_values: digits(&[3, 4, 5, 7, 8, 9]), position: (11, 1) });
+    assert_eq!(children[86], Children { parents: runs(29, 47), siblings: cells(&[58, 67, 77, 85, 95, 104]), value: 0, possible_values: digits(&[3, 5, 7, 8, 9]), position: (11, 2) });
+    assert_eq!(children[87], Children { parents: runs(43, 49), siblings: cells(&[88, 89, 90, 91, 97, 106]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (11, 4) });
+    assert_eq!(children[88], Children { parents: runs(31, 49), siblings: cells(&[61, 70, 79, 87, 89, 90, 91, 98, 107]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (11, 5) });
+    assert_eq!(children[89], Children { parents: runs(40, 49), siblings: cells(&[80, 87, 88, 90, 91]), value: 0, possible_values: digits(&[5, 6, 8]), position: (11, 6) });
+    assert_eq!(children[90], Children { parents: runs(32, 49), siblings: cells(&[62, 71, 81, 87, 88, 89, 91, 99, 108]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8]), position: (11, 7) });
+    assert_eq!(children[91], Children { parents: runs(45, 49), siblings: cells(&[87, 88, 89, 90, 100, 109]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (11, 8) });
+    assert_eq!(children[92], Children { parents: runs(34, 51), siblings: cells(&[65, 74, 83, 93, 102, 111]), value: 0, possible_values: digits(&[1, 2, 4, 5]), position: (11, 10) });
+    assert_eq!(children[93], Children { parents: runs(38, 51), siblings: cells(&[75, 84, 92, 103]), value: 0, possible_values: digits(&[1, 2, 4, 5]), position: (11, 11) });
+    assert_eq!(children[94], Children { parents: runs(35, 52), siblings: cells(&[66, 76, 85, 95, 96, 97, 98]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (12, 1) });
+    assert_eq!(children[95], Children { parents: runs(29, 52), siblings: cells(&[58, 67, 77, 86, 94, 96, 97, 98, 104]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (12, 2) });
+    assert_eq!(children[96], Children { parents: runs(48, 52), siblings: cells(&[94, 95, 97, 98, 105]), value: 0, possible_values: digits(&[5, 6, 8, 9]), position: (12, 3) });
+    assert_eq!(children[97], Children { parents: runs(43, 52), siblings: cells(&[87, 94, 95, 96, 98, 106]), value: 0, possible_values: digits(&[5, 6]), position: (12, 4) });
+    assert_eq!(children[98], Children { parents: runs(31, 52), siblings: cells(&[61, 70, 79, 88, 94, 95, 96, 97, 107]), value: 0, possible_values: digits(&[5, 6]), position: (12, 5) });
+    assert_eq!(children[99], Children { parents: runs(32, 53), siblings: cells(&[62, 71, 81, 90, 100, 101, 102, 103, 108]), value: 0, possible_values: digits(&[3, 5, 6, 7]), position: (12, 7) });
+    assert_eq!(children[100], Children { parents: runs(45, 53), siblings: cells(&[91, 99, 101, 102, 103, 109]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (12, 8) });
+    assert_eq!(children[101], Children { parents: runs(50, 53), siblings: cells(&[99, 100, 102, 103, 110]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (12, 9) });
+    assert_eq!(children[102], Children { parents: runs(34, 53), siblings: cells(&[65, 74, 83, 92, 99, 100, 101, 103, 111]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (12, 10) });
+    assert_eq!(children[103], Children { parents: runs(38, 53), siblings: cells(&[75, 84, 93, 99, 100, 101, 102]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (12, 11) });
+    assert_eq!(children[104], Children { parents: runs(29, 54), siblings: cells(&[58, 67, 77, 86, 95, 105, 106, 107]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (13, 2) });
+    assert_eq!(children[105], Children { parents: runs(48, 54), siblings: cells(&[96, 104, 106, 107]), value: 0, possible_values: digits(&[5, 6, 8, 9]), position: (13, 3) });
+    assert_eq!(children[106], Children { parents: runs(43, 54), siblings: cells(&[87, 97, 104, 105, 107]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (13, 4) });
+    assert_eq!(children[107], Children { parents: runs(31, 54), siblings: cells(&[61, 70, 79, 88, 98, 104, 105, 106]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (13, 5) });
+    assert_eq!(children[108], Children { parents: runs(32, 55), siblings: cells(&[62, 71, 81, 90, 99, 109, 110, 111]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (13, 7) });
+    assert_eq!(children[109], Children { parents: runs(45, 55), siblings: cells(&[91, 100, 108, 110, 111]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (13, 8) });
+    assert_eq!(children[110], Children { parents: runs(50, 55), siblings: cells(&[101, 108, 109, 111]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (13, 9) });
+    assert_eq!(children[111], Children { parents: runs(34, 55), siblings: cells(&[65, 74, 83, 92, 102, 108, 109, 110]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (13, 10) });
   }
 
   #[test]
   fn test_puzzle_setup_with_input_file_4() {
-    let mut parents_and_children: (Vec<Parents>, Vec<Children>) = (Vec::new(), Vec::new());
-    let puzzle_file = "puzzles\\Kakuro_input4.txt".to_string();
+    let puzzle_file = puzzle_path("Kakuro_input4.txt");
 
-    puzzle_setup(&mut parents_and_children, puzzle_file);
+    let puzzle = Puzzle::load(&puzzle_file).unwrap();
 
-    let parents = parents_and_children.0;
-    let children = parents_and_children.1;
+    let parents = puzzle.parents;
+    let children = puzzle.children;
 
     assert_eq!(parents.len(), 68);
     assert_eq!(children.len(), 121);
 
-    assert_eq!(parents[0], Parents { children: vec![0, 8, 18, 27, 33, 43], sum: 23, value_size: "23-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 8], vec![1, 2, 3, 4, 6, 7]] });
-    assert_eq!(parents[1], Parents { children: vec![1, 9], sum: 14, value_size: "14-2".to_string(), combinations: vec![vec![5, 9], vec![6, 8]] });
-    assert_eq!(parents[2], Parents { children: vec![2, 10, 19], sum: 16, value_size: "16-3".to_string(), combinations: vec![vec![1, 6, 9], vec![1, 7, 8], vec![2, 5, 9], vec![2, 6, 8], vec![3, 4, 9], vec![3, 5, 8], vec![3, 6, 7], vec![4, 5, 7]] });
-    assert_eq!(parents[3], Parents { children: vec![3, 12, 22, 31, 37], sum: 21, value_size: "21-5".to_string(), combinations: vec![vec![1, 2, 3, 6, 9], vec![1, 2, 3, 7, 8], vec![1, 2, 4, 5, 9], vec![1, 2, 4, 6, 8], vec![1, 2, 5, 6, 7], vec![1, 3, 4, 5, 8], vec![1, 3, 4, 6, 7], vec![2, 3, 4, 5, 7]] });
-    assert_eq!(parents[4], Parents { children: vec![4, 13, 23, 32, 38, 46, 54], sum: 29, value_size: "29-7".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 8]] });
-    assert_eq!(parents[5], Parents { children: vec![5, 14, 24], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[6], Parents { children: vec![6, 15], sum: 6, value_size: "6-2".to_string(), combinations: vec![vec![1, 5], vec![2, 4]] });
-    assert_eq!(parents[7], Parents { children: vec![7, 17, 26], sum: 19, value_size: "19-3".to_string(), combinations: vec![vec![2, 8, 9], vec![3, 7, 9], vec![4, 6, 9], vec![4, 7, 8], vec![5, 6, 8]] });
-    assert_eq!(parents[8], Parents { children: vec![0, 1, 2], sum: 22, value_size: "22-3".to_string(), combinations: vec![vec![5, 8, 9], vec![6, 7, 9]] });
-    assert_eq!(parents[9], Parents { children: vec![11, 21, 30], sum: 20, value_size: "20-3".to_string(), combinations: vec![vec![3, 8, 9], vec![4, 7, 9], vec![5, 6, 9], vec![5, 7, 8]] });
-    assert_eq!(parents[10], Parents { children: vec![3, 4, 5, 6], sum: 16, value_size: "16-4".to_string(), combinations: vec![vec![1, 2, 4, 9], vec![1, 2, 5, 8], vec![1, 2, 6, 7], vec![1, 3, 4, 8], vec![1, 3, 5, 7], vec![1, 4, 5, 6], vec![2, 3, 4, 7], vec![2, 3, 5, 6]] });
-    assert_eq!(parents[11], Parents { children: vec![16, 25], sum: 15, value_size: "15-2".to_string(), combinations: vec![vec![6, 9], vec![7, 8]] });
-    assert_eq!(parents[12], Parents { children: vec![7, 8, 9, 10], sum: 18, value_size: "18-4".to_string(), combinations: vec![vec![1, 2, 6, 9], vec![1, 2, 7, 8], vec![1, 3, 5, 9], vec![1, 3, 6, 8], vec![1, 4, 5, 8], vec![1, 4, 6, 7], vec![2, 3, 4, 9], vec![2, 3, 5, 8], vec![2, 3, 6, 7], vec![2, 4, 5, 7], vec![3, 4, 5, 6]] });
-    assert_eq!(parents[13], Parents { children: vec![20, 29, 36], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[14], Parents { children: vec![11, 12, 13, 14, 15], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[15], Parents { children: vec![16, 17, 18], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[16], Parents { children: vec![28, 34, 44], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[17], Parents { children: vec![19, 20, 21, 22, 23, 24], sum: 38, value_size: "38-6".to_string(), combinations: vec![vec![3, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[18], Parents { children: vec![25, 26, 27, 28], sum: 29, value_size: "29-4".to_string(), combinations: vec![vec![5, 7, 8, 9]] });
-    assert_eq!(parents[19], Parents { children: vec![35, 45, 51, 59], sum: 16, value_size: "16-4".to_string(), combinations: vec![vec![1, 2, 4, 9], vec![1, 2, 5, 8], vec![1, 2, 6, 7], vec![1, 3, 4, 8], vec![1, 3, 5, 7], vec![1, 4, 5, 6], vec![2, 3, 4, 7], vec![2, 3, 5, 6]] });
-    assert_eq!(parents[20], Parents { children: vec![29, 30, 31, 32], sum: 16, value_size: "16-4".to_string(), combinations: vec![vec![1, 2, 4, 9], vec![1, 2, 5, 8], vec![1, 2, 6, 7], vec![1, 3, 4, 8], vec![1, 3, 5, 7], vec![1, 4, 5, 6], vec![2, 3, 4, 7], vec![2, 3, 5, 6]] });
-    assert_eq!(parents[21], Parents { children: vec![39, 47, 55, 63, 70, 78], sum: 39, value_size: "39-6".to_string(), combinations: vec![vec![4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[22], Parents { children: vec![40, 48], sum: 15, value_size: "15-2".to_string(), combinations: vec![vec![6, 9], vec![7, 8]] });
-    assert_eq!(parents[23], Parents { children: vec![41, 49, 56], sum: 6, value_size: "6-3".to_string(), combinations: vec![vec![1, 2, 3]] });
-    assert_eq!(parents[24], Parents { children: vec![42, 50, 57, 65, 73, 81], sum: 23, value_size: "23-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 8], vec![1, 2, 3, 4, 6, 7]] });
-    assert_eq!(parents[25], Parents { children: vec![33, 34, 35, 36], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[26], Parents { children: vec![37, 38, 39, 40], sum: 28, value_size: "28-4".to_string(), combinations: vec![vec![4, 7, 8, 9], vec![5, 6, 8, 9]] });
-    assert_eq!(parents[27], Parents { children: vec![41, 42, 43, 44, 45], sum: 17, value_size: "17-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 7], vec![1, 2, 3, 5, 6]] });
-    assert_eq!(parents[28], Parents { children: vec![52, 60, 68], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[29], Parents { children: vec![53, 62], sum: 14, value_size: "14-2".to_string(), combinations: vec![vec![5, 9], vec![6, 8]] });
-    assert_eq!(parents[30], Parents { children: vec![46, 47, 48], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[31], Parents { children: vec![49, 50], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[32], Parents { children: vec![58, 67], sum: 5, value_size: "5-2".to_string(), combinations: vec![vec![1, 4], vec![2, 3]] });
-    assert_eq!(parents[33], Parents { children: vec![51, 52], sum: 13, value_size: "13-2".to_string(), combinations: vec![vec![4, 9], vec![5, 8], vec![6, 7]] });
-    assert_eq!(parents[34], Parents { children: vec![61, 69, 75, 85], sum: 13, value_size: "13-4".to_string(), combinations: vec![vec![1, 2, 3, 7], vec![1, 2, 4, 6], vec![1, 3, 4, 5]] });
-    assert_eq!(parents[35], Parents { children: vec![53, 54, 55], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[36], Parents { children: vec![64, 71, 79], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[37], Parents { children: vec![56, 57], sum: 9, value_size: "9-2".to_string(), combinations: vec![vec![1, 8], vec![2, 7], vec![3, 6], vec![4, 5]] });
-    assert_eq!(parents[38], Parents { children: vec![66, 74, 82, 88, 97, 107, 116], sum: 41, value_size: "41-7".to_string(), combinations: vec![vec![2, 4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[39], Parents { children: vec![58, 59, 60, 61, 62], sum: 15, value_size: "15-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 5]] });
-    assert_eq!(parents[40], Parents { children: vec![63, 64], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[41], Parents { children: vec![72, 80], sum: 5, value_size: "5-2".to_string(), combinations: vec![vec![1, 4], vec![2, 3]] });
-    assert_eq!(parents[42], Parents { children: vec![65, 66, 67], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[43], Parents { children: vec![68, 69], sum: 10, value_size: "10-2".to_string(), combinations: vec![vec![1, 9], vec![2, 8], vec![3, 7], vec![4, 6]] });
-    assert_eq!(parents[44], Parents { children: vec![76, 86, 92], sum: 22, value_size: "22-3".to_string(), combinations: vec![vec![5, 8, 9], vec![6, 7, 9]] });
-    assert_eq!(parents[45], Parents { children: vec![77, 87, 93, 102, 112, 120], sum: 21, value_size: "21-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6]] });
-    assert_eq!(parents[46], Parents { children: vec![70, 71], sum: 13, value_size: "13-2".to_string(), combinations: vec![vec![4, 9], vec![5, 8], vec![6, 7]] });
-    assert_eq!(parents[47], Parents { children: vec![72, 73, 74], sum: 6, value_size: "6-3".to_string(), combinations: vec![vec![1, 2, 3]] });
-    assert_eq!(parents[48], Parents { children: vec![83, 89, 98, 108, 117], sum: 17, value_size: "17-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 7], vec![1, 2, 3, 5, 6]] });
-    assert_eq!(parents[49], Parents { children: vec![84, 91, 100], sum: 19, value_size: "19-3".to_string(), combinations: vec![vec![2, 8, 9], vec![3, 7, 9], vec![4, 6, 9], vec![4, 7, 8], vec![5, 6, 8]] });
-    assert_eq!(parents[50], Parents { children: vec![75, 76, 77, 78, 79], sum: 23, value_size: "23-5".to_string(), combinations: vec![vec![1, 2, 3, 8, 9], vec![1, 2, 4, 7, 9], vec![1, 2, 5, 6, 9], vec![1, 2, 5, 7, 8], vec![1, 3, 4, 6, 9], vec![1, 3, 4, 7, 8], vec![1, 3, 5, 6, 8], vec![1, 4, 5, 6, 7], vec![2, 3, 4, 5, 9], vec![2, 3, 4, 6, 8], vec![2, 3, 5, 6, 7]] });
-    assert_eq!(parents[51], Parents { children: vec![80, 81, 82, 83], sum: 10, value_size: "10-4".to_string(), combinations: vec![vec![1, 2, 3, 4]] });
-    assert_eq!(parents[52], Parents { children: vec![90, 99, 109], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[53], Parents { children: vec![84, 85, 86, 87], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[54], Parents { children: vec![94, 103, 113], sum: 8, value_size: "8-3".to_string(), combinations: vec![vec![1, 2, 5], vec![1, 3, 4]] });
-    assert_eq!(parents[55], Parents { children: vec![95, 104], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[56], Parents { children: vec![96, 106, 115], sum: 8, value_size: "8-3".to_string(), combinations: vec![vec![1, 2, 5], vec![1, 3, 4]] });
-    assert_eq!(parents[57], Parents { children: vec![88, 89, 90, 91], sum: 29, value_size: "29-4".to_string(), combinations: vec![vec![5, 7, 8, 9]] });
-    assert_eq!(parents[58], Parents { children: vec![101, 110, 118], sum: 22, value_size: "22-3".to_string(), combinations: vec![vec![5, 8, 9], vec![6, 7, 9]] });
-    assert_eq!(parents[59], Parents { children: vec![92, 93, 94, 95], sum: 15, value_size: "15-4".to_string(), combinations: vec![vec![1, 2, 3, 9], vec![1, 2, 4, 8], vec![1, 2, 5, 7], vec![1, 3, 4, 7], vec![1, 3, 5, 6], vec![2, 3, 4, 6]] });
-    assert_eq!(parents[60], Parents { children: vec![105, 114], sum: 6, value_size: "6-2".to_string(), combinations: vec![vec![1, 5], vec![2, 4]] });
-    assert_eq!(parents[61], Parents { children: vec![96, 97, 98, 99, 100, 101], sum: 36, value_size: "36-6".to_string(), combinations: vec![vec![1, 5, 6, 7, 8, 9], vec![2, 4, 6, 7, 8, 9], vec![3, 4, 5, 7, 8, 9]] });
-    assert_eq!(parents[62], Parents { children: vec![111, 119], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[63], Parents { children: vec![102, 103, 104], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[64], Parents { children: vec![105, 106, 107, 108, 109], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[65], Parents { children: vec![110, 111, 112, 113], sum: 27, value_size: "27-4".to_string(), combinations: vec![vec![3, 7, 8, 9], vec![4, 6, 8, 9], vec![5, 6, 7, 9]] });
-    assert_eq!(parents[66], Parents { children: vec![114, 115, 116, 117], sum: 15, value_size: "15-4".to_string(), combinations: vec![vec![1, 2, 3, 9], vec![1, 2, 4, 8], vec![1, 2, 5, 7], vec![1, 3, 4, 7], vec![1, 3, 5, 6], vec![2, 3, 4, 6]] });
-    assert_eq!(parents[67], Parents { children: vec![118, 119, 120], sum: 19, value_size: "19-3".to_string(), combinations: vec![vec![2, 8, 9], vec![3, 7, 9], vec![4, 6, 9], vec![4, 7, 8], vec![5, 6, 8]] });
+    assert_eq!(parents[0], Parents { children: cells(&[0, 8, 18, 27, 33, 43]), sum: 23, value_size: "23-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 8]), digits(&[1, 2, 3, 4, 6, 7])], direction: Direction::Down, head: (0, 3) });
+    assert_eq!(parents[1], Parents { children: cells(&[1, 9]), sum: 14, value_size: "14-2".to_string(), combinations: vec![digits(&[5, 9]), digits(&[6, 8])], direction: Direction::Down, head: (0, 4) });
+    assert_eq!(parents[2], Parents { children: cells(&[2, 10, 19]), sum: 16, value_size: "16-3".to_string(), combinations: vec![digits(&[1, 6, 9]), digits(&[1, 7, 8]), digits(&[2, 5, 9]), digits(&[2, 6, 8]), digits(&[3, 4, 9]), digits(&[3, 5, 8]), digits(&[3, 6, 7]), digits(&[4, 5, 7])], direction: Direction::Down, head: (0, 5) });
+    assert_eq!(parents[3], Parents { children: cells(&[3, 12, 22, 31, 37]), sum: 21, value_size: "21-5".to_string(), combinations: vec![digits(&[1, 2, 3, 6, 9]), digits(&[1, 2, 3, 7, 8]), digits(&[1, 2, 4, 5, 9]), digits(&[1, 2, 4, 6, 8]), digits(&[1, 2, 5, 6, 7]), digits(&[1, 3, 4, 5, 8]), digits(&[1, 3, 4, 6, 7]), digits(&[2, 3, 4, 5, 7])], direction: Direction::Down, head: (0, 8) });
+    assert_eq!(parents[4], Parents { children: cells(&[4, 13, 23, 32, 38, 46, 54]), sum: 29, value_size: "29-7".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 8])], direction: Direction::Down, head: (0, 9) });
+    assert_eq!(parents[5], Parents { children: cells(&[5, 14, 24]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Down, head: (0, 10) });
+    assert_eq!(parents[6], Parents { children: cells(&[6, 15]), sum: 6, value_size: "6-2".to_string(), combinations: vec![digits(&[1, 5]), digits(&[2, 4])], direction: Direction::Down, head: (0, 11) });
+    assert_eq!(parents[7], Parents { children: cells(&[7, 17, 26]), sum: 19, value_size: "19-3".to_string(), combinations: vec![digits(&[2, 8, 9]), digits(&[3, 7, 9]), digits(&[4, 6, 9]), digits(&[4, 7, 8]), digits(&[5, 6, 8])], direction: Direction::Down, head: (1, 2) });
+    assert_eq!(parents[8], Parents { children: cells(&[0, 1, 2]), sum: 22, value_size: "22-3".to_string(), combinations: vec![digits(&[5, 8, 9]), digits(&[6, 7, 9])], direction: Direction::Across, head: (1, 2) });
+    assert_eq!(parents[9], Parents { children: cells(&[11, 21, 30]), sum: 20, value_size: "20-3".to_string(), combinations: vec![digits(&[3, 8, 9]), digits(&[4, 7, 9]), digits(&[5, 6, 9]), digits(&[5, 7, 8])], direction: Direction::Down, head: (1, 7) });
+    assert_eq!(parents[10], Parents { children: cells(&[3, 4, 5, 6]), sum: 16, value_size: "16-4".to_string(), combinations: vec![digits(&[1, 2, 4, 9]), digits(&[1, 2, 5, 8]), digits(&[1, 2, 6, 7]), digits(&[1, 3, 4, 8]), digits(&[1, 3, 5, 7]), digits(&[1, 4, 5, 6]), digits(&[2, 3, 4, 7]), digits(&[2, 3, 5, 6])], direction: Direction::Across, head: (1, 7) });
+    assert_eq!(parents[11], Parents { children: cells(&[16, 25]), sum: 15, value_size: "15-2".to_string(), combinations: vec![digits(&[6, 9]), digits(&[7, 8])], direction: Direction::Down, head: (2, 1) });
+    assert_eq!(parents[12], Parents { children: cells(&[7, 8, 9, 10]), sum: 18, value_size: "18-4".to_string(), combinations: vec![digits(&[1, 2, 6, 9]), digits(&[1, 2, 7, 8]), digits(&[1, 3, 5, 9]), digits(&[1, 3, 6, 8]), digits(&[1, 4, 5, 8]), digits(&[1, 4, 6, 7]), digits(&[2, 3, 4, 9]), digits(&[2, 3, 5, 8]), digits(&[2, 3, 6, 7]), digits(&[2, 4, 5, 7]), digits(&[3, 4, 5, 6])], direction: Direction::Across, head: (2, 1) });
+    assert_eq!(parents[13], Parents { children: cells(&[20, 29, 36]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (2, 6) });
+    assert_eq!(parents[14], Parents { children: cells(&[11, 12, 13, 14, 15]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Across, head: (2, 6) });
+    assert_eq!(parents[15], Parents { children: cells(&[16, 17, 18]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Across, head: (3, 0) });
+    assert_eq!(parents[16], Parents { children: cells(&[28, 34, 44]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Down, head: (3, 4) });
+    assert_eq!(parents[17], Parents { children: cells(&[19, 20, 21, 22, 23, 24]), sum: 38, value_size: "38-6".to_string(), combinations: vec![digits(&[3, 5, 6, 7, 8, 9])], direction: Direction::Across, head: (3, 4) });
+    assert_eq!(parents[18], Parents { children: cells(&[25, 26, 27, 28]), sum: 29, value_size: "29-4".to_string(), combinations: vec![digits(&[5, 7, 8, 9])], direction: Direction::Across, head: (4, 0) });
+    assert_eq!(parents[19], Parents { children: cells(&[35, 45, 51, 59]), sum: 16, value_size: "16-4".to_string(), combinations: vec![digits(&[1, 2, 4, 9]), digits(&[1, 2, 5, 8]), digits(&[1, 2, 6, 7]), digits(&[1, 3, 4, 8]), digits(&[1, 3, 5, 7]), digits(&[1, 4, 5, 6]), digits(&[2, 3, 4, 7]), digits(&[2, 3, 5, 6])], direction: Direction::Down, head: (4, 5) });
+    assert_eq!(parents[20], Parents { children: cells(&[29, 30, 31, 32]), sum: 16, value_size: "16-4".to_string(), combinations: vec![digits(&[1, 2, 4, 9]), digits(&[1, 2, 5, 8]), digits(&[1, 2, 6, 7]), digits(&[1, 3, 4, 8]), digits(&[1, 3, 5, 7]), digits(&[1, 4, 5, 6]), digits(&[2, 3, 4, 7]), digits(&[2, 3, 5, 6])], direction: Direction::Across, head: (4, 5) });
+    assert_eq!(parents[21], Parents { children: cells(&[39, 47, 55, 63, 70, 78]), sum: 39, value_size: "39-6".to_string(), combinations: vec![digits(&[4, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (4, 10) });
+    assert_eq!(parents[22], Parents { children: cells(&[40, 48]), sum: 15, value_size: "15-2".to_string(), combinations: vec![digits(&[6, 9]), digits(&[7, 8])], direction: Direction::Down, head: (4, 11) });
+    assert_eq!(parents[23], Parents { children: cells(&[41, 49, 56]), sum: 6, value_size: "6-3".to_string(), combinations: vec![digits(&[1, 2, 3])], direction: Direction::Down, head: (5, 1) });
+    assert_eq!(parents[24], Parents { children: cells(&[42, 50, 57, 65, 73, 81]), sum: 23, value_size: "23-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 8]), digits(&[1, 2, 3, 4, 6, 7])], direction: Direction::Down, head: (5, 2) });
+    assert_eq!(parents[25], Parents { children: cells(&[33, 34, 35, 36]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Across, head: (5, 2) });
+    assert_eq!(parents[26], Parents { children: cells(&[37, 38, 39, 40]), sum: 28, value_size: "28-4".to_string(), combinations: vec![digits(&[4, 7, 8, 9]), digits(&[5, 6, 8, 9])], direction: Direction::Across, head: (5, 7) });
+    assert_eq!(parents[27], Parents { children: cells(&[41, 42, 43, 44, 45]), sum: 17, value_size: "17-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 7]), digits(&[1, 2, 3, 5, 6])], direction: Direction::Across, head: (6, 0) });
+    assert_eq!(parents[28], Parents { children: cells(&[52, 60, 68]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (6, 6) });
+    assert_eq!(parents[29], Parents { children: cells(&[53, 62]), sum: 14, value_size: "14-2".to_string(), combinations: vec![digits(&[5, 9]), digits(&[6, 8])], direction: Direction::Down, head: (6, 8) });
+    assert_eq!(parents[30], Parents { children: cells(&[46, 47, 48]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Across, head: (6, 8) });
+    assert_eq!(parents[31], Parents { children: cells(&[49, 50]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Across, head: (7, 0) });
+    assert_eq!(parents[32], Parents { children: cells(&[58, 67]), sum: 5, value_size: "5-2".to_string(), combinations: vec![digits(&[1, 4]), digits(&[2, 3])], direction: Direction::Down, head: (7, 4) });
+    assert_eq!(parents[33], Parents { children: cells(&[51, 52]), sum: 13, value_size: "13-2".to_string(), combinations: vec![digits(&[4, 9]), digits(&[5, 8]), digits(&[6, 7])], direction: Direction::Across, head: (7, 4) });
+    assert_eq!(parents[34], Parents { children: cells(&[61, 69, 75, 85]), sum: 13, value_size: "13-4".to_string(), combinations: vec![digits(&[1, 2, 3, 7]), digits(&[1, 2, 4, 6]), digits(&[1, 3, 4, 5])], direction: Direction::Down, head: (7, 7) });
+    assert_eq!(parents[35], Parents { children: cells(&[53, 54, 55]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (7, 7) });
+    assert_eq!(parents[36], Parents { children: cells(&[64, 71, 79]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Down, head: (7, 11) });
+    assert_eq!(parents[37], Parents { children: cells(&[56, 57]), sum: 9, value_size: "9-2".to_string(), combinations: vec![digits(&[1, 8]), digits(&[2, 7]), digits(&[3, 6]), digits(&[4, 5])], direction: Direction::Across, head: (8, 0) });
+    assert_eq!(parents[38], Parents { children: cells(&[66, 74, 82, 88, 97, 107, 116]), sum: 41, value_size: "41-7".to_string(), combinations: vec![digits(&[2, 4, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (8, 3) });
+    assert_eq!(parents[39], Parents { children: cells(&[58, 59, 60, 61, 62]), sum: 15, value_size: "15-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5])], direction: Direction::Across, head: (8, 3) });
+    assert_eq!(parents[40], Parents { children: cells(&[63, 64]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Across, head: (8, 9) });
+    assert_eq!(parents[41], Parents { children: cells(&[72, 80]), sum: 5, value_size: "5-2".to_string(), combinations: vec![digits(&[1, 4]), digits(&[2, 3])], direction: Direction::Down, head: (9, 1) });
+    assert_eq!(parents[42], Parents { children: cells(&[65, 66, 67]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Across, head: (9, 1) });
+    assert_eq!(parents[43], Parents { children: cells(&[68, 69]), sum: 10, value_size: "10-2".to_string(), combinations: vec![digits(&[1, 9]), digits(&[2, 8]), digits(&[3, 7]), digits(&[4, 6])], direction: Direction::Across, head: (9, 5) });
+    assert_eq!(parents[44], Parents { children: cells(&[76, 86, 92]), sum: 22, value_size: "22-3".to_string(), combinations: vec![digits(&[5, 8, 9]), digits(&[6, 7, 9])], direction: Direction::Down, head: (9, 8) });
+    assert_eq!(parents[45], Parents { children: cells(&[77, 87, 93, 102, 112, 120]), sum: 21, value_size: "21-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6])], direction: Direction::Down, head: (9, 9) });
+    assert_eq!(parents[46], Parents { children: cells(&[70, 71]), sum: 13, value_size: "13-2".to_string(), combinations: vec![digits(&[4, 9]), digits(&[5, 8]), digits(&[6, 7])], direction: Direction::Across, head: (9, 9) });
+    assert_eq!(parents[47], Parents { children: cells(&[72, 73, 74]), sum: 6, value_size: "6-3".to_string(), combinations: vec![digits(&[1, 2, 3])], direction: Direction::Across, head: (10, 0) });
+    assert_eq!(parents[48], Parents { children: cells(&[83, 89, 98, 108, 117]), sum: 17, value_size: "17-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 7]), digits(&[1, 2, 3, 5, 6])], direction: Direction::Down, head: (10, 4) });
+    assert_eq!(parents[49], Parents { children: cells(&[84, 91, 100]), sum: 19, value_size: "19-3".to_string(), combinations: vec![digits(&[2, 8, 9]), digits(&[3, 7, 9]), digits(&[4, 6, 9]), digits(&[4, 7, 8]), digits(&[5, 6, 8])], direction: Direction::Down, head: (10, 6) });
+    assert_eq!(parents[50], Parents { children: cells(&[75, 76, 77, 78, 79]), sum: 23, value_size: "23-5".to_string(), combinations: vec![digits(&[1, 2, 3, 8, 9]), digits(&[1, 2, 4, 7, 9]), digits(&[1, 2, 5, 6, 9]), digits(&[1, 2, 5, 7, 8]), digits(&[1, 3, 4, 6, 9]), digits(&[1, 3, 4, 7, 8]), digits(&[1, 3, 5, 6, 8]), digits(&[1, 4, 5, 6, 7]), digits(&[2, 3, 4, 5, 9]), digits(&[2, 3, 4, 6, 8]), digits(&[2, 3, 5, 6, 7])], direction: Direction::Across, head: (10, 6) });
+    assert_eq!(parents[51], Parents { children: cells(&[80, 81, 82, 83]), sum: 10, value_size: "10-4".to_string(), combinations: vec![digits(&[1, 2, 3, 4])], direction: Direction::Across, head: (11, 0) });
+    assert_eq!(parents[52], Parents { children: cells(&[90, 99, 109]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Down, head: (11, 5) });
+    assert_eq!(parents[53], Parents { children: cells(&[84, 85, 86, 87]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Across, head: (11, 5) });
+    assert_eq!(parents[54], Parents { children: cells(&[94, 103, 113]), sum: 8, value_size: "8-3".to_string(), combinations: vec![digits(&[1, 2, 5]), digits(&[1, 3, 4])], direction: Direction::Down, head: (11, 10) });
+    assert_eq!(parents[55], Parents { children: cells(&[95, 104]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (11, 11) });
+    assert_eq!(parents[56], Parents { children: cells(&[96, 106, 115]), sum: 8, value_size: "8-3".to_string(), combinations: vec![digits(&[1, 2, 5]), digits(&[1, 3, 4])], direction: Direction::Down, head: (12, 2) });
+    assert_eq!(parents[57], Parents { children: cells(&[88, 89, 90, 91]), sum: 29, value_size: "29-4".to_string(), combinations: vec![digits(&[5, 7, 8, 9])], direction: Direction::Across, head: (12, 2) });
+    assert_eq!(parents[58], Parents { children: cells(&[101, 110, 118]), sum: 22, value_size: "22-3".to_string(), combinations: vec![digits(&[5, 8, 9]), digits(&[6, 7, 9])], direction: Direction::Down, head: (12, 7) });
+    assert_eq!(parents[59], Parents { children: cells(&[92, 93, 94, 95]), sum: 15, value_size: "15-4".to_string(), combinations: vec![digits(&[1, 2, 3, 9]), digits(&[1, 2, 4, 8]), digits(&[1, 2, 5, 7]), digits(&[1, 3, 4, 7]), digits(&[1, 3, 5, 6]), digits(&[2, 3, 4, 6])], direction: Direction::Across, head: (12, 7) });
+    assert_eq!(parents[60], Parents { children: cells(&[105, 114]), sum: 6, value_size: "6-2".to_string(), combinations: vec![digits(&[1, 5]), digits(&[2, 4])], direction: Direction::Down, head: (13, 1) });
+    assert_eq!(parents[61], Parents { children: cells(&[96, 97, 98, 99, 100, 101]), sum: 36, value_size: "36-6".to_string(), combinations: vec![digits(&[1, 5, 6, 7, 8, 9]), digits(&[2, 4, 6, 7, 8, 9]), digits(&[3, 4, 5, 7, 8, 9])], direction: Direction::Across, head: (13, 1) });
+    assert_eq!(parents[62], Parents { children: cells(&[111, 119]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Down, head: (13, 8) });
+    assert_eq!(parents[63], Parents { children: cells(&[102, 103, 104]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (13, 8) });
+    assert_eq!(parents[64], Parents { children: cells(&[105, 106, 107, 108, 109]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Across, head: (14, 0) });
+    assert_eq!(parents[65], Parents { children: cells(&[110, 111, 112, 113]), sum: 27, value_size: "27-4".to_string(), combinations: vec![digits(&[3, 7, 8, 9]), digits(&[4, 6, 8, 9]), digits(&[5, 6, 7, 9])], direction: Direction::Across, head: (14, 6) });
+    assert_eq!(parents[66], Parents { children: cells(&[114, 115, 116, 117]), sum: 15, value_size: "15-4".to_string(), combinations: vec![digits(&[1, 2, 3, 9]), digits(&[1, 2, 4, 8]), digits(&[1, 2, 5, 7]), digits(&[1, 3, 4, 7]), digits(&[1, 3, 5, 6]), digits(&[2, 3, 4, 6])], direction: Direction::Across, head: (15, 0) });
+    assert_eq!(parents[67], Parents { children: cells(&[118, 119, 120]), sum: 19, value_size: "19-3".to_string(), combinations: vec![digits(&[2, 8, 9]), digits(&[3, 7, 9]), digits(&[4, 6, 9]), digits(&[4, 7, 8]), digits(&[5, 6, 8])], direction: Direction::Across, head: (15, 6) });
    
-    assert_eq!(children[0], Children { parents: (0, 8), siblings: vec![1, 2, 8, 18, 27, 33, 43], value: 0, possible_values: vec![5, 6, 7, 8] });
-    assert_eq!(children[1], Children { parents: (1, 8), siblings: vec![0, 2, 9], value: 0, possible_values: vec![5, 6, 8, 9] });
-    assert_eq!(children[2], Children { parents: (2, 8), siblings: vec![0, 1, 10, 19], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[3], Children { parents: (3, 10), siblings: vec![4, 5, 6, 12, 22, 31, 37], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[4], Children { parents: (4, 10), siblings: vec![3, 5, 6, 13, 23, 32, 38, 46, 54], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 8] });
-    assert_eq!(children[5], Children { parents: (5, 10), siblings: vec![3, 4, 6, 14, 24], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[6], Children { parents: (6, 10), siblings: vec![3, 4, 5, 15], value: 0, possible_values: vec![1, 2, 4, 5] });
-    assert_eq!(children[7], Children { parents: (7, 12), siblings: vec![8, 9, 10, 17, 26], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[8], Children { parents: (0, 12), siblings: vec![0, 7, 9, 10, 18, 27, 33, 43], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[9], Children { parents: (1, 12), siblings: vec![1, 7, 8, 10], value: 0, possible_values: vec![5, 6, 8, 9] });
-    assert_eq!(children[10], Children { parents: (2, 12), siblings: vec![2, 7, 8, 9, 19], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[11], Children { parents: (9, 14), siblings: vec![12, 13, 14, 15, 21, 30], value: 0, possible_values: vec![3, 4, 6] });
-    assert_eq!(children[12], Children { parents: (3, 14), siblings: vec![3, 11, 13, 14, 15, 22, 31, 37], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[13], Children { parents: (4, 14), siblings: vec![4, 11, 12, 14, 15, 23, 32, 38, 46, 54], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[14], Children { parents: (5, 14), siblings: vec![5, 11, 12, 13, 15, 24], value: 0, possible_values: vec![6] });
-    assert_eq!(children[15], Children { parents: (6, 14), siblings: vec![6, 11, 12, 13, 14], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[16], Children { parents: (11, 15), siblings: vec![17, 18, 25], value: 0, possible_values: vec![6] });
-    assert_eq!(children[17], Children { parents: (7, 15), siblings: vec![7, 16, 18, 26], value: 0, possible_values: vec![2, 3, 4, 5, 6] });
-    assert_eq!(children[18], Children { parents: (0, 15), siblings: vec![0, 8, 16, 17, 27, 33, 43], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[19], Children { parents: (2, 17), siblings: vec![2, 10, 20, 21, 22, 23, 24], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[20], Children { parents: (13, 17), siblings: vec![19, 21, 22, 23, 24, 29, 36], value: 0, possible_values: vec![3, 5, 6, 7, 8] });
-    assert_eq!(children[21], Children { parents: (9, 17), siblings: vec![11, 19, 20, 22, 23, 24, 30], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[22], Children { parents: (3, 17), siblings: vec![3, 12, 19, 20, 21, 23, 24, 31, 37], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[23], Children { parents: (4, 17), siblings: vec![4, 13, 19, 20, 21, 22, 24, 32, 38, 46, 54], value: 0, possible_values: vec![3, 5, 6, 8] });
-    assert_eq!(children[24], Children { parents: (5, 17), siblings: vec![5, 14, 19, 20, 21, 22, 23], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[25], Children { parents: (11, 18), siblings: vec![16, 26, 27, 28], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[26], Children { parents: (7, 18), siblings: vec![7, 17, 25, 27, 28], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[27], Children { parents: (0, 18), siblings: vec![0, 8, 18, 25, 26, 28, 33, 43], value: 0, possible_values: vec![5, 7, 8] });
-    assert_eq!(children[28], Children { parents: (16, 18), siblings: vec![25, 26, 27, 34, 44], value: 0, possible_values: vec![5] });
-    assert_eq!(children[29], Children { parents: (13, 20), siblings: vec![20, 30, 31, 32, 36], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[30], Children { parents: (9, 20), siblings: vec![11, 21, 29, 31, 32], value: 0, possible_values: vec![3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[31], Children { parents: (3, 20), siblings: vec![3, 12, 22, 29, 30, 32, 37], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[32], Children { parents: (4, 20), siblings: vec![4, 13, 23, 29, 30, 31, 38, 46, 54], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 8] });
-    assert_eq!(children[33], Children { parents: (0, 25), siblings: vec![0, 8, 18, 27, 34, 35, 36, 43], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[34], Children { parents: (16, 25), siblings: vec![28, 33, 35, 36, 44], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[35], Children { parents: (19, 25), siblings: vec![33, 34, 36, 45, 51, 59], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[36], Children { parents: (13, 25), siblings: vec![20, 29, 33, 34, 35], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[37], Children { parents: (3, 26), siblings: vec![3, 12, 22, 31, 38, 39, 40], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[38], Children { parents: (4, 26), siblings: vec![4, 13, 23, 32, 37, 39, 40, 46, 54], value: 0, possible_values: vec![4, 5, 6, 8] });
-    assert_eq!(children[39], Children { parents: (21, 26), siblings: vec![37, 38, 40, 47, 55, 63, 70, 78], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[40], Children { parents: (22, 26), siblings: vec![37, 38, 39, 48], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[41], Children { parents: (23, 27), siblings: vec![42, 43, 44, 45, 49, 56], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[42], Children { parents: (24, 27), siblings: vec![41, 43, 44, 45, 50, 57, 65, 73, 81], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[43], Children { parents: (0, 27), siblings: vec![0, 8, 18, 27, 33, 41, 42, 44, 45], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[44], Children { parents: (16, 27), siblings: vec![28, 34, 41, 42, 43, 45], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[45], Children { parents: (19, 27), siblings: vec![35, 41, 42, 43, 44, 51, 59], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[46], Children { parents: (4, 30), siblings: vec![4, 13, 23, 32, 38, 47, 48, 54], value: 0, possible_values: vec![8] });
-    assert_eq!(children[47], Children { parents: (21, 30), siblings: vec![39, 46, 48, 55, 63, 70, 78], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[48], Children { parents: (22, 30), siblings: vec![40, 46, 47], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[49], Children { parents: (23, 31), siblings: vec![41, 50, 56], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[50], Children { parents: (24, 31), siblings: vec![42, 49, 57, 65, 73, 81], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[51], Children { parents: (19, 33), siblings: vec![35, 45, 52, 59], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[52], Children { parents: (28, 33), siblings: vec![51, 60, 68], value: 0, possible_values: vec![4, 5, 6, 7, 8] });
-    assert_eq!(children[53], Children { parents: (29, 35), siblings: vec![54, 55, 62], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[54], Children { parents: (4, 35), siblings: vec![4, 13, 23, 32, 38, 46, 53, 55], value: 0, possible_values: vec![6, 8] });
-    assert_eq!(children[55], Children { parents: (21, 35), siblings: vec![39, 47, 53, 54, 63, 70, 78], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[56], Children { parents: (23, 37), siblings: vec![41, 49, 57], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[57], Children { parents: (24, 37), siblings: vec![42, 50, 56, 65, 73, 81], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[58], Children { parents: (32, 39), siblings: vec![59, 60, 61, 62, 67], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[59], Children { parents: (19, 39), siblings: vec![35, 45, 51, 58, 60, 61, 62], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[60], Children { parents: (28, 39), siblings: vec![52, 58, 59, 61, 62, 68], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[61], Children { parents: (34, 39), siblings: vec![58, 59, 60, 62, 69, 75, 85], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[62], Children { parents: (29, 39), siblings: vec![53, 58, 59, 60, 61], value: 0, possible_values: vec![5] });
-    assert_eq!(children[63], Children { parents: (21, 40), siblings: vec![39, 47, 55, 64, 70, 78], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[64], Children { parents: (36, 40), siblings: vec![63, 71, 79], value: 0, possible_values: vec![9] });
-    assert_eq!(children[65], Children { parents: (24, 42), siblings: vec![42, 50, 57, 66, 67, 73, 81], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[66], Children { parents: (38, 42), siblings: vec![65, 67, 74, 82, 88, 97, 107, 116], value: 0, possible_values: vec![2, 4, 5, 6, 7] });
-    assert_eq!(children[67], Children { parents: (32, 42), siblings: vec![58, 65, 66], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[68], Children { parents: (28, 43), siblings: vec![52, 60, 69], value: 0, possible_values: vec![1, 2, 3, 4, 6, 7, 8] });
-    assert_eq!(children[69], Children { parents: (34, 43), siblings: vec![61, 68, 75, 85], value: 0, possible_values: vec![1, 2, 3, 4, 6, 7] });
-    assert_eq!(children[70], Children { parents: (21, 46), siblings: vec![39, 47, 55, 63, 71, 78], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[71], Children { parents: (36, 46), siblings: vec![64, 70, 79], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[72], Children { parents: (41, 47), siblings: vec![73, 74, 80], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[73], Children { parents: (24, 47), siblings: vec![42, 50, 57, 65, 72, 74, 81], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[74], Children { parents: (38, 47), siblings: vec![66, 72, 73, 82, 88, 97, 107, 116], value: 0, possible_values: vec![2] });
-    assert_eq!(children[75], Children { parents: (34, 50), siblings: vec![61, 69, 76, 77, 78, 79, 85], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[76], Children { parents: (44, 50), siblings: vec![75, 77, 78, 79, 86, 92], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[77], Children { parents: (45, 50), siblings: vec![75, 76, 78, 79, 87, 93, 102, 112, 120], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[78], Children { parents: (21, 50), siblings: vec![39, 47, 55, 63, 70, 75, 76, 77, 79], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[79], Children { parents: (36, 50), siblings: vec![64, 71, 75, 76, 77, 78], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[80], Children { parents: (41, 51), siblings: vec![72, 81, 82, 83], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[81], Children { parents: (24, 51), siblings: vec![42, 50, 57, 65, 73, 80, 82, 83], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[82], Children { parents: (38, 51), siblings: vec![66, 74, 80, 81, 83, 88, 97, 107, 116], value: 0, possible_values: vec![2, 4] });
-    assert_eq!(children[83], Children { parents: (48, 51), siblings: vec![80, 81, 82, 89, 98, 108, 117], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[84], Children { parents: (49, 53), siblings: vec![85, 86, 87, 91, 100], value: 0, possible_values: vec![2, 3, 5] });
-    assert_eq!(children[85], Children { parents: (34, 53), siblings: vec![61, 69, 75, 84, 86, 87], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[86], Children { parents: (44, 53), siblings: vec![76, 84, 85, 87, 92], value: 0, possible_values: vec![5] });
-    assert_eq!(children[87], Children { parents: (45, 53), siblings: vec![77, 84, 85, 86, 93, 102, 112, 120], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[88], Children { parents: (38, 57), siblings: vec![66, 74, 82, 89, 90, 91, 97, 107, 116], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[89], Children { parents: (48, 57), siblings: vec![83, 88, 90, 91, 98, 108, 117], value: 0, possible_values: vec![5, 7] });
-    assert_eq!(children[90], Children { parents: (52, 57), siblings: vec![88, 89, 91, 99, 109], value: 0, possible_values: vec![5, 7] });
-    assert_eq!(children[91], Children { parents: (49, 57), siblings: vec![84, 88, 89, 90, 100], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[92], Children { parents: (44, 59), siblings: vec![76, 86, 93, 94, 95], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[93], Children { parents: (45, 59), siblings: vec![77, 87, 92, 94, 95, 102, 112, 120], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[94], Children { parents: (54, 59), siblings: vec![92, 93, 95, 103, 113], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[95], Children { parents: (55, 59), siblings: vec![92, 93, 94, 104], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[96], Children { parents: (56, 61), siblings: vec![97, 98, 99, 100, 101, 106, 115], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[97], Children { parents: (38, 61), siblings: vec![66, 74, 82, 88, 96, 98, 99, 100, 101, 107, 116], value: 0, possible_values: vec![2, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[98], Children { parents: (48, 61), siblings: vec![83, 89, 96, 97, 99, 100, 101, 108, 117], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[99], Children { parents: (52, 61), siblings: vec![90, 96, 97, 98, 100, 101, 109], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[100], Children { parents: (49, 61), siblings: vec![84, 91, 96, 97, 98, 99, 101], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[101], Children { parents: (58, 61), siblings: vec![96, 97, 98, 99, 100, 110, 118], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[102], Children { parents: (45, 63), siblings: vec![77, 87, 93, 103, 104, 112, 120], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[103], Children { parents: (54, 63), siblings: vec![94, 102, 104, 113], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[104], Children { parents: (55, 63), siblings: vec![95, 102, 103], value: 0, possible_values: vec![1] });
-    assert_eq!(children[105], Children { parents: (60, 64), siblings: vec![106, 107, 108, 109, 114], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[106], Children { parents: (56, 64), siblings: vec![96, 105, 107, 108, 109, 115], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[107], Children { parents: (38, 64), siblings: vec![66, 74, 82, 88, 97, 105, 106, 108, 109, 116], value: 0, possible_values: vec![2, 4, 6] });
-    assert_eq!(children[108], Children { parents: (48, 64), siblings: vec![83, 89, 98, 105, 106, 107, 109, 117], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[109], Children { parents: (52, 64), siblings: vec![90, 99, 105, 106, 107, 108], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[110], Children { parents: (58, 65), siblings: vec![101, 111, 112, 113, 118], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[111], Children { parents: (62, 65), siblings: vec![110, 112, 113, 119], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[112], Children { parents: (45, 65), siblings: vec![77, 87, 93, 102, 110, 111, 113, 120], value: 0, possible_values: vec![3, 4, 5, 6] });
-    assert_eq!(children[113], Children { parents: (54, 65), siblings: vec![94, 103, 110, 111, 112], value: 0, possible_values: vec![3, 4, 5] });
-    assert_eq!(children[114], Children { parents: (60, 66), siblings: vec![105, 115, 116, 117], value: 0, possible_values: vec![1, 2, 4, 5] });
-    assert_eq!(children[115], Children { parents: (56, 66), siblings: vec![96, 106, 114, 116, 117], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[116], Children { parents: (38, 66), siblings: vec![66, 74, 82, 88, 97, 107, 114, 115, 117], value: 0, possible_values: vec![2, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[117], Children { parents: (48, 66), siblings: vec![83, 89, 98, 108, 114, 115, 116], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[118], Children { parents: (58, 67), siblings: vec![101, 110, 119, 120], value: 0, possible_values: vec![5, 6, 7, 8, 9] });
-    assert_eq!(children[119], Children { parents: (62, 67), siblings: vec![111, 118, 120], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[120], Children { parents: (45, 67), siblings: vec![77, 87, 93, 102, 112, 118, 119], value: 0, possible_values: vec![2, 3, 4, 5, 6] });
+    assert_eq!(children[0], Children { parents: runs(0, 8), siblings: cells(&[1, 2, 8, 18, 27, 33, 43]), value: 0, possible_values: digits(&[5, 6, 7, 8]), position: (1, 3) });
+    assert_eq!(children[1], Children { parents: runs(1, 8), siblings: cells(&[0, 2, 9]), value: 0, possible_values: digits(&[5, 6, 8, 9]), position: (1, 4) });
+    assert_eq!(children[2], Children { parents: runs(2, 8), siblings: cells(&[0, 1, 10, 19]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (1, 5) });
+    assert_eq!(children[3], Children { parents: runs(3, 10), siblings: cells(&[4, 5, 6, 12, 22, 31, 37]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (1, 8) });
+    assert_eq!(children[4], Children { parents: runs(4, 10), siblings: cells(&[3, 5, 6, 13, 23, 32, 38, 46, 54]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 8]), position: (1, 9) });
+    assert_eq!(children[5], Children { parents: runs(5, 10), siblings: cells(&[3, 4, 6, 14, 24]), value: 0, possible_values: digits(&[6, 8, 9]), position: (1, 10) });
+    assert_eq!(children[6], Children { parents: runs(6, 10), siblings: cells(&[3, 4, 5, 15]), value: 0, possible_values: digits(&[1, 2, 4, 5]), position: (1, 11) });
+    assert_eq!(children[7], Children { parents: runs(7, 12), siblings: cells(&[8, 9, 10, 17, 26]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8, 9]), position: (2, 2) });
+    assert_eq!(children[8], Children { parents: runs(0, 12), siblings: cells(&[0, 7, 9, 10, 18, 27, 33, 43]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (2, 3) });
+    assert_eq!(children[9], Children { parents: runs(1, 12), siblings: cells(&[1, 7, 8, 10]), value: 0, possible_values: digits(&[5, 6, 8, 9]), position: (2, 4) });
+    assert_eq!(children[10], Children { parents: runs(2, 12), siblings: cells(&[2, 7, 8, 9, 19]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (2, 5) });
+    assert_eq!(children[11], Children { parents: runs(9, 14), siblings: cells(&[12, 13, 14, 15, 21, 30]), value: 0, possible_values: digits(&[3, 4, 6]), position: (2, 7) });
+    assert_eq!(children[12], Children { parents: runs(3, 14), siblings: cells(&[3, 11, 13, 14, 15, 22, 31, 37]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (2, 8) });
+    assert_eq!(children[13], Children { parents: runs(4, 14), siblings: cells(&[4, 11, 12, 14, 15, 23, 32, 38, 46, 54]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (2, 9) });
+    assert_eq!(children[14], Children { parents: runs(5, 14), siblings: cells(&[5, 11, 12, 13, 15, 24]), value: 0, possible_values: digits(&[6]), position: (2, 10) });
+    assert_eq!(children[15], Children { parents: runs(6, 14), siblings: cells(&[6, 11, 12, 13, 14]), value: 0, possible_values: digits(&[1, 2, 4]), position: (2, 11) });
+    assert_eq!(children[16], Children { parents: runs(11, 15), siblings: cells(&[17, 18, 25]), value: 0, possible_values: digits(&[6]), position: (3, 1) });
+    assert_eq!(children[17], Children { parents: runs(7, 15), siblings: cells(&[7, 16, 18, 26]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6]), position: (3, 2) });
+    assert_eq!(children[18], Children { parents: runs(0, 15), siblings: cells(&[0, 8, 16, 17, 27, 33, 43]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (3, 3) });
+    assert_eq!(children[19], Children { parents: runs(2, 17), siblings: cells(&[2, 10, 20, 21, 22, 23, 24]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (3, 5) });
+    assert_eq!(children[20], Children { parents: runs(13, 17), siblings: cells(&[19, 21, 22, 23, 24, 29, 36]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8]), position: (3, 6) });
+    assert_eq!(children[21], Children { parents: runs(9, 17), siblings: cells(&[11, 19, 20, 22, 23, 24, 30]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (3, 7) });
+    assert_eq!(children[22], Children { parents: runs(3, 17), siblings: cells(&[3, 12, 19, 20, 21, 23, 24, 31, 37]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (3, 8) });
+    assert_eq!(children[23], Children { parents: runs(4, 17), siblings: cells(&[4, 13, 19, 20, 21, 22, 24, 32, 38, 46, 54]), value: 0, possible_values: digits(&[3, 5, 6, 8]), position: (3, 9) });
+    assert_eq!(children[24], Children { parents: runs(5, 17), siblings: cells(&[5, 14, 19, 20, 21, 22, 23]), value: 0, possible_values: digits(&[6, 8, 9]), position: (3, 10) });
+    assert_eq!(children[25], Children { parents: runs(11, 18), siblings: cells(&[16, 26, 27, 28]), value: 0, possible_values: digits(&[7, 8, 9]), position: (4, 1) });
+    assert_eq!(children[26], Children { parents: runs(7, 18), siblings: cells(&[7, 17, 25, 27, 28]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (4, 2) });
+    assert_eq!(children[27], Children { parents: runs(0, 18), siblings: cells(&[0, 8, 18, 25, 26, 28, 33, 43]), value: 0, possible_values: digits(&[5, 7, 8]), position: (4, 3) });
+    assert_eq!(children[28], Children { parents: runs(16, 18), siblings: cells(&[25, 26, 27, 34, 44]), value: 0, possible_values: digits(&[5]), position: (4, 4) });
+    assert_eq!(children[29], Children { parents: runs(13, 20), siblings: cells(&[20, 30, 31, 32, 36]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (4, 6) });
+    assert_eq!(children[30], Children { parents: runs(9, 20), siblings: cells(&[11, 21, 29, 31, 32]), value: 0, possible_values: digits(&[3, 4, 5, 6, 7, 8, 9]), position: (4, 7) });
+    assert_eq!(children[31], Children { parents: runs(3, 20), siblings: cells(&[3, 12, 22, 29, 30, 32, 37]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (4, 8) });
+    assert_eq!(children[32], Children { parents: runs(4, 20), siblings: cells(&[4, 13, 23, 29, 30, 31, 38, 46, 54]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 8]), position: (4, 9) });
+    assert_eq!(children[33], Children { parents: runs(0, 25), siblings: cells(&[0, 8, 18, 27, 34, 35, 36, 43]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (5, 3) });
+    assert_eq!(children[34], Children { parents: runs(16, 25), siblings: cells(&[28, 33, 35, 36, 44]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (5, 4) });
+    assert_eq!(children[35], Children { parents: runs(19, 25), siblings: cells(&[33, 34, 36, 45, 51, 59]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (5, 5) });
+    assert_eq!(children[36], Children { parents: runs(13, 25), siblings: cells(&[20, 29, 33, 34, 35]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (5, 6) });
+    assert_eq!(children[37], Children { parents: runs(3, 26), siblings: cells(&[3, 12, 22, 31, 38, 39, 40]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (5, 8) });
+    assert_eq!(children[38], Children { parents: runs(4, 26), siblings: cells(&[4, 13, 23, 32, 37, 39, 40, 46, 54]), value: 0, possible_values: digits(&[4, 5, 6, 8]), position: (5, 9) });
+    assert_eq!(children[39], Children { parents: runs(21, 26), siblings: cells(&[37, 38, 40, 47, 55, 63, 70, 78]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (5, 10) });
+    assert_eq!(children[40], Children { parents: runs(22, 26), siblings: cells(&[37, 38, 39, 48]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (5, 11) });
+    assert_eq!(children[41], Children { parents: runs(23, 27), siblings: cells(&[42, 43, 44, 45, 49, 56]), value: 0, possible_values: digits(&[1, 2, 3]), position: (6, 1) });
+    assert_eq!(children[42], Children { parents: runs(24, 27), siblings: cells(&[41, 43, 44, 45, 50, 57, 65, 73, 81]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (6, 2) });
+    assert_eq!(children[43], Children { parents: runs(0, 27), siblings: cells(&[0, 8, 18, 27, 33, 41, 42, 44, 45]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (6, 3) });
+    assert_eq!(children[44], Children { parents: runs(16, 27), siblings: cells(&[28, 34, 41, 42, 43, 45]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (6, 4) });
+    assert_eq!(children[45], Children { parents: runs(19, 27), siblings: cells(&[35, 41, 42, 43, 44, 51, 59]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (6, 5) });
+    assert_eq!(children[46], Children { parents: runs(4, 30), siblings: cells(&[4, 13, 23, 32, 38, 47, 48, 54]), value: 0, possible_values: digits(&[8]), position: (6, 9) });
+    assert_eq!(children[47], Children { parents: runs(21, 30), siblings: cells(&[39, 46, 48, 55, 63, 70, 78]), value: 0, possible_values: digits(&[7, 8, 9]), position: (6, 10) });
+    assert_eq!(children[48], Children { parents: runs(22, 30), siblings: cells(&[40, 46, 47]), value: 0, possible_values: digits(&[7, 8, 9]), position: (6, 11) });
+    assert_eq!(children[49], Children { parents: runs(23, 31), siblings: cells(&[41, 50, 56]), value: 0, possible_values: digits(&[1, 2]), position: (7, 1) });
+    assert_eq!(children[50], Children { parents: runs(24, 31), siblings: cells(&[42, 49, 57, 65, 73, 81]), value: 0, possible_values: digits(&[1, 2]), position: (7, 2) });
+    assert_eq!(children[51], Children { parents: runs(19, 33), siblings: cells(&[35, 45, 52, 59]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (7, 5) });
+    assert_eq!(children[52], Children { parents: runs(28, 33), siblings: cells(&[51, 60, 68]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8]), position: (7, 6) });
+    assert_eq!(children[53], Children { parents: runs(29, 35), siblings: cells(&[54, 55, 62]), value: 0, possible_values: digits(&[6, 8, 9]), position: (7, 8) });
+    assert_eq!(children[54], Children { parents: runs(4, 35), siblings: cells(&[4, 13, 23, 32, 38, 46, 53, 55]), value: 0, possible_values: digits(&[6, 8]), position: (7, 9) });
+    assert_eq!(children[55], Children { parents: runs(21, 35), siblings: cells(&[39, 47, 53, 54, 63, 70, 78]), value: 0, possible_values: digits(&[6, 8, 9]), position: (7, 10) });
+    assert_eq!(children[56], Children { parents: runs(23, 37), siblings: cells(&[41, 49, 57]), value: 0, possible_values: digits(&[1, 2, 3]), position: (8, 1) });
+    assert_eq!(children[57], Children { parents: runs(24, 37), siblings: cells(&[42, 50, 56, 65, 73, 81]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (8, 2) });
+    assert_eq!(children[58], Children { parents: runs(32, 39), siblings: cells(&[59, 60, 61, 62, 67]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (8, 4) });
+    assert_eq!(children[59], Children { parents: runs(19, 39), siblings: cells(&[35, 45, 51, 58, 60, 61, 62]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (8, 5) });
+    assert_eq!(children[60], Children { parents: runs(28, 39), siblings: cells(&[52, 58, 59, 61, 62, 68]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (8, 6) });
+    assert_eq!(children[61], Children { parents: runs(34, 39), siblings: cells(&[58, 59, 60, 62, 69, 75, 85]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (8, 7) });
+    assert_eq!(children[62], Children { parents: runs(29, 39), siblings: cells(&[53, 58, 59, 60, 61]), value: 0, possible_values: digits(&[5]), position: (8, 8) });
+    assert_eq!(children[63], Children { parents: runs(21, 40), siblings: cells(&[39, 47, 55, 64, 70, 78]), value: 0, possible_values: digits(&[7, 9]), position: (8, 10) });
+    assert_eq!(children[64], Children { parents: runs(36, 40), siblings: cells(&[63, 71, 79]), value: 0, possible_values: digits(&[9]), position: (8, 11) });
+    assert_eq!(children[65], Children { parents: runs(24, 42), siblings: cells(&[42, 50, 57, 66, 67, 73, 81]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (9, 2) });
+    assert_eq!(children[66], Children { parents: runs(38, 42), siblings: cells(&[65, 67, 74, 82, 88, 97, 107, 116]), value: 0, possible_values: digits(&[2, 4, 5, 6, 7]), position: (9, 3) });
+    assert_eq!(children[67], Children { parents: runs(32, 42), siblings: cells(&[58, 65, 66]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (9, 4) });
+    assert_eq!(children[68], Children { parents: runs(28, 43), siblings: cells(&[52, 60, 69]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6, 7, 8]), position: (9, 6) });
+    assert_eq!(children[69], Children { parents: runs(34, 43), siblings: cells(&[61, 68, 75, 85]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6, 7]), position: (9, 7) });
+    assert_eq!(children[70], Children { parents: runs(21, 46), siblings: cells(&[39, 47, 55, 63, 71, 78]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (9, 10) });
+    assert_eq!(children[71], Children { parents: runs(36, 46), siblings: cells(&[64, 70, 79]), value: 0, possible_values: digits(&[6, 8, 9]), position: (9, 11) });
+    assert_eq!(children[72], Children { parents: runs(41, 47), siblings: cells(&[73, 74, 80]), value: 0, possible_values: digits(&[1, 2, 3]), position: (10, 1) });
+    assert_eq!(children[73], Children { parents: runs(24, 47), siblings: cells(&[42, 50, 57, 65, 72, 74, 81]), value: 0, possible_values: digits(&[1, 2, 3]), position: (10, 2) });
+    assert_eq!(children[74], Children { parents: runs(38, 47), siblings: cells(&[66, 72, 73, 82, 88, 97, 107, 116]), value: 0, possible_values: digits(&[2]), position: (10, 3) });
+    assert_eq!(children[75], Children { parents: runs(34, 50), siblings: cells(&[61, 69, 76, 77, 78, 79, 85]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (10, 7) });
+    assert_eq!(children[76], Children { parents: runs(44, 50), siblings: cells(&[75, 77, 78, 79, 86, 92]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (10, 8) });
+    assert_eq!(children[77], Children { parents: runs(45, 50), siblings: cells(&[75, 76, 78, 79, 87, 93, 102, 112, 120]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (10, 9) });
+    assert_eq!(children[78], Children { parents: runs(21, 50), siblings: cells(&[39, 47, 55, 63, 70, 75, 76, 77, 79]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (10, 10) });
+    assert_eq!(children[79], Children { parents: runs(36, 50), siblings: cells(&[64, 71, 75, 76, 77, 78]), value: 0, possible_values: digits(&[6, 8, 9]), position: (10, 11) });
+    assert_eq!(children[80], Children { parents: runs(41, 51), siblings: cells(&[72, 81, 82, 83]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (11, 1) });
+    assert_eq!(children[81], Children { parents: runs(24, 51), siblings: cells(&[42, 50, 57, 65, 73, 80, 82, 83]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (11, 2) });
+    assert_eq!(children[82], Children { parents: runs(38, 51), siblings: cells(&[66, 74, 80, 81, 83, 88, 97, 107, 116]), value: 0, possible_values: digits(&[2, 4]), position: (11, 3) });
+    assert_eq!(children[83], Children { parents: runs(48, 51), siblings: cells(&[80, 81, 82, 89, 98, 108, 117]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (11, 4) });
+    assert_eq!(children[84], Children { parents: runs(49, 53), siblings: cells(&[85, 86, 87, 91, 100]), value: 0, possible_values: digits(&[2, 3, 5]), position: (11, 6) });
+    assert_eq!(children[85], Children { parents: runs(34, 53), siblings: cells(&[61, 69, 75, 84, 86, 87]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (11, 7) });
+    assert_eq!(children[86], Children { parents: runs(44, 53), siblings: cells(&[76, 84, 85, 87, 92]), value: 0, possible_values: digits(&[5]), position: (11, 8) });
+    assert_eq!(children[87], Children { parents: runs(45, 53), siblings: cells(&[77, 84, 85, 86, 93, 102, 112, 120]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (11, 9) });
+    assert_eq!(children[88], Children { parents: runs(38, 57), siblings: cells(&[66, 74, 82, 89, 90, 91, 97, 107, 116]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (12, 3) });
+    assert_eq!(children[89], Children { parents: runs(48, 57), siblings: cells(&[83, 88, 90, 91, 98, 108, 117]), value: 0, possible_values: digits(&[5, 7]), position: (12, 4) });
+    assert_eq!(children[90], Children { parents: runs(52, 57), siblings: cells(&[88, 89, 91, 99, 109]), value: 0, possible_values: digits(&[5, 7]), position: (12, 5) });
+    assert_eq!(children[91], Children { parents: runs(49, 57), siblings: cells(&[84, 88, 89, 90, 100]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (12, 6) });
+    assert_eq!(children[92], Children { parents: runs(44, 59), siblings: cells(&[76, 86, 93, 94, 95]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (12, 8) });
+    assert_eq!(children[93], Children { parents: runs(45, 59), siblings: cells(&[77, 87, 92, 94, 95, 102, 112, 120]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (12, 9) });
+    assert_eq!(children[94], Children { parents: runs(54, 59), siblings: cells(&[92, 93, 95, 103, 113]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (12, 10) });
+    assert_eq!(children[95], Children { parents: runs(55, 59), siblings: cells(&[92, 93, 94, 104]), value: 0, possible_values: digits(&[1, 3]), position: (12, 11) });
+    assert_eq!(children[96], Children { parents: runs(56, 61), siblings: cells(&[97, 98, 99, 100, 101, 106, 115]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (13, 2) });
+    assert_eq!(children[97], Children { parents: runs(38, 61), siblings: cells(&[66, 74, 82, 88, 96, 98, 99, 100, 101, 107, 116]), value: 0, possible_values: digits(&[2, 4, 5, 6, 7, 8, 9]), position: (13, 3) });
+    assert_eq!(children[98], Children { parents: runs(48, 61), siblings: cells(&[83, 89, 96, 97, 99, 100, 101, 108, 117]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (13, 4) });
+    assert_eq!(children[99], Children { parents: runs(52, 61), siblings: cells(&[90, 96, 97, 98, 100, 101, 109]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (13, 5) });
+    assert_eq!(children[100], Children { parents: runs(49, 61), siblings: cells(&[84, 91, 96, 97, 98, 99, 101]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8, 9]), position: (13, 6) });
+    assert_eq!(children[101], Children { parents: runs(58, 61), siblings: cells(&[96, 97, 98, 99, 100, 110, 118]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (13, 7) });
+    assert_eq!(children[102], Children { parents: runs(45, 63), siblings: cells(&[77, 87, 93, 103, 104, 112, 120]), value: 0, possible_values: digits(&[1, 2, 4]), position: (13, 9) });
+    assert_eq!(children[103], Children { parents: runs(54, 63), siblings: cells(&[94, 102, 104, 113]), value: 0, possible_values: digits(&[1, 2, 4]), position: (13, 10) });
+    assert_eq!(children[104], Children { parents: runs(55, 63), siblings: cells(&[95, 102, 103]), value: 0, possible_values: digits(&[1]), position: (13, 11) });
+    assert_eq!(children[105], Children { parents: runs(60, 64), siblings: cells(&[106, 107, 108, 109, 114]), value: 0, possible_values: digits(&[1, 2, 4]), position: (14, 1) });
+    assert_eq!(children[106], Children { parents: runs(56, 64), siblings: cells(&[96, 105, 107, 108, 109, 115]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (14, 2) });
+    assert_eq!(children[107], Children { parents: runs(38, 64), siblings: cells(&[66, 74, 82, 88, 97, 105, 106, 108, 109, 116]), value: 0, possible_values: digits(&[2, 4, 6]), position: (14, 3) });
+    assert_eq!(children[108], Children { parents: runs(48, 64), siblings: cells(&[83, 89, 98, 105, 106, 107, 109, 117]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (14, 4) });
+    assert_eq!(children[109], Children { parents: runs(52, 64), siblings: cells(&[90, 99, 105, 106, 107, 108]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (14, 5) });
+    assert_eq!(children[110], Children { parents: runs(58, 65), siblings: cells(&[101, 111, 112, 113, 118]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (14, 7) });
+    assert_eq!(children[111], Children { parents: runs(62, 65), siblings: cells(&[110, 112, 113, 119]), value: 0, possible_values: digits(&[8, 9]), position: (14, 8) });
+    assert_eq!(children[112], Children { parents: runs(45, 65), siblings: cells(&[77, 87, 93, 102, 110, 111, 113, 120]), value: 0, possible_values: digits(&[3, 4, 5, 6]), position: (14, 9) });
+    assert_eq!(children[113], Children { parents: runs(54, 65), siblings: cells(&[94, 103, 110, 111, 112]), value: 0, possible_values: digits(&[3, 4, 5]), position: (14, 10) });
+    assert_eq!(children[114], Children { parents: runs(60, 66), siblings: cells(&[105, 115, 116, 117]), value: 0, possible_values: digits(&[1, 2, 4, 5]), position: (15, 1) });
+    assert_eq!(children[115], Children { parents: runs(56, 66), siblings: cells(&[96, 106, 114, 116, 117]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (15, 2) });
+    assert_eq!(children[116], Children { parents: runs(38, 66), siblings: cells(&[66, 74, 82, 88, 97, 107, 114, 115, 117]), value: 0, possible_values: digits(&[2, 4, 5, 6, 7, 8, 9]), position: (15, 3) });
+    assert_eq!(children[117], Children { parents: runs(48, 66), siblings: cells(&[83, 89, 98, 108, 114, 115, 116]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (15, 4) });
+    assert_eq!(children[118], Children { parents: runs(58, 67), siblings: cells(&[101, 110, 119, 120]), value: 0, possible_values: digits(&[5, 6, 7, 8, 9]), position: (15, 7) });
+    assert_eq!(children[119], Children { parents: runs(62, 67), siblings: cells(&[111, 118, 120]), value: 0, possible_values: digits(&[8, 9]), position: (15, 8) });
+    assert_eq!(children[120], Children { parents: runs(45, 67), siblings: cells(&[77, 87, 93, 102, 112, 118, 119]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6]), position: (15, 9) });
   }
 
   #[test]
   fn test_puzzle_setup_with_input_file_5() {
-    let mut parents_and_children: (Vec<Parents>, Vec<Children>) = (Vec::new(), Vec::new());
-    let puzzle_file = "puzzles\\Kakuro_input5.txt".to_string();
+    let puzzle_file = puzzle_path("Kakuro_input5.txt");
 
-    puzzle_setup(&mut parents_and_children, puzzle_file);
+    let puzzle = Puzzle::load(&puzzle_file).unwrap();
 
-    let parents = parents_and_children.0;
-    let children = parents_and_children.1;
+    let parents = puzzle.parents;
+    let children = puzzle.children;
 
     assert_eq!(parents.len(), 106);
     assert_eq!(children.len(), 184);
 
-    assert_eq!(parents[0], Parents { children: vec![0, 8, 20, 30, 39], sum: 15, value_size: "15-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 5]] });
-    assert_eq!(parents[1], Parents { children: vec![1, 9, 21], sum: 8, value_size: "8-3".to_string(), combinations: vec![vec![1, 2, 5], vec![1, 3, 4]] });
-    assert_eq!(parents[2], Parents { children: vec![2, 11], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[3], Parents { children: vec![3, 12], sum: 9, value_size: "9-2".to_string(), combinations: vec![vec![1, 8], vec![2, 7], vec![3, 6], vec![4, 5]] });
-    assert_eq!(parents[4], Parents { children: vec![4, 14, 25, 35], sum: 29, value_size: "29-4".to_string(), combinations: vec![vec![5, 7, 8, 9]] });
-    assert_eq!(parents[5], Parents { children: vec![5, 15], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[6], Parents { children: vec![6, 16, 26], sum: 11, value_size: "11-3".to_string(), combinations: vec![vec![1, 2, 8], vec![1, 3, 7], vec![1, 4, 6], vec![2, 3, 6], vec![2, 4, 5]] });
-    assert_eq!(parents[7], Parents { children: vec![7, 19, 29], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[8], Parents { children: vec![0, 1], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[9], Parents { children: vec![10, 22], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[10], Parents { children: vec![2, 3], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[11], Parents { children: vec![13, 23, 33, 44, 52], sum: 25, value_size: "25-5".to_string(), combinations: vec![vec![1, 2, 5, 8, 9], vec![1, 2, 6, 7, 9], vec![1, 3, 4, 8, 9], vec![1, 3, 5, 7, 9], vec![1, 3, 6, 7, 8], vec![1, 4, 5, 6, 9], vec![1, 4, 5, 7, 8], vec![2, 3, 4, 7, 9], vec![2, 3, 5, 6, 9], vec![2, 3, 5, 7, 8], vec![2, 4, 5, 6, 8], vec![3, 4, 5, 6, 7]] });
-    assert_eq!(parents[12], Parents { children: vec![4, 5, 6], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[13], Parents { children: vec![17, 27, 37, 48, 56, 65, 76], sum: 28, value_size: "28-7".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 7]] });
-    assert_eq!(parents[14], Parents { children: vec![18, 28, 38], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[15], Parents { children: vec![7, 8, 9, 10, 11, 12, 13], sum: 29, value_size: "29-7".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 8]] });
-    assert_eq!(parents[16], Parents { children: vec![24, 34, 45, 53], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[17], Parents { children: vec![14, 15, 16, 17, 18], sum: 34, value_size: "34-5".to_string(), combinations: vec![vec![4, 6, 7, 8, 9]] });
-    assert_eq!(parents[18], Parents { children: vec![19, 20, 21, 22], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[19], Parents { children: vec![31, 42, 51, 61], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[20], Parents { children: vec![32, 43], sum: 8, value_size: "8-2".to_string(), combinations: vec![vec![1, 7], vec![2, 6], vec![3, 5]] });
-    assert_eq!(parents[21], Parents { children: vec![23, 24, 25], sum: 19, value_size: "19-3".to_string(), combinations: vec![vec![2, 8, 9], vec![3, 7, 9], vec![4, 6, 9], vec![4, 7, 8], vec![5, 6, 8]] });
-    assert_eq!(parents[22], Parents { children: vec![36, 46], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[23], Parents { children: vec![26, 27, 28], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[24], Parents { children: vec![29, 30], sum: 12, value_size: "12-2".to_string(), combinations: vec![vec![3, 9], vec![4, 8], vec![5, 7]] });
-    assert_eq!(parents[25], Parents { children: vec![40, 49], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[26], Parents { children: vec![41, 50, 60, 70, 80, 89, 98, 109, 119], sum: 45, value_size: "45-9".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[27], Parents { children: vec![31, 32, 33, 34, 35, 36], sum: 21, value_size: "21-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6]] });
-    assert_eq!(parents[28], Parents { children: vec![47, 55], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[29], Parents { children: vec![37, 38], sum: 10, value_size: "10-2".to_string(), combinations: vec![vec![1, 9], vec![2, 8], vec![3, 7], vec![4, 6]] });
-    assert_eq!(parents[30], Parents { children: vec![39, 40, 41, 42, 43, 44, 45], sum: 42, value_size: "42-7".to_string(), combinations: vec![vec![3, 4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[31], Parents { children: vec![54, 63], sum: 7, value_size: "7-2".to_string(), combinations: vec![vec![1, 6], vec![2, 5], vec![3, 4]] });
-    assert_eq!(parents[32], Parents { children: vec![46, 47, 48], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[33], Parents { children: vec![57, 66], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[34], Parents { children: vec![58, 67, 77], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[35], Parents { children: vec![59, 68, 78, 87], sum: 29, value_size: "29-4".to_string(), combinations: vec![vec![5, 7, 8, 9]] });
-    assert_eq!(parents[36], Parents { children: vec![49, 50, 51], sum: 17, value_size: "17-3".to_string(), combinations: vec![vec![1, 7, 9], vec![2, 6, 9], vec![2, 7, 8], vec![3, 5, 9], vec![3, 6, 8], vec![4, 5, 8], vec![4, 6, 7]] });
-    assert_eq!(parents[37], Parents { children: vec![62, 71, 82, 91, 99, 110], sum: 23, value_size: "23-6".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 8], vec![1, 2, 3, 4, 6, 7]] });
-    assert_eq!(parents[38], Parents { children: vec![52, 53, 54], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[39], Parents { children: vec![64, 74, 85, 94, 103, 113, 123, 133, 142], sum: 45, value_size: "45-9".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[40], Parents { children: vec![55, 56, 57], sum: 6, value_size: "6-3".to_string(), combinations: vec![vec![1, 2, 3]] });
-    assert_eq!(parents[41], Parents { children: vec![58, 59], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[42], Parents { children: vec![69, 79, 88, 97, 108], sum: 23, value_size: "23-5".to_string(), combinations: vec![vec![1, 2, 3, 8, 9], vec![1, 2, 4, 7, 9], vec![1, 2, 5, 6, 9], vec![1, 2, 5, 7, 8], vec![1, 3, 4, 6, 9], vec![1, 3, 4, 7, 8], vec![1, 3, 5, 6, 8], vec![1, 4, 5, 6, 7], vec![2, 3, 4, 5, 9], vec![2, 3, 4, 6, 8], vec![2, 3, 5, 6, 7]] });
-    assert_eq!(parents[43], Parents { children: vec![60, 61, 62], sum: 8, value_size: "8-3".to_string(), combinations: vec![vec![1, 2, 5], vec![1, 3, 4]] });
-    assert_eq!(parents[44], Parents { children: vec![72, 83], sum: 13, value_size: "13-2".to_string(), combinations: vec![vec![4, 9], vec![5, 8], vec![6, 7]] });
-    assert_eq!(parents[45], Parents { children: vec![73, 84, 92, 101, 112, 121], sum: 38, value_size: "38-6".to_string(), combinations: vec![vec![3, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[46], Parents { children: vec![63, 64], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[47], Parents { children: vec![75, 86, 95, 104, 114], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[48], Parents { children: vec![65, 66], sum: 6, value_size: "6-2".to_string(), combinations: vec![vec![1, 5], vec![2, 4]] });
-    assert_eq!(parents[49], Parents { children: vec![67, 68, 69, 70], sum: 30, value_size: "30-4".to_string(), combinations: vec![vec![6, 7, 8, 9]] });
-    assert_eq!(parents[50], Parents { children: vec![81, 90], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[51], Parents { children: vec![71, 72, 73], sum: 13, value_size: "13-3".to_string(), combinations: vec![vec![1, 3, 9], vec![1, 4, 8], vec![1, 5, 7], vec![2, 3, 8], vec![2, 4, 7], vec![2, 5, 6], vec![3, 4, 6]] });
-    assert_eq!(parents[52], Parents { children: vec![74, 75, 76], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[53], Parents { children: vec![77, 78, 79, 80, 81, 82, 83, 84], sum: 36, value_size: "36-8".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 7, 8]] });
-    assert_eq!(parents[54], Parents { children: vec![93, 102], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[55], Parents { children: vec![85, 86], sum: 11, value_size: "11-2".to_string(), combinations: vec![vec![2, 9], vec![3, 8], vec![4, 7], vec![5, 6]] });
-    assert_eq!(parents[56], Parents { children: vec![96, 105, 115, 124], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[57], Parents { children: vec![87, 88, 89, 90, 91], sum: 15, value_size: "15-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 5]] });
-    assert_eq!(parents[58], Parents { children: vec![100, 111], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[59], Parents { children: vec![92, 93, 94, 95, 96], sum: 15, value_size: "15-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 5]] });
-    assert_eq!(parents[60], Parents { children: vec![106, 116, 125], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[61], Parents { children: vec![107, 118, 127, 135, 146, 156, 166], sum: 32, value_size: "32-7".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 8, 9], vec![1, 2, 3, 4, 6, 7, 9], vec![1, 2, 3, 5, 6, 7, 8]] });
-    assert_eq!(parents[62], Parents { children: vec![97, 98], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[63], Parents { children: vec![99, 100, 101, 102, 103, 104, 105, 106], sum: 44, value_size: "44-8".to_string(), combinations: vec![vec![2, 3, 4, 5, 6, 7, 8, 9]] });
-    assert_eq!(parents[64], Parents { children: vec![117, 126], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[65], Parents { children: vec![107, 108, 109], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[66], Parents { children: vec![120, 129], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[67], Parents { children: vec![110, 111, 112], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[68], Parents { children: vec![122, 132, 141, 152], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[69], Parents { children: vec![113, 114, 115, 116], sum: 10, value_size: "10-4".to_string(), combinations: vec![vec![1, 2, 3, 4]] });
-    assert_eq!(parents[70], Parents { children: vec![117, 118], sum: 3, value_size: "3-2".to_string(), combinations: vec![vec![1, 2]] });
-    assert_eq!(parents[71], Parents { children: vec![128, 136], sum: 13, value_size: "13-2".to_string(), combinations: vec![vec![4, 9], vec![5, 8], vec![6, 7]] });
-    assert_eq!(parents[72], Parents { children: vec![119, 120], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[73], Parents { children: vec![130, 138, 149, 159], sum: 12, value_size: "12-4".to_string(), combinations: vec![vec![1, 2, 3, 6], vec![1, 2, 4, 5]] });
-    assert_eq!(parents[74], Parents { children: vec![131, 139, 150, 160, 170], sum: 15, value_size: "15-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 5]] });
-    assert_eq!(parents[75], Parents { children: vec![121, 122, 123], sum: 12, value_size: "12-3".to_string(), combinations: vec![vec![1, 2, 9], vec![1, 3, 8], vec![1, 4, 7], vec![1, 5, 6], vec![2, 3, 7], vec![2, 4, 6], vec![3, 4, 5]] });
-    assert_eq!(parents[76], Parents { children: vec![134, 143], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[77], Parents { children: vec![124, 125], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[78], Parents { children: vec![126, 127, 128], sum: 8, value_size: "8-3".to_string(), combinations: vec![vec![1, 2, 5], vec![1, 3, 4]] });
-    assert_eq!(parents[79], Parents { children: vec![137, 147], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[80], Parents { children: vec![129, 130, 131], sum: 10, value_size: "10-3".to_string(), combinations: vec![vec![1, 2, 7], vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]] });
-    assert_eq!(parents[81], Parents { children: vec![140, 151], sum: 12, value_size: "12-2".to_string(), combinations: vec![vec![3, 9], vec![4, 8], vec![5, 7]] });
-    assert_eq!(parents[82], Parents { children: vec![132, 133, 134], sum: 23, value_size: "23-3".to_string(), combinations: vec![vec![6, 8, 9]] });
-    assert_eq!(parents[83], Parents { children: vec![144, 153, 163, 175, 183], sum: 20, value_size: "20-5".to_string(), combinations: vec![vec![1, 2, 3, 5, 9], vec![1, 2, 3, 6, 8], vec![1, 2, 4, 5, 8], vec![1, 2, 4, 6, 7], vec![1, 3, 4, 5, 7], vec![2, 3, 4, 5, 6]] });
-    assert_eq!(parents[84], Parents { children: vec![145, 155, 165], sum: 15, value_size: "15-3".to_string(), combinations: vec![vec![1, 5, 9], vec![1, 6, 8], vec![2, 4, 9], vec![2, 5, 8], vec![2, 6, 7], vec![3, 4, 8], vec![3, 5, 7], vec![4, 5, 6]] });
-    assert_eq!(parents[85], Parents { children: vec![135, 136, 137], sum: 24, value_size: "24-3".to_string(), combinations: vec![vec![7, 8, 9]] });
-    assert_eq!(parents[86], Parents { children: vec![148, 158, 169, 179], sum: 11, value_size: "11-4".to_string(), combinations: vec![vec![1, 2, 3, 5]] });
-    assert_eq!(parents[87], Parents { children: vec![138, 139, 140, 141, 142, 143, 144], sum: 28, value_size: "28-7".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 7]] });
-    assert_eq!(parents[88], Parents { children: vec![154, 164, 176], sum: 6, value_size: "6-3".to_string(), combinations: vec![vec![1, 2, 3]] });
-    assert_eq!(parents[89], Parents { children: vec![145, 146], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[90], Parents { children: vec![157, 167, 177], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[91], Parents { children: vec![147, 148, 149, 150, 151, 152], sum: 28, value_size: "28-6".to_string(), combinations: vec![vec![1, 2, 3, 5, 8, 9], vec![1, 2, 3, 6, 7, 9], vec![1, 2, 4, 5, 7, 9], vec![1, 2, 4, 6, 7, 8], vec![1, 3, 4, 5, 6, 9], vec![1, 3, 4, 5, 7, 8], vec![2, 3, 4, 5, 6, 8]] });
-    assert_eq!(parents[92], Parents { children: vec![161, 173], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[93], Parents { children: vec![162, 174, 182], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[94], Parents { children: vec![153, 154], sum: 7, value_size: "7-2".to_string(), combinations: vec![vec![1, 6], vec![2, 5], vec![3, 4]] });
-    assert_eq!(parents[95], Parents { children: vec![155, 156, 157], sum: 8, value_size: "8-3".to_string(), combinations: vec![vec![1, 2, 5], vec![1, 3, 4]] });
-    assert_eq!(parents[96], Parents { children: vec![168, 178], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
-    assert_eq!(parents[97], Parents { children: vec![158, 159, 160], sum: 7, value_size: "7-3".to_string(), combinations: vec![vec![1, 2, 4]] });
-    assert_eq!(parents[98], Parents { children: vec![171, 180], sum: 16, value_size: "16-2".to_string(), combinations: vec![vec![7, 9]] });
-    assert_eq!(parents[99], Parents { children: vec![172, 181], sum: 9, value_size: "9-2".to_string(), combinations: vec![vec![1, 8], vec![2, 7], vec![3, 6], vec![4, 5]] });
-    assert_eq!(parents[100], Parents { children: vec![161, 162, 163, 164], sum: 10, value_size: "10-4".to_string(), combinations: vec![vec![1, 2, 3, 4]] });
-    assert_eq!(parents[101], Parents { children: vec![165, 166, 167, 168, 169], sum: 16, value_size: "16-5".to_string(), combinations: vec![vec![1, 2, 3, 4, 6]] });
-    assert_eq!(parents[102], Parents { children: vec![170, 171, 172, 173, 174, 175, 176], sum: 28, value_size: "28-7".to_string(), combinations: vec![vec![1, 2, 3, 4, 5, 6, 7]] });
-    assert_eq!(parents[103], Parents { children: vec![177, 178, 179], sum: 9, value_size: "9-3".to_string(), combinations: vec![vec![1, 2, 6], vec![1, 3, 5], vec![2, 3, 4]] });
-    assert_eq!(parents[104], Parents { children: vec![180, 181], sum: 17, value_size: "17-2".to_string(), combinations: vec![vec![8, 9]] });
-    assert_eq!(parents[105], Parents { children: vec![182, 183], sum: 4, value_size: "4-2".to_string(), combinations: vec![vec![1, 3]] });
+    assert_eq!(parents[0], Parents { children: cells(&[0, 8, 20, 30, 39]), sum: 15, value_size: "15-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5])], direction: Direction::Down, head: (0, 2) });
+    assert_eq!(parents[1], Parents { children: cells(&[1, 9, 21]), sum: 8, value_size: "8-3".to_string(), combinations: vec![digits(&[1, 2, 5]), digits(&[1, 3, 4])], direction: Direction::Down, head: (0, 3) });
+    assert_eq!(parents[2], Parents { children: cells(&[2, 11]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (0, 5) });
+    assert_eq!(parents[3], Parents { children: cells(&[3, 12]), sum: 9, value_size: "9-2".to_string(), combinations: vec![digits(&[1, 8]), digits(&[2, 7]), digits(&[3, 6]), digits(&[4, 5])], direction: Direction::Down, head: (0, 6) });
+    assert_eq!(parents[4], Parents { children: cells(&[4, 14, 25, 35]), sum: 29, value_size: "29-4".to_string(), combinations: vec![digits(&[5, 7, 8, 9])], direction: Direction::Down, head: (0, 9) });
+    assert_eq!(parents[5], Parents { children: cells(&[5, 15]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Down, head: (0, 10) });
+    assert_eq!(parents[6], Parents { children: cells(&[6, 16, 26]), sum: 11, value_size: "11-3".to_string(), combinations: vec![digits(&[1, 2, 8]), digits(&[1, 3, 7]), digits(&[1, 4, 6]), digits(&[2, 3, 6]), digits(&[2, 4, 5])], direction: Direction::Down, head: (0, 11) });
+    assert_eq!(parents[7], Parents { children: cells(&[7, 19, 29]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Down, head: (1, 1) });
+    assert_eq!(parents[8], Parents { children: cells(&[0, 1]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Across, head: (1, 1) });
+    assert_eq!(parents[9], Parents { children: cells(&[10, 22]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (1, 4) });
+    assert_eq!(parents[10], Parents { children: cells(&[2, 3]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Across, head: (1, 4) });
+    assert_eq!(parents[11], Parents { children: cells(&[13, 23, 33, 44, 52]), sum: 25, value_size: "25-5".to_string(), combinations: vec![digits(&[1, 2, 5, 8, 9]), digits(&[1, 2, 6, 7, 9]), digits(&[1, 3, 4, 8, 9]), digits(&[1, 3, 5, 7, 9]), digits(&[1, 3, 6, 7, 8]), digits(&[1, 4, 5, 6, 9]), digits(&[1, 4, 5, 7, 8]), digits(&[2, 3, 4, 7, 9]), digits(&[2, 3, 5, 6, 9]), digits(&[2, 3, 5, 7, 8]), digits(&[2, 4, 5, 6, 8]), digits(&[3, 4, 5, 6, 7])], direction: Direction::Down, head: (1, 7) });
+    assert_eq!(parents[12], Parents { children: cells(&[4, 5, 6]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (1, 8) });
+    assert_eq!(parents[13], Parents { children: cells(&[17, 27, 37, 48, 56, 65, 76]), sum: 28, value_size: "28-7".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 7])], direction: Direction::Down, head: (1, 12) });
+    assert_eq!(parents[14], Parents { children: cells(&[18, 28, 38]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Down, head: (1, 13) });
+    assert_eq!(parents[15], Parents { children: cells(&[7, 8, 9, 10, 11, 12, 13]), sum: 29, value_size: "29-7".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 8])], direction: Direction::Across, head: (2, 0) });
+    assert_eq!(parents[16], Parents { children: cells(&[24, 34, 45, 53]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Down, head: (2, 8) });
+    assert_eq!(parents[17], Parents { children: cells(&[14, 15, 16, 17, 18]), sum: 34, value_size: "34-5".to_string(), combinations: vec![digits(&[4, 6, 7, 8, 9])], direction: Direction::Across, head: (2, 8) });
+    assert_eq!(parents[18], Parents { children: cells(&[19, 20, 21, 22]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Across, head: (3, 0) });
+    assert_eq!(parents[19], Parents { children: cells(&[31, 42, 51, 61]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Down, head: (3, 5) });
+    assert_eq!(parents[20], Parents { children: cells(&[32, 43]), sum: 8, value_size: "8-2".to_string(), combinations: vec![digits(&[1, 7]), digits(&[2, 6]), digits(&[3, 5])], direction: Direction::Down, head: (3, 6) });
+    assert_eq!(parents[21], Parents { children: cells(&[23, 24, 25]), sum: 19, value_size: "19-3".to_string(), combinations: vec![digits(&[2, 8, 9]), digits(&[3, 7, 9]), digits(&[4, 6, 9]), digits(&[4, 7, 8]), digits(&[5, 6, 8])], direction: Direction::Across, head: (3, 6) });
+    assert_eq!(parents[22], Parents { children: cells(&[36, 46]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (3, 10) });
+    assert_eq!(parents[23], Parents { children: cells(&[26, 27, 28]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Across, head: (3, 10) });
+    assert_eq!(parents[24], Parents { children: cells(&[29, 30]), sum: 12, value_size: "12-2".to_string(), combinations: vec![digits(&[3, 9]), digits(&[4, 8]), digits(&[5, 7])], direction: Direction::Across, head: (4, 0) });
+    assert_eq!(parents[25], Parents { children: cells(&[40, 49]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Down, head: (4, 3) });
+    assert_eq!(parents[26], Parents { children: cells(&[41, 50, 60, 70, 80, 89, 98, 109, 119]), sum: 45, value_size: "45-9".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (4, 4) });
+    assert_eq!(parents[27], Parents { children: cells(&[31, 32, 33, 34, 35, 36]), sum: 21, value_size: "21-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6])], direction: Direction::Across, head: (4, 4) });
+    assert_eq!(parents[28], Parents { children: cells(&[47, 55]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (4, 11) });
+    assert_eq!(parents[29], Parents { children: cells(&[37, 38]), sum: 10, value_size: "10-2".to_string(), combinations: vec![digits(&[1, 9]), digits(&[2, 8]), digits(&[3, 7]), digits(&[4, 6])], direction: Direction::Across, head: (4, 11) });
+    assert_eq!(parents[30], Parents { children: cells(&[39, 40, 41, 42, 43, 44, 45]), sum: 42, value_size: "42-7".to_string(), combinations: vec![digits(&[3, 4, 5, 6, 7, 8, 9])], direction: Direction::Across, head: (5, 1) });
+    assert_eq!(parents[31], Parents { children: cells(&[54, 63]), sum: 7, value_size: "7-2".to_string(), combinations: vec![digits(&[1, 6]), digits(&[2, 5]), digits(&[3, 4])], direction: Direction::Down, head: (5, 9) });
+    assert_eq!(parents[32], Parents { children: cells(&[46, 47, 48]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (5, 9) });
+    assert_eq!(parents[33], Parents { children: cells(&[57, 66]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (5, 13) });
+    assert_eq!(parents[34], Parents { children: cells(&[58, 67, 77]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Down, head: (6, 1) });
+    assert_eq!(parents[35], Parents { children: cells(&[59, 68, 78, 87]), sum: 29, value_size: "29-4".to_string(), combinations: vec![digits(&[5, 7, 8, 9])], direction: Direction::Down, head: (6, 2) });
+    assert_eq!(parents[36], Parents { children: cells(&[49, 50, 51]), sum: 17, value_size: "17-3".to_string(), combinations: vec![digits(&[1, 7, 9]), digits(&[2, 6, 9]), digits(&[2, 7, 8]), digits(&[3, 5, 9]), digits(&[3, 6, 8]), digits(&[4, 5, 8]), digits(&[4, 6, 7])], direction: Direction::Across, head: (6, 2) });
+    assert_eq!(parents[37], Parents { children: cells(&[62, 71, 82, 91, 99, 110]), sum: 23, value_size: "23-6".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 8]), digits(&[1, 2, 3, 4, 6, 7])], direction: Direction::Down, head: (6, 6) });
+    assert_eq!(parents[38], Parents { children: cells(&[52, 53, 54]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (6, 6) });
+    assert_eq!(parents[39], Parents { children: cells(&[64, 74, 85, 94, 103, 113, 123, 133, 142]), sum: 45, value_size: "45-9".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (6, 10) });
+    assert_eq!(parents[40], Parents { children: cells(&[55, 56, 57]), sum: 6, value_size: "6-3".to_string(), combinations: vec![digits(&[1, 2, 3])], direction: Direction::Across, head: (6, 10) });
+    assert_eq!(parents[41], Parents { children: cells(&[58, 59]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Across, head: (7, 0) });
+    assert_eq!(parents[42], Parents { children: cells(&[69, 79, 88, 97, 108]), sum: 23, value_size: "23-5".to_string(), combinations: vec![digits(&[1, 2, 3, 8, 9]), digits(&[1, 2, 4, 7, 9]), digits(&[1, 2, 5, 6, 9]), digits(&[1, 2, 5, 7, 8]), digits(&[1, 3, 4, 6, 9]), digits(&[1, 3, 4, 7, 8]), digits(&[1, 3, 5, 6, 8]), digits(&[1, 4, 5, 6, 7]), digits(&[2, 3, 4, 5, 9]), digits(&[2, 3, 4, 6, 8]), digits(&[2, 3, 5, 6, 7])], direction: Direction::Down, head: (7, 3) });
+    assert_eq!(parents[43], Parents { children: cells(&[60, 61, 62]), sum: 8, value_size: "8-3".to_string(), combinations: vec![digits(&[1, 2, 5]), digits(&[1, 3, 4])], direction: Direction::Across, head: (7, 3) });
+    assert_eq!(parents[44], Parents { children: cells(&[72, 83]), sum: 13, value_size: "13-2".to_string(), combinations: vec![digits(&[4, 9]), digits(&[5, 8]), digits(&[6, 7])], direction: Direction::Down, head: (7, 7) });
+    assert_eq!(parents[45], Parents { children: cells(&[73, 84, 92, 101, 112, 121]), sum: 38, value_size: "38-6".to_string(), combinations: vec![digits(&[3, 5, 6, 7, 8, 9])], direction: Direction::Down, head: (7, 8) });
+    assert_eq!(parents[46], Parents { children: cells(&[63, 64]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Across, head: (7, 8) });
+    assert_eq!(parents[47], Parents { children: cells(&[75, 86, 95, 104, 114]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Down, head: (7, 11) });
+    assert_eq!(parents[48], Parents { children: cells(&[65, 66]), sum: 6, value_size: "6-2".to_string(), combinations: vec![digits(&[1, 5]), digits(&[2, 4])], direction: Direction::Across, head: (7, 11) });
+    assert_eq!(parents[49], Parents { children: cells(&[67, 68, 69, 70]), sum: 30, value_size: "30-4".to_string(), combinations: vec![digits(&[6, 7, 8, 9])], direction: Direction::Across, head: (8, 0) });
+    assert_eq!(parents[50], Parents { children: cells(&[81, 90]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (8, 5) });
+    assert_eq!(parents[51], Parents { children: cells(&[71, 72, 73]), sum: 13, value_size: "13-3".to_string(), combinations: vec![digits(&[1, 3, 9]), digits(&[1, 4, 8]), digits(&[1, 5, 7]), digits(&[2, 3, 8]), digits(&[2, 4, 7]), digits(&[2, 5, 6]), digits(&[3, 4, 6])], direction: Direction::Across, head: (8, 5) });
+    assert_eq!(parents[52], Parents { children: cells(&[74, 75, 76]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Across, head: (8, 9) });
+    assert_eq!(parents[53], Parents { children: cells(&[77, 78, 79, 80, 81, 82, 83, 84]), sum: 36, value_size: "36-8".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 7, 8])], direction: Direction::Across, head: (9, 0) });
+    assert_eq!(parents[54], Parents { children: cells(&[93, 102]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (9, 9) });
+    assert_eq!(parents[55], Parents { children: cells(&[85, 86]), sum: 11, value_size: "11-2".to_string(), combinations: vec![digits(&[2, 9]), digits(&[3, 8]), digits(&[4, 7]), digits(&[5, 6])], direction: Direction::Across, head: (9, 9) });
+    assert_eq!(parents[56], Parents { children: cells(&[96, 105, 115, 124]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Down, head: (9, 12) });
+    assert_eq!(parents[57], Parents { children: cells(&[87, 88, 89, 90, 91]), sum: 15, value_size: "15-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5])], direction: Direction::Across, head: (10, 1) });
+    assert_eq!(parents[58], Parents { children: cells(&[100, 111]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Down, head: (10, 7) });
+    assert_eq!(parents[59], Parents { children: cells(&[92, 93, 94, 95, 96]), sum: 15, value_size: "15-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5])], direction: Direction::Across, head: (10, 7) });
+    assert_eq!(parents[60], Parents { children: cells(&[106, 116, 125]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Down, head: (10, 13) });
+    assert_eq!(parents[61], Parents { children: cells(&[107, 118, 127, 135, 146, 156, 166]), sum: 32, value_size: "32-7".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 8, 9]), digits(&[1, 2, 3, 4, 6, 7, 9]), digits(&[1, 2, 3, 5, 6, 7, 8])], direction: Direction::Down, head: (11, 2) });
+    assert_eq!(parents[62], Parents { children: cells(&[97, 98]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Across, head: (11, 2) });
+    assert_eq!(parents[63], Parents { children: cells(&[99, 100, 101, 102, 103, 104, 105, 106]), sum: 44, value_size: "44-8".to_string(), combinations: vec![digits(&[2, 3, 4, 5, 6, 7, 8, 9])], direction: Direction::Across, head: (11, 5) });
+    assert_eq!(parents[64], Parents { children: cells(&[117, 126]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (12, 1) });
+    assert_eq!(parents[65], Parents { children: cells(&[107, 108, 109]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (12, 1) });
+    assert_eq!(parents[66], Parents { children: cells(&[120, 129]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Down, head: (12, 5) });
+    assert_eq!(parents[67], Parents { children: cells(&[110, 111, 112]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Across, head: (12, 5) });
+    assert_eq!(parents[68], Parents { children: cells(&[122, 132, 141, 152]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Down, head: (12, 9) });
+    assert_eq!(parents[69], Parents { children: cells(&[113, 114, 115, 116]), sum: 10, value_size: "10-4".to_string(), combinations: vec![digits(&[1, 2, 3, 4])], direction: Direction::Across, head: (12, 9) });
+    assert_eq!(parents[70], Parents { children: cells(&[117, 118]), sum: 3, value_size: "3-2".to_string(), combinations: vec![digits(&[1, 2])], direction: Direction::Across, head: (13, 0) });
+    assert_eq!(parents[71], Parents { children: cells(&[128, 136]), sum: 13, value_size: "13-2".to_string(), combinations: vec![digits(&[4, 9]), digits(&[5, 8]), digits(&[6, 7])], direction: Direction::Down, head: (13, 3) });
+    assert_eq!(parents[72], Parents { children: cells(&[119, 120]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Across, head: (13, 3) });
+    assert_eq!(parents[73], Parents { children: cells(&[130, 138, 149, 159]), sum: 12, value_size: "12-4".to_string(), combinations: vec![digits(&[1, 2, 3, 6]), digits(&[1, 2, 4, 5])], direction: Direction::Down, head: (13, 6) });
+    assert_eq!(parents[74], Parents { children: cells(&[131, 139, 150, 160, 170]), sum: 15, value_size: "15-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5])], direction: Direction::Down, head: (13, 7) });
+    assert_eq!(parents[75], Parents { children: cells(&[121, 122, 123]), sum: 12, value_size: "12-3".to_string(), combinations: vec![digits(&[1, 2, 9]), digits(&[1, 3, 8]), digits(&[1, 4, 7]), digits(&[1, 5, 6]), digits(&[2, 3, 7]), digits(&[2, 4, 6]), digits(&[3, 4, 5])], direction: Direction::Across, head: (13, 7) });
+    assert_eq!(parents[76], Parents { children: cells(&[134, 143]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Down, head: (13, 11) });
+    assert_eq!(parents[77], Parents { children: cells(&[124, 125]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Across, head: (13, 11) });
+    assert_eq!(parents[78], Parents { children: cells(&[126, 127, 128]), sum: 8, value_size: "8-3".to_string(), combinations: vec![digits(&[1, 2, 5]), digits(&[1, 3, 4])], direction: Direction::Across, head: (14, 0) });
+    assert_eq!(parents[79], Parents { children: cells(&[137, 147]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Down, head: (14, 4) });
+    assert_eq!(parents[80], Parents { children: cells(&[129, 130, 131]), sum: 10, value_size: "10-3".to_string(), combinations: vec![digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])], direction: Direction::Across, head: (14, 4) });
+    assert_eq!(parents[81], Parents { children: cells(&[140, 151]), sum: 12, value_size: "12-2".to_string(), combinations: vec![digits(&[3, 9]), digits(&[4, 8]), digits(&[5, 7])], direction: Direction::Down, head: (14, 8) });
+    assert_eq!(parents[82], Parents { children: cells(&[132, 133, 134]), sum: 23, value_size: "23-3".to_string(), combinations: vec![digits(&[6, 8, 9])], direction: Direction::Across, head: (14, 8) });
+    assert_eq!(parents[83], Parents { children: cells(&[144, 153, 163, 175, 183]), sum: 20, value_size: "20-5".to_string(), combinations: vec![digits(&[1, 2, 3, 5, 9]), digits(&[1, 2, 3, 6, 8]), digits(&[1, 2, 4, 5, 8]), digits(&[1, 2, 4, 6, 7]), digits(&[1, 3, 4, 5, 7]), digits(&[2, 3, 4, 5, 6])], direction: Direction::Down, head: (14, 12) });
+    assert_eq!(parents[84], Parents { children: cells(&[145, 155, 165]), sum: 15, value_size: "15-3".to_string(), combinations: vec![digits(&[1, 5, 9]), digits(&[1, 6, 8]), digits(&[2, 4, 9]), digits(&[2, 5, 8]), digits(&[2, 6, 7]), digits(&[3, 4, 8]), digits(&[3, 5, 7]), digits(&[4, 5, 6])], direction: Direction::Down, head: (15, 1) });
+    assert_eq!(parents[85], Parents { children: cells(&[135, 136, 137]), sum: 24, value_size: "24-3".to_string(), combinations: vec![digits(&[7, 8, 9])], direction: Direction::Across, head: (15, 1) });
+    assert_eq!(parents[86], Parents { children: cells(&[148, 158, 169, 179]), sum: 11, value_size: "11-4".to_string(), combinations: vec![digits(&[1, 2, 3, 5])], direction: Direction::Down, head: (15, 5) });
+    assert_eq!(parents[87], Parents { children: cells(&[138, 139, 140, 141, 142, 143, 144]), sum: 28, value_size: "28-7".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 7])], direction: Direction::Across, head: (15, 5) });
+    assert_eq!(parents[88], Parents { children: cells(&[154, 164, 176]), sum: 6, value_size: "6-3".to_string(), combinations: vec![digits(&[1, 2, 3])], direction: Direction::Down, head: (15, 13) });
+    assert_eq!(parents[89], Parents { children: cells(&[145, 146]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Across, head: (16, 0) });
+    assert_eq!(parents[90], Parents { children: cells(&[157, 167, 177]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Down, head: (16, 3) });
+    assert_eq!(parents[91], Parents { children: cells(&[147, 148, 149, 150, 151, 152]), sum: 28, value_size: "28-6".to_string(), combinations: vec![digits(&[1, 2, 3, 5, 8, 9]), digits(&[1, 2, 3, 6, 7, 9]), digits(&[1, 2, 4, 5, 7, 9]), digits(&[1, 2, 4, 6, 7, 8]), digits(&[1, 3, 4, 5, 6, 9]), digits(&[1, 3, 4, 5, 7, 8]), digits(&[2, 3, 4, 5, 6, 8])], direction: Direction::Across, head: (16, 3) });
+    assert_eq!(parents[92], Parents { children: cells(&[161, 173]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (16, 10) });
+    assert_eq!(parents[93], Parents { children: cells(&[162, 174, 182]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Down, head: (16, 11) });
+    assert_eq!(parents[94], Parents { children: cells(&[153, 154]), sum: 7, value_size: "7-2".to_string(), combinations: vec![digits(&[1, 6]), digits(&[2, 5]), digits(&[3, 4])], direction: Direction::Across, head: (16, 11) });
+    assert_eq!(parents[95], Parents { children: cells(&[155, 156, 157]), sum: 8, value_size: "8-3".to_string(), combinations: vec![digits(&[1, 2, 5]), digits(&[1, 3, 4])], direction: Direction::Across, head: (17, 0) });
+    assert_eq!(parents[96], Parents { children: cells(&[168, 178]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Down, head: (17, 4) });
+    assert_eq!(parents[97], Parents { children: cells(&[158, 159, 160]), sum: 7, value_size: "7-3".to_string(), combinations: vec![digits(&[1, 2, 4])], direction: Direction::Across, head: (17, 4) });
+    assert_eq!(parents[98], Parents { children: cells(&[171, 180]), sum: 16, value_size: "16-2".to_string(), combinations: vec![digits(&[7, 9])], direction: Direction::Down, head: (17, 8) });
+    assert_eq!(parents[99], Parents { children: cells(&[172, 181]), sum: 9, value_size: "9-2".to_string(), combinations: vec![digits(&[1, 8]), digits(&[2, 7]), digits(&[3, 6]), digits(&[4, 5])], direction: Direction::Down, head: (17, 9) });
+    assert_eq!(parents[100], Parents { children: cells(&[161, 162, 163, 164]), sum: 10, value_size: "10-4".to_string(), combinations: vec![digits(&[1, 2, 3, 4])], direction: Direction::Across, head: (17, 9) });
+    assert_eq!(parents[101], Parents { children: cells(&[165, 166, 167, 168, 169]), sum: 16, value_size: "16-5".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 6])], direction: Direction::Across, head: (18, 0) });
+    assert_eq!(parents[102], Parents { children: cells(&[170, 171, 172, 173, 174, 175, 176]), sum: 28, value_size: "28-7".to_string(), combinations: vec![digits(&[1, 2, 3, 4, 5, 6, 7])], direction: Direction::Across, head: (18, 6) });
+    assert_eq!(parents[103], Parents { children: cells(&[177, 178, 179]), sum: 9, value_size: "9-3".to_string(), combinations: vec![digits(&[1, 2, 6]), digits(&[1, 3, 5]), digits(&[2, 3, 4])], direction: Direction::Across, head: (19, 2) });
+    assert_eq!(parents[104], Parents { children: cells(&[180, 181]), sum: 17, value_size: "17-2".to_string(), combinations: vec![digits(&[8, 9])], direction: Direction::Across, head: (19, 7) });
+    assert_eq!(parents[105], Parents { children: cells(&[182, 183]), sum: 4, value_size: "4-2".to_string(), combinations: vec![digits(&[1, 3])], direction: Direction::Across, head: (19, 10) });
     
-    assert_eq!(children[0], Children { parents: (0, 8), siblings: vec![1, 8, 20, 30, 39], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[1], Children { parents: (1, 8), siblings: vec![0, 9, 21], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[2], Children { parents: (2, 10), siblings: vec![3, 11], value: 0, possible_values: vec![1] });
-    assert_eq!(children[3], Children { parents: (3, 10), siblings: vec![2, 12], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[4], Children { parents: (4, 12), siblings: vec![5, 6, 14, 25, 35], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[5], Children { parents: (5, 12), siblings: vec![4, 6, 15], value: 0, possible_values: vec![9] });
-    assert_eq!(children[6], Children { parents: (6, 12), siblings: vec![4, 5, 16, 26], value: 0, possible_values: vec![6, 8] });
-    assert_eq!(children[7], Children { parents: (7, 15), siblings: vec![8, 9, 10, 11, 12, 13, 19, 29], value: 0, possible_values: vec![6, 8] });
-    assert_eq!(children[8], Children { parents: (0, 15), siblings: vec![0, 7, 9, 10, 11, 12, 13, 20, 30, 39], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[9], Children { parents: (1, 15), siblings: vec![1, 7, 8, 10, 11, 12, 13, 21], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[10], Children { parents: (9, 15), siblings: vec![7, 8, 9, 11, 12, 13, 22], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[11], Children { parents: (2, 15), siblings: vec![2, 7, 8, 9, 10, 12, 13], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[12], Children { parents: (3, 15), siblings: vec![3, 7, 8, 9, 10, 11, 13], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 8] });
-    assert_eq!(children[13], Children { parents: (11, 15), siblings: vec![7, 8, 9, 10, 11, 12, 23, 33, 44, 52], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 8] });
-    assert_eq!(children[14], Children { parents: (4, 17), siblings: vec![4, 15, 16, 17, 18, 25, 35], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[15], Children { parents: (5, 17), siblings: vec![5, 14, 16, 17, 18], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[16], Children { parents: (6, 17), siblings: vec![6, 14, 15, 17, 18, 26], value: 0, possible_values: vec![4, 6, 7, 8] });
-    assert_eq!(children[17], Children { parents: (13, 17), siblings: vec![14, 15, 16, 18, 27, 37, 48, 56, 65, 76], value: 0, possible_values: vec![4, 6, 7] });
-    assert_eq!(children[18], Children { parents: (14, 17), siblings: vec![14, 15, 16, 17, 28, 38], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[19], Children { parents: (7, 18), siblings: vec![7, 20, 21, 22, 29], value: 0, possible_values: vec![6] });
-    assert_eq!(children[20], Children { parents: (0, 18), siblings: vec![0, 8, 19, 21, 22, 30, 39], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[21], Children { parents: (1, 18), siblings: vec![1, 9, 19, 20, 22], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[22], Children { parents: (9, 18), siblings: vec![10, 19, 20, 21], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[23], Children { parents: (11, 21), siblings: vec![13, 24, 25, 33, 44, 52], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[24], Children { parents: (16, 21), siblings: vec![23, 25, 34, 45, 53], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[25], Children { parents: (4, 21), siblings: vec![4, 14, 23, 24, 35], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[26], Children { parents: (6, 23), siblings: vec![6, 16, 27, 28], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[27], Children { parents: (13, 23), siblings: vec![17, 26, 28, 37, 48, 56, 65, 76], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[28], Children { parents: (14, 23), siblings: vec![18, 26, 27, 38], value: 0, possible_values: vec![7] });
-    assert_eq!(children[29], Children { parents: (7, 24), siblings: vec![7, 19, 30], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[30], Children { parents: (0, 24), siblings: vec![0, 8, 20, 29, 39], value: 0, possible_values: vec![3, 4, 5] });
-    assert_eq!(children[31], Children { parents: (19, 27), siblings: vec![32, 33, 34, 35, 36, 42, 51, 61], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[32], Children { parents: (20, 27), siblings: vec![31, 33, 34, 35, 36, 43], value: 0, possible_values: vec![1, 2, 3, 5, 6] });
-    assert_eq!(children[33], Children { parents: (11, 27), siblings: vec![13, 23, 31, 32, 34, 35, 36, 44, 52], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[34], Children { parents: (16, 27), siblings: vec![24, 31, 32, 33, 35, 36, 45, 53], value: 0, possible_values: vec![6] });
-    assert_eq!(children[35], Children { parents: (4, 27), siblings: vec![4, 14, 25, 31, 32, 33, 34, 36], value: 0, possible_values: vec![5] });
-    assert_eq!(children[36], Children { parents: (22, 27), siblings: vec![31, 32, 33, 34, 35, 46], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[37], Children { parents: (13, 29), siblings: vec![17, 27, 38, 48, 56, 65, 76], value: 0, possible_values: vec![1, 2, 3, 4, 6, 7] });
-    assert_eq!(children[38], Children { parents: (14, 29), siblings: vec![18, 28, 37], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[39], Children { parents: (0, 30), siblings: vec![0, 8, 20, 30, 40, 41, 42, 43, 44, 45], value: 0, possible_values: vec![3, 4, 5] });
-    assert_eq!(children[40], Children { parents: (25, 30), siblings: vec![39, 41, 42, 43, 44, 45, 49], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[41], Children { parents: (26, 30), siblings: vec![39, 40, 42, 43, 44, 45, 50, 60, 70, 80, 89, 98, 109, 119], value: 0, possible_values: vec![3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[42], Children { parents: (19, 30), siblings: vec![31, 39, 40, 41, 43, 44, 45, 51, 61], value: 0, possible_values: vec![3, 4, 5, 6] });
-    assert_eq!(children[43], Children { parents: (20, 30), siblings: vec![32, 39, 40, 41, 42, 44, 45], value: 0, possible_values: vec![3, 5, 6, 7] });
-    assert_eq!(children[44], Children { parents: (11, 30), siblings: vec![13, 23, 33, 39, 40, 41, 42, 43, 45, 52], value: 0, possible_values: vec![3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[45], Children { parents: (16, 30), siblings: vec![24, 34, 39, 40, 41, 42, 43, 44, 53], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[46], Children { parents: (22, 32), siblings: vec![36, 47, 48], value: 0, possible_values: vec![1] });
-    assert_eq!(children[47], Children { parents: (28, 32), siblings: vec![46, 48, 55], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[48], Children { parents: (13, 32), siblings: vec![17, 27, 37, 46, 47, 56, 65, 76], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[49], Children { parents: (25, 36), siblings: vec![40, 50, 51], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[50], Children { parents: (26, 36), siblings: vec![41, 49, 51, 60, 70, 80, 89, 98, 109, 119], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[51], Children { parents: (19, 36), siblings: vec![31, 42, 49, 50, 61], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[52], Children { parents: (11, 38), siblings: vec![13, 23, 33, 44, 53, 54], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[53], Children { parents: (16, 38), siblings: vec![24, 34, 45, 52, 54], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[54], Children { parents: (31, 38), siblings: vec![52, 53, 63], value: 0, possible_values: vec![6] });
-    assert_eq!(children[55], Children { parents: (28, 40), siblings: vec![47, 56, 57], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[56], Children { parents: (13, 40), siblings: vec![17, 27, 37, 48, 55, 57, 65, 76], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[57], Children { parents: (33, 40), siblings: vec![55, 56, 66], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[58], Children { parents: (34, 41), siblings: vec![59, 67, 77], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[59], Children { parents: (35, 41), siblings: vec![58, 68, 78, 87], value: 0, possible_values: vec![5, 7, 8, 9] });
-    assert_eq!(children[60], Children { parents: (26, 43), siblings: vec![41, 50, 61, 62, 70, 80, 89, 98, 109, 119], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[61], Children { parents: (19, 43), siblings: vec![31, 42, 51, 60, 62], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[62], Children { parents: (37, 43), siblings: vec![60, 61, 71, 82, 91, 99, 110], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[63], Children { parents: (31, 46), siblings: vec![54, 64], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[64], Children { parents: (39, 46), siblings: vec![63, 74, 85, 94, 103, 113, 123, 133, 142], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[65], Children { parents: (13, 48), siblings: vec![17, 27, 37, 48, 56, 66, 76], value: 0, possible_values: vec![1, 2, 4, 5] });
-    assert_eq!(children[66], Children { parents: (33, 48), siblings: vec![57, 65], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[67], Children { parents: (34, 49), siblings: vec![58, 68, 69, 70, 77], value: 0, possible_values: vec![6, 7] });
-    assert_eq!(children[68], Children { parents: (35, 49), siblings: vec![59, 67, 69, 70, 78, 87], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[69], Children { parents: (42, 49), siblings: vec![67, 68, 70, 79, 88, 97, 108], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[70], Children { parents: (26, 49), siblings: vec![41, 50, 60, 67, 68, 69, 80, 89, 98, 109, 119], value: 0, possible_values: vec![6, 7, 8, 9] });
-    assert_eq!(children[71], Children { parents: (37, 51), siblings: vec![62, 72, 73, 82, 91, 99, 110], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[72], Children { parents: (44, 51), siblings: vec![71, 73, 83], value: 0, possible_values: vec![4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[73], Children { parents: (45, 51), siblings: vec![71, 72, 84, 92, 101, 112, 121], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[74], Children { parents: (39, 52), siblings: vec![64, 75, 76, 85, 94, 103, 113, 123, 133, 142], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[75], Children { parents: (47, 52), siblings: vec![74, 76, 86, 95, 104, 114], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[76], Children { parents: (13, 52), siblings: vec![17, 27, 37, 48, 56, 65, 74, 75], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[77], Children { parents: (34, 53), siblings: vec![58, 67, 78, 79, 80, 81, 82, 83, 84], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[78], Children { parents: (35, 53), siblings: vec![59, 68, 77, 79, 80, 81, 82, 83, 84, 87], value: 0, possible_values: vec![5, 7, 8] });
-    assert_eq!(children[79], Children { parents: (42, 53), siblings: vec![69, 77, 78, 80, 81, 82, 83, 84, 88, 97, 108], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[80], Children { parents: (26, 53), siblings: vec![41, 50, 60, 70, 77, 78, 79, 81, 82, 83, 84, 89, 98, 109, 119], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[81], Children { parents: (50, 53), siblings: vec![77, 78, 79, 80, 82, 83, 84, 90], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[82], Children { parents: (37, 53), siblings: vec![62, 71, 77, 78, 79, 80, 81, 83, 84, 91, 99, 110], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[83], Children { parents: (44, 53), siblings: vec![72, 77, 78, 79, 80, 81, 82, 84], value: 0, possible_values: vec![4, 5, 6, 7, 8] });
-    assert_eq!(children[84], Children { parents: (45, 53), siblings: vec![73, 77, 78, 79, 80, 81, 82, 83, 92, 101, 112, 121], value: 0, possible_values: vec![3, 5, 6, 7, 8] });
-    assert_eq!(children[85], Children { parents: (39, 55), siblings: vec![64, 74, 86, 94, 103, 113, 123, 133, 142], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[86], Children { parents: (47, 55), siblings: vec![75, 85, 95, 104, 114], value: 0, possible_values: vec![2, 3, 4, 6] });
-    assert_eq!(children[87], Children { parents: (35, 57), siblings: vec![59, 68, 78, 88, 89, 90, 91], value: 0, possible_values: vec![5] });
-    assert_eq!(children[88], Children { parents: (42, 57), siblings: vec![69, 79, 87, 89, 90, 91, 97, 108], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[89], Children { parents: (26, 57), siblings: vec![41, 50, 60, 70, 80, 87, 88, 90, 91, 98, 109, 119], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[90], Children { parents: (50, 57), siblings: vec![81, 87, 88, 89, 91], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[91], Children { parents: (37, 57), siblings: vec![62, 71, 82, 87, 88, 89, 90, 99, 110], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[92], Children { parents: (45, 59), siblings: vec![73, 84, 93, 94, 95, 96, 101, 112, 121], value: 0, possible_values: vec![3, 5] });
-    assert_eq!(children[93], Children { parents: (54, 59), siblings: vec![92, 94, 95, 96, 102], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[94], Children { parents: (39, 59), siblings: vec![64, 74, 85, 92, 93, 95, 96, 103, 113, 123, 133, 142], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[95], Children { parents: (47, 59), siblings: vec![75, 86, 92, 93, 94, 96, 104, 114], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[96], Children { parents: (56, 59), siblings: vec![92, 93, 94, 95, 105, 115, 124], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[97], Children { parents: (42, 62), siblings: vec![69, 79, 88, 98, 108], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[98], Children { parents: (26, 62), siblings: vec![41, 50, 60, 70, 80, 89, 97, 109, 119], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[99], Children { parents: (37, 63), siblings: vec![62, 71, 82, 91, 100, 101, 102, 103, 104, 105, 106, 110], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8] });
-    assert_eq!(children[100], Children { parents: (58, 63), siblings: vec![99, 101, 102, 103, 104, 105, 106, 111], value: 0, possible_values: vec![2] });
-    assert_eq!(children[101], Children { parents: (45, 63), siblings: vec![73, 84, 92, 99, 100, 102, 103, 104, 105, 106, 112, 121], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[102], Children { parents: (54, 63), siblings: vec![93, 99, 100, 101, 103, 104, 105, 106], value: 0, possible_values: vec![3] });
-    assert_eq!(children[103], Children { parents: (39, 63), siblings: vec![64, 74, 85, 94, 99, 100, 101, 102, 104, 105, 106, 113, 123, 133, 142], value: 0, possible_values: vec![2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[104], Children { parents: (47, 63), siblings: vec![75, 86, 95, 99, 100, 101, 102, 103, 105, 106, 114], value: 0, possible_values: vec![2, 3, 4, 6] });
-    assert_eq!(children[105], Children { parents: (56, 63), siblings: vec![96, 99, 100, 101, 102, 103, 104, 106, 115, 124], value: 0, possible_values: vec![2, 3, 5] });
-    assert_eq!(children[106], Children { parents: (60, 63), siblings: vec![99, 100, 101, 102, 103, 104, 105, 116, 125], value: 0, possible_values: vec![2, 4] });
-    assert_eq!(children[107], Children { parents: (61, 65), siblings: vec![108, 109, 118, 127, 135, 146, 156, 166], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[108], Children { parents: (42, 65), siblings: vec![69, 79, 88, 97, 107, 109], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[109], Children { parents: (26, 65), siblings: vec![41, 50, 60, 70, 80, 89, 98, 107, 108, 119], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[110], Children { parents: (37, 67), siblings: vec![62, 71, 82, 91, 99, 111, 112], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[111], Children { parents: (58, 67), siblings: vec![100, 110, 112], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[112], Children { parents: (45, 67), siblings: vec![73, 84, 92, 101, 110, 111, 121], value: 0, possible_values: vec![3, 5, 6] });
-    assert_eq!(children[113], Children { parents: (39, 69), siblings: vec![64, 74, 85, 94, 103, 114, 115, 116, 123, 133, 142], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[114], Children { parents: (47, 69), siblings: vec![75, 86, 95, 104, 113, 115, 116], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[115], Children { parents: (56, 69), siblings: vec![96, 105, 113, 114, 116, 124], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[116], Children { parents: (60, 69), siblings: vec![106, 113, 114, 115, 125], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[117], Children { parents: (64, 70), siblings: vec![118, 126], value: 0, possible_values: vec![1] });
-    assert_eq!(children[118], Children { parents: (61, 70), siblings: vec![107, 117, 127, 135, 146, 156, 166], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[119], Children { parents: (26, 72), siblings: vec![41, 50, 60, 70, 80, 89, 98, 109, 120], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[120], Children { parents: (66, 72), siblings: vec![119, 129], value: 0, possible_values: vec![9] });
-    assert_eq!(children[121], Children { parents: (45, 75), siblings: vec![73, 84, 92, 101, 112, 122, 123], value: 0, possible_values: vec![3, 5, 6, 7, 8, 9] });
-    assert_eq!(children[122], Children { parents: (68, 75), siblings: vec![121, 123, 132, 141, 152], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[123], Children { parents: (39, 75), siblings: vec![64, 74, 85, 94, 103, 113, 121, 122, 133, 142], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] });
-    assert_eq!(children[124], Children { parents: (56, 77), siblings: vec![96, 105, 115, 125], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[125], Children { parents: (60, 77), siblings: vec![106, 116, 124], value: 0, possible_values: vec![1] });
-    assert_eq!(children[126], Children { parents: (64, 78), siblings: vec![117, 127, 128], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[127], Children { parents: (61, 78), siblings: vec![107, 118, 126, 128, 135, 146, 156, 166], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[128], Children { parents: (71, 78), siblings: vec![126, 127, 136], value: 0, possible_values: vec![4, 5] });
-    assert_eq!(children[129], Children { parents: (66, 80), siblings: vec![120, 130, 131], value: 0, possible_values: vec![7] });
-    assert_eq!(children[130], Children { parents: (73, 80), siblings: vec![129, 131, 138, 149, 159], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[131], Children { parents: (74, 80), siblings: vec![129, 130, 139, 150, 160, 170], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[132], Children { parents: (68, 82), siblings: vec![122, 133, 134, 141, 152], value: 0, possible_values: vec![6] });
-    assert_eq!(children[133], Children { parents: (39, 82), siblings: vec![64, 74, 85, 94, 103, 113, 123, 132, 134, 142], value: 0, possible_values: vec![6, 8, 9] });
-    assert_eq!(children[134], Children { parents: (76, 82), siblings: vec![132, 133, 143], value: 0, possible_values: vec![9] });
-    assert_eq!(children[135], Children { parents: (61, 85), siblings: vec![107, 118, 127, 136, 137, 146, 156, 166], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[136], Children { parents: (71, 85), siblings: vec![128, 135, 137], value: 0, possible_values: vec![7, 8, 9] });
-    assert_eq!(children[137], Children { parents: (79, 85), siblings: vec![135, 136, 147], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[138], Children { parents: (73, 87), siblings: vec![130, 139, 140, 141, 142, 143, 144, 149, 159], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[139], Children { parents: (74, 87), siblings: vec![131, 138, 140, 141, 142, 143, 144, 150, 160, 170], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[140], Children { parents: (81, 87), siblings: vec![138, 139, 141, 142, 143, 144, 151], value: 0, possible_values: vec![3, 4, 5, 7] });
-    assert_eq!(children[141], Children { parents: (68, 87), siblings: vec![122, 132, 138, 139, 140, 142, 143, 144, 152], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[142], Children { parents: (39, 87), siblings: vec![64, 74, 85, 94, 103, 113, 123, 133, 138, 139, 140, 141, 143, 144], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[143], Children { parents: (76, 87), siblings: vec![134, 138, 139, 140, 141, 142, 144], value: 0, possible_values: vec![7] });
-    assert_eq!(children[144], Children { parents: (83, 87), siblings: vec![138, 139, 140, 141, 142, 143, 153, 163, 175, 183], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[145], Children { parents: (84, 89), siblings: vec![146, 155, 165], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[146], Children { parents: (61, 89), siblings: vec![107, 118, 127, 135, 145, 156, 166], value: 0, possible_values: vec![8, 9] });
-    assert_eq!(children[147], Children { parents: (79, 91), siblings: vec![137, 148, 149, 150, 151, 152], value: 0, possible_values: vec![7, 9] });
-    assert_eq!(children[148], Children { parents: (86, 91), siblings: vec![147, 149, 150, 151, 152, 158, 169, 179], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[149], Children { parents: (73, 91), siblings: vec![130, 138, 147, 148, 150, 151, 152, 159], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[150], Children { parents: (74, 91), siblings: vec![131, 139, 147, 148, 149, 151, 152, 160, 170], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[151], Children { parents: (81, 91), siblings: vec![140, 147, 148, 149, 150, 152], value: 0, possible_values: vec![3, 4, 5, 7, 8, 9] });
-    assert_eq!(children[152], Children { parents: (68, 91), siblings: vec![122, 132, 141, 147, 148, 149, 150, 151], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[153], Children { parents: (83, 94), siblings: vec![144, 154, 163, 175, 183], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[154], Children { parents: (88, 94), siblings: vec![153, 164, 176], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[155], Children { parents: (84, 95), siblings: vec![145, 156, 157, 165], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[156], Children { parents: (61, 95), siblings: vec![107, 118, 127, 135, 146, 155, 157, 166], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[157], Children { parents: (90, 95), siblings: vec![155, 156, 167, 177], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[158], Children { parents: (86, 97), siblings: vec![148, 159, 160, 169, 179], value: 0, possible_values: vec![1, 2] });
-    assert_eq!(children[159], Children { parents: (73, 97), siblings: vec![130, 138, 149, 158, 160], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[160], Children { parents: (74, 97), siblings: vec![131, 139, 150, 158, 159, 170], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[161], Children { parents: (92, 100), siblings: vec![162, 163, 164, 173], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[162], Children { parents: (93, 100), siblings: vec![161, 163, 164, 174, 182], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[163], Children { parents: (83, 100), siblings: vec![144, 153, 161, 162, 164, 175, 183], value: 0, possible_values: vec![1, 2, 3, 4] });
-    assert_eq!(children[164], Children { parents: (88, 100), siblings: vec![154, 161, 162, 163, 176], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[165], Children { parents: (84, 101), siblings: vec![145, 155, 166, 167, 168, 169], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[166], Children { parents: (61, 101), siblings: vec![107, 118, 127, 135, 146, 156, 165, 167, 168, 169], value: 0, possible_values: vec![1, 2, 3, 4, 6] });
-    assert_eq!(children[167], Children { parents: (90, 101), siblings: vec![157, 165, 166, 168, 169, 177], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[168], Children { parents: (96, 101), siblings: vec![165, 166, 167, 169, 178], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[169], Children { parents: (86, 101), siblings: vec![148, 158, 165, 166, 167, 168, 179], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[170], Children { parents: (74, 102), siblings: vec![131, 139, 150, 160, 171, 172, 173, 174, 175, 176], value: 0, possible_values: vec![1, 2, 3, 4, 5] });
-    assert_eq!(children[171], Children { parents: (98, 102), siblings: vec![170, 172, 173, 174, 175, 176, 180], value: 0, possible_values: vec![7] });
-    assert_eq!(children[172], Children { parents: (99, 102), siblings: vec![170, 171, 173, 174, 175, 176, 181], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[173], Children { parents: (92, 102), siblings: vec![161, 170, 171, 172, 174, 175, 176], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[174], Children { parents: (93, 102), siblings: vec![162, 170, 171, 172, 173, 175, 176, 182], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6] });
-    assert_eq!(children[175], Children { parents: (83, 102), siblings: vec![144, 153, 163, 170, 171, 172, 173, 174, 176, 183], value: 0, possible_values: vec![1, 2, 3, 4, 5, 6, 7] });
-    assert_eq!(children[176], Children { parents: (88, 102), siblings: vec![154, 164, 170, 171, 172, 173, 174, 175], value: 0, possible_values: vec![1, 2, 3] });
-    assert_eq!(children[177], Children { parents: (90, 103), siblings: vec![157, 167, 178, 179], value: 0, possible_values: vec![1, 2, 4] });
-    assert_eq!(children[178], Children { parents: (96, 103), siblings: vec![168, 177, 179], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[179], Children { parents: (86, 103), siblings: vec![148, 158, 169, 177, 178], value: 0, possible_values: vec![1, 2, 3, 5] });
-    assert_eq!(children[180], Children { parents: (98, 104), siblings: vec![171, 181], value: 0, possible_values: vec![9] });
-    assert_eq!(children[181], Children { parents: (99, 104), siblings: vec![172, 180], value: 0, possible_values: vec![8] });
-    assert_eq!(children[182], Children { parents: (93, 105), siblings: vec![162, 174, 183], value: 0, possible_values: vec![1, 3] });
-    assert_eq!(children[183], Children { parents: (83, 105), siblings: vec![144, 153, 163, 175, 182], value: 0, possible_values: vec![1, 3] });
-  }
-}
\ No newline at end of file
+    assert_eq!(children[0], Children { parents: runs(0, 8), siblings: cells(&[1, 8, 20, 30, 39]), value: 0, possible_values: digits(&[1, 2]), position: (1, 2) });
+    assert_eq!(children[1], Children { parents: runs(1, 8), siblings: cells(&[0, 9, 21]), value: 0, possible_values: digits(&[1, 2]), position: (1, 3) });
+    assert_eq!(children[2], Children { parents: runs(2, 10), siblings: cells(&[3, 11]), value: 0, possible_values: digits(&[1]), position: (1, 5) });
+    assert_eq!(children[3], Children { parents: runs(3, 10), siblings: cells(&[2, 12]), value: 0, possible_values: digits(&[1, 3]), position: (1, 6) });
+    assert_eq!(children[4], Children { parents: runs(4, 12), siblings: cells(&[5, 6, 14, 25, 35]), value: 0, possible_values: digits(&[8, 9]), position: (1, 9) });
+    assert_eq!(children[5], Children { parents: runs(5, 12), siblings: cells(&[4, 6, 15]), value: 0, possible_values: digits(&[9]), position: (1, 10) });
+    assert_eq!(children[6], Children { parents: runs(6, 12), siblings: cells(&[4, 5, 16, 26]), value: 0, possible_values: digits(&[6, 8]), position: (1, 11) });
+    assert_eq!(children[7], Children { parents: runs(7, 15), siblings: cells(&[8, 9, 10, 11, 12, 13, 19, 29]), value: 0, possible_values: digits(&[6, 8]), position: (2, 1) });
+    assert_eq!(children[8], Children { parents: runs(0, 15), siblings: cells(&[0, 7, 9, 10, 11, 12, 13, 20, 30, 39]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (2, 2) });
+    assert_eq!(children[9], Children { parents: runs(1, 15), siblings: cells(&[1, 7, 8, 10, 11, 12, 13, 21]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (2, 3) });
+    assert_eq!(children[10], Children { parents: runs(9, 15), siblings: cells(&[7, 8, 9, 11, 12, 13, 22]), value: 0, possible_values: digits(&[1, 2]), position: (2, 4) });
+    assert_eq!(children[11], Children { parents: runs(2, 15), siblings: cells(&[2, 7, 8, 9, 10, 12, 13]), value: 0, possible_values: digits(&[1, 2]), position: (2, 5) });
+    assert_eq!(children[12], Children { parents: runs(3, 15), siblings: cells(&[3, 7, 8, 9, 10, 11, 13]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 8]), position: (2, 6) });
+    assert_eq!(children[13], Children { parents: runs(11, 15), siblings: cells(&[7, 8, 9, 10, 11, 12, 23, 33, 44, 52]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 8]), position: (2, 7) });
+    assert_eq!(children[14], Children { parents: runs(4, 17), siblings: cells(&[4, 15, 16, 17, 18, 25, 35]), value: 0, possible_values: digits(&[7, 8, 9]), position: (2, 9) });
+    assert_eq!(children[15], Children { parents: runs(5, 17), siblings: cells(&[5, 14, 16, 17, 18]), value: 0, possible_values: digits(&[7, 9]), position: (2, 10) });
+    assert_eq!(children[16], Children { parents: runs(6, 17), siblings: cells(&[6, 14, 15, 17, 18, 26]), value: 0, possible_values: digits(&[4, 6, 7, 8]), position: (2, 11) });
+    assert_eq!(children[17], Children { parents: runs(13, 17), siblings: cells(&[14, 15, 16, 18, 27, 37, 48, 56, 65, 76]), value: 0, possible_values: digits(&[4, 6, 7]), position: (2, 12) });
+    assert_eq!(children[18], Children { parents: runs(14, 17), siblings: cells(&[14, 15, 16, 17, 28, 38]), value: 0, possible_values: digits(&[7, 8, 9]), position: (2, 13) });
+    assert_eq!(children[19], Children { parents: runs(7, 18), siblings: cells(&[7, 20, 21, 22, 29]), value: 0, possible_values: digits(&[6]), position: (3, 1) });
+    assert_eq!(children[20], Children { parents: runs(0, 18), siblings: cells(&[0, 8, 19, 21, 22, 30, 39]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (3, 2) });
+    assert_eq!(children[21], Children { parents: runs(1, 18), siblings: cells(&[1, 9, 19, 20, 22]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (3, 3) });
+    assert_eq!(children[22], Children { parents: runs(9, 18), siblings: cells(&[10, 19, 20, 21]), value: 0, possible_values: digits(&[1, 2]), position: (3, 4) });
+    assert_eq!(children[23], Children { parents: runs(11, 21), siblings: cells(&[13, 24, 25, 33, 44, 52]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8, 9]), position: (3, 7) });
+    assert_eq!(children[24], Children { parents: runs(16, 21), siblings: cells(&[23, 25, 34, 45, 53]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (3, 8) });
+    assert_eq!(children[25], Children { parents: runs(4, 21), siblings: cells(&[4, 14, 23, 24, 35]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (3, 9) });
+    assert_eq!(children[26], Children { parents: runs(6, 23), siblings: cells(&[6, 16, 27, 28]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (3, 11) });
+    assert_eq!(children[27], Children { parents: runs(13, 23), siblings: cells(&[17, 26, 28, 37, 48, 56, 65, 76]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (3, 12) });
+    assert_eq!(children[28], Children { parents: runs(14, 23), siblings: cells(&[18, 26, 27, 38]), value: 0, possible_values: digits(&[7]), position: (3, 13) });
+    assert_eq!(children[29], Children { parents: runs(7, 24), siblings: cells(&[7, 19, 30]), value: 0, possible_values: digits(&[8, 9]), position: (4, 1) });
+    assert_eq!(children[30], Children { parents: runs(0, 24), siblings: cells(&[0, 8, 20, 29, 39]), value: 0, possible_values: digits(&[3, 4, 5]), position: (4, 2) });
+    assert_eq!(children[31], Children { parents: runs(19, 27), siblings: cells(&[32, 33, 34, 35, 36, 42, 51, 61]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (4, 5) });
+    assert_eq!(children[32], Children { parents: runs(20, 27), siblings: cells(&[31, 33, 34, 35, 36, 43]), value: 0, possible_values: digits(&[1, 2, 3, 5, 6]), position: (4, 6) });
+    assert_eq!(children[33], Children { parents: runs(11, 27), siblings: cells(&[13, 23, 31, 32, 34, 35, 36, 44, 52]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (4, 7) });
+    assert_eq!(children[34], Children { parents: runs(16, 27), siblings: cells(&[24, 31, 32, 33, 35, 36, 45, 53]), value: 0, possible_values: digits(&[6]), position: (4, 8) });
+    assert_eq!(children[35], Children { parents: runs(4, 27), siblings: cells(&[4, 14, 25, 31, 32, 33, 34, 36]), value: 0, possible_values: digits(&[5]), position: (4, 9) });
+    assert_eq!(children[36], Children { parents: runs(22, 27), siblings: cells(&[31, 32, 33, 34, 35, 46]), value: 0, possible_values: digits(&[1, 3]), position: (4, 10) });
+    assert_eq!(children[37], Children { parents: runs(13, 29), siblings: cells(&[17, 27, 38, 48, 56, 65, 76]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6, 7]), position: (4, 12) });
+    assert_eq!(children[38], Children { parents: runs(14, 29), siblings: cells(&[18, 28, 37]), value: 0, possible_values: digits(&[7, 8, 9]), position: (4, 13) });
+    assert_eq!(children[39], Children { parents: runs(0, 30), siblings: cells(&[0, 8, 20, 30, 40, 41, 42, 43, 44, 45]), value: 0, possible_values: digits(&[3, 4, 5]), position: (5, 2) });
+    assert_eq!(children[40], Children { parents: runs(25, 30), siblings: cells(&[39, 41, 42, 43, 44, 45, 49]), value: 0, possible_values: digits(&[8, 9]), position: (5, 3) });
+    assert_eq!(children[41], Children { parents: runs(26, 30), siblings: cells(&[39, 40, 42, 43, 44, 45, 50, 60, 70, 80, 89, 98, 109, 119]), value: 0, possible_values: digits(&[3, 4, 5, 6, 7, 8, 9]), position: (5, 4) });
+    assert_eq!(children[42], Children { parents: runs(19, 30), siblings: cells(&[31, 39, 40, 41, 43, 44, 45, 51, 61]), value: 0, possible_values: digits(&[3, 4, 5, 6]), position: (5, 5) });
+    assert_eq!(children[43], Children { parents: runs(20, 30), siblings: cells(&[32, 39, 40, 41, 42, 44, 45]), value: 0, possible_values: digits(&[3, 5, 6, 7]), position: (5, 6) });
+    assert_eq!(children[44], Children { parents: runs(11, 30), siblings: cells(&[13, 23, 33, 39, 40, 41, 42, 43, 45, 52]), value: 0, possible_values: digits(&[3, 4, 5, 6, 7, 8, 9]), position: (5, 7) });
+    assert_eq!(children[45], Children { parents: runs(16, 30), siblings: cells(&[24, 34, 39, 40, 41, 42, 43, 44, 53]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (5, 8) });
+    assert_eq!(children[46], Children { parents: runs(22, 32), siblings: cells(&[36, 47, 48]), value: 0, possible_values: digits(&[1]), position: (5, 10) });
+    assert_eq!(children[47], Children { parents: runs(28, 32), siblings: cells(&[46, 48, 55]), value: 0, possible_values: digits(&[1, 2]), position: (5, 11) });
+    assert_eq!(children[48], Children { parents: runs(13, 32), siblings: cells(&[17, 27, 37, 46, 47, 56, 65, 76]), value: 0, possible_values: digits(&[1, 2, 4]), position: (5, 12) });
+    assert_eq!(children[49], Children { parents: runs(25, 36), siblings: cells(&[40, 50, 51]), value: 0, possible_values: digits(&[8, 9]), position: (6, 3) });
+    assert_eq!(children[50], Children { parents: runs(26, 36), siblings: cells(&[41, 49, 51, 60, 70, 80, 89, 98, 109, 119]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (6, 4) });
+    assert_eq!(children[51], Children { parents: runs(19, 36), siblings: cells(&[31, 42, 49, 50, 61]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (6, 5) });
+    assert_eq!(children[52], Children { parents: runs(11, 38), siblings: cells(&[13, 23, 33, 44, 53, 54]), value: 0, possible_values: digits(&[6, 8, 9]), position: (6, 7) });
+    assert_eq!(children[53], Children { parents: runs(16, 38), siblings: cells(&[24, 34, 45, 52, 54]), value: 0, possible_values: digits(&[6, 8, 9]), position: (6, 8) });
+    assert_eq!(children[54], Children { parents: runs(31, 38), siblings: cells(&[52, 53, 63]), value: 0, possible_values: digits(&[6]), position: (6, 9) });
+    assert_eq!(children[55], Children { parents: runs(28, 40), siblings: cells(&[47, 56, 57]), value: 0, possible_values: digits(&[1, 2]), position: (6, 11) });
+    assert_eq!(children[56], Children { parents: runs(13, 40), siblings: cells(&[17, 27, 37, 48, 55, 57, 65, 76]), value: 0, possible_values: digits(&[1, 2, 3]), position: (6, 12) });
+    assert_eq!(children[57], Children { parents: runs(33, 40), siblings: cells(&[55, 56, 66]), value: 0, possible_values: digits(&[1, 2]), position: (6, 13) });
+    assert_eq!(children[58], Children { parents: runs(34, 41), siblings: cells(&[59, 67, 77]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7]), position: (7, 1) });
+    assert_eq!(children[59], Children { parents: runs(35, 41), siblings: cells(&[58, 68, 78, 87]), value: 0, possible_values: digits(&[5, 7, 8, 9]), position: (7, 2) });
+    assert_eq!(children[60], Children { parents: runs(26, 43), siblings: cells(&[41, 50, 61, 62, 70, 80, 89, 98, 109, 119]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (7, 4) });
+    assert_eq!(children[61], Children { parents: runs(19, 43), siblings: cells(&[31, 42, 51, 60, 62]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (7, 5) });
+    assert_eq!(children[62], Children { parents: runs(37, 43), siblings: cells(&[60, 61, 71, 82, 91, 99, 110]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (7, 6) });
+    assert_eq!(children[63], Children { parents: runs(31, 46), siblings: cells(&[54, 64]), value: 0, possible_values: digits(&[1, 3]), position: (7, 9) });
+    assert_eq!(children[64], Children { parents: runs(39, 46), siblings: cells(&[63, 74, 85, 94, 103, 113, 123, 133, 142]), value: 0, possible_values: digits(&[1, 3]), position: (7, 10) });
+    assert_eq!(children[65], Children { parents: runs(13, 48), siblings: cells(&[17, 27, 37, 48, 56, 66, 76]), value: 0, possible_values: digits(&[1, 2, 4, 5]), position: (7, 12) });
+    assert_eq!(children[66], Children { parents: runs(33, 48), siblings: cells(&[57, 65]), value: 0, possible_values: digits(&[1, 2]), position: (7, 13) });
+    assert_eq!(children[67], Children { parents: runs(34, 49), siblings: cells(&[58, 68, 69, 70, 77]), value: 0, possible_values: digits(&[6, 7]), position: (8, 1) });
+    assert_eq!(children[68], Children { parents: runs(35, 49), siblings: cells(&[59, 67, 69, 70, 78, 87]), value: 0, possible_values: digits(&[7, 8, 9]), position: (8, 2) });
+    assert_eq!(children[69], Children { parents: runs(42, 49), siblings: cells(&[67, 68, 70, 79, 88, 97, 108]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (8, 3) });
+    assert_eq!(children[70], Children { parents: runs(26, 49), siblings: cells(&[41, 50, 60, 67, 68, 69, 80, 89, 98, 109, 119]), value: 0, possible_values: digits(&[6, 7, 8, 9]), position: (8, 4) });
+    assert_eq!(children[71], Children { parents: runs(37, 51), siblings: cells(&[62, 72, 73, 82, 91, 99, 110]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (8, 6) });
+    assert_eq!(children[72], Children { parents: runs(44, 51), siblings: cells(&[71, 73, 83]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8, 9]), position: (8, 7) });
+    assert_eq!(children[73], Children { parents: runs(45, 51), siblings: cells(&[71, 72, 84, 92, 101, 112, 121]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (8, 8) });
+    assert_eq!(children[74], Children { parents: runs(39, 52), siblings: cells(&[64, 75, 76, 85, 94, 103, 113, 123, 133, 142]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (8, 10) });
+    assert_eq!(children[75], Children { parents: runs(47, 52), siblings: cells(&[74, 76, 86, 95, 104, 114]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (8, 11) });
+    assert_eq!(children[76], Children { parents: runs(13, 52), siblings: cells(&[17, 27, 37, 48, 56, 65, 74, 75]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (8, 12) });
+    assert_eq!(children[77], Children { parents: runs(34, 53), siblings: cells(&[58, 67, 78, 79, 80, 81, 82, 83, 84]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (9, 1) });
+    assert_eq!(children[78], Children { parents: runs(35, 53), siblings: cells(&[59, 68, 77, 79, 80, 81, 82, 83, 84, 87]), value: 0, possible_values: digits(&[5, 7, 8]), position: (9, 2) });
+    assert_eq!(children[79], Children { parents: runs(42, 53), siblings: cells(&[69, 77, 78, 80, 81, 82, 83, 84, 88, 97, 108]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (9, 3) });
+    assert_eq!(children[80], Children { parents: runs(26, 53), siblings: cells(&[41, 50, 60, 70, 77, 78, 79, 81, 82, 83, 84, 89, 98, 109, 119]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (9, 4) });
+    assert_eq!(children[81], Children { parents: runs(50, 53), siblings: cells(&[77, 78, 79, 80, 82, 83, 84, 90]), value: 0, possible_values: digits(&[1, 3]), position: (9, 5) });
+    assert_eq!(children[82], Children { parents: runs(37, 53), siblings: cells(&[62, 71, 77, 78, 79, 80, 81, 83, 84, 91, 99, 110]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8]), position: (9, 6) });
+    assert_eq!(children[83], Children { parents: runs(44, 53), siblings: cells(&[72, 77, 78, 79, 80, 81, 82, 84]), value: 0, possible_values: digits(&[4, 5, 6, 7, 8]), position: (9, 7) });
+    assert_eq!(children[84], Children { parents: runs(45, 53), siblings: cells(&[73, 77, 78, 79, 80, 81, 82, 83, 92, 101, 112, 121]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8]), position: (9, 8) });
+    assert_eq!(children[85], Children { parents: runs(39, 55), siblings: cells(&[64, 74, 86, 94, 103, 113, 123, 133, 142]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8, 9]), position: (9, 10) });
+    assert_eq!(children[86], Children { parents: runs(47, 55), siblings: cells(&[75, 85, 95, 104, 114]), value: 0, possible_values: digits(&[2, 3, 4, 6]), position: (9, 11) });
+    assert_eq!(children[87], Children { parents: runs(35, 57), siblings: cells(&[59, 68, 78, 88, 89, 90, 91]), value: 0, possible_values: digits(&[5]), position: (10, 2) });
+    assert_eq!(children[88], Children { parents: runs(42, 57), siblings: cells(&[69, 79, 87, 89, 90, 91, 97, 108]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (10, 3) });
+    assert_eq!(children[89], Children { parents: runs(26, 57), siblings: cells(&[41, 50, 60, 70, 80, 87, 88, 90, 91, 98, 109, 119]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (10, 4) });
+    assert_eq!(children[90], Children { parents: runs(50, 57), siblings: cells(&[81, 87, 88, 89, 91]), value: 0, possible_values: digits(&[1, 3]), position: (10, 5) });
+    assert_eq!(children[91], Children { parents: runs(37, 57), siblings: cells(&[62, 71, 82, 87, 88, 89, 90, 99, 110]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (10, 6) });
+    assert_eq!(children[92], Children { parents: runs(45, 59), siblings: cells(&[73, 84, 93, 94, 95, 96, 101, 112, 121]), value: 0, possible_values: digits(&[3, 5]), position: (10, 8) });
+    assert_eq!(children[93], Children { parents: runs(54, 59), siblings: cells(&[92, 94, 95, 96, 102]), value: 0, possible_values: digits(&[1, 3]), position: (10, 9) });
+    assert_eq!(children[94], Children { parents: runs(39, 59), siblings: cells(&[64, 74, 85, 92, 93, 95, 96, 103, 113, 123, 133, 142]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (10, 10) });
+    assert_eq!(children[95], Children { parents: runs(47, 59), siblings: cells(&[75, 86, 92, 93, 94, 96, 104, 114]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (10, 11) });
+    assert_eq!(children[96], Children { parents: runs(56, 59), siblings: cells(&[92, 93, 94, 95, 105, 115, 124]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (10, 12) });
+    assert_eq!(children[97], Children { parents: runs(42, 62), siblings: cells(&[69, 79, 88, 98, 108]), value: 0, possible_values: digits(&[8, 9]), position: (11, 3) });
+    assert_eq!(children[98], Children { parents: runs(26, 62), siblings: cells(&[41, 50, 60, 70, 80, 89, 97, 109, 119]), value: 0, possible_values: digits(&[8, 9]), position: (11, 4) });
+    assert_eq!(children[99], Children { parents: runs(37, 63), siblings: cells(&[62, 71, 82, 91, 100, 101, 102, 103, 104, 105, 106, 110]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8]), position: (11, 6) });
+    assert_eq!(children[100], Children { parents: runs(58, 63), siblings: cells(&[99, 101, 102, 103, 104, 105, 106, 111]), value: 0, possible_values: digits(&[2]), position: (11, 7) });
+    assert_eq!(children[101], Children { parents: runs(45, 63), siblings: cells(&[73, 84, 92, 99, 100, 102, 103, 104, 105, 106, 112, 121]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (11, 8) });
+    assert_eq!(children[102], Children { parents: runs(54, 63), siblings: cells(&[93, 99, 100, 101, 103, 104, 105, 106]), value: 0, possible_values: digits(&[3]), position: (11, 9) });
+    assert_eq!(children[103], Children { parents: runs(39, 63), siblings: cells(&[64, 74, 85, 94, 99, 100, 101, 102, 104, 105, 106, 113, 123, 133, 142]), value: 0, possible_values: digits(&[2, 3, 4, 5, 6, 7, 8, 9]), position: (11, 10) });
+    assert_eq!(children[104], Children { parents: runs(47, 63), siblings: cells(&[75, 86, 95, 99, 100, 101, 102, 103, 105, 106, 114]), value: 0, possible_values: digits(&[2, 3, 4, 6]), position: (11, 11) });
+    assert_eq!(children[105], Children { parents: runs(56, 63), siblings: cells(&[96, 99, 100, 101, 102, 103, 104, 106, 115, 124]), value: 0, possible_values: digits(&[2, 3, 5]), position: (11, 12) });
+    assert_eq!(children[106], Children { parents: runs(60, 63), siblings: cells(&[99, 100, 101, 102, 103, 104, 105, 116, 125]), value: 0, possible_values: digits(&[2, 4]), position: (11, 13) });
+    assert_eq!(children[107], Children { parents: runs(61, 65), siblings: cells(&[108, 109, 118, 127, 135, 146, 156, 166]), value: 0, possible_values: digits(&[1, 2, 4]), position: (12, 2) });
+    assert_eq!(children[108], Children { parents: runs(42, 65), siblings: cells(&[69, 79, 88, 97, 107, 109]), value: 0, possible_values: digits(&[1, 2, 4]), position: (12, 3) });
+    assert_eq!(children[109], Children { parents: runs(26, 65), siblings: cells(&[41, 50, 60, 70, 80, 89, 98, 107, 108, 119]), value: 0, possible_values: digits(&[1, 2, 4]), position: (12, 4) });
+    assert_eq!(children[110], Children { parents: runs(37, 67), siblings: cells(&[62, 71, 82, 91, 99, 111, 112]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (12, 6) });
+    assert_eq!(children[111], Children { parents: runs(58, 67), siblings: cells(&[100, 110, 112]), value: 0, possible_values: digits(&[1, 2]), position: (12, 7) });
+    assert_eq!(children[112], Children { parents: runs(45, 67), siblings: cells(&[73, 84, 92, 101, 110, 111, 121]), value: 0, possible_values: digits(&[3, 5, 6]), position: (12, 8) });
+    assert_eq!(children[113], Children { parents: runs(39, 69), siblings: cells(&[64, 74, 85, 94, 103, 114, 115, 116, 123, 133, 142]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (12, 10) });
+    assert_eq!(children[114], Children { parents: runs(47, 69), siblings: cells(&[75, 86, 95, 104, 113, 115, 116]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (12, 11) });
+    assert_eq!(children[115], Children { parents: runs(56, 69), siblings: cells(&[96, 105, 113, 114, 116, 124]), value: 0, possible_values: digits(&[1, 2, 3]), position: (12, 12) });
+    assert_eq!(children[116], Children { parents: runs(60, 69), siblings: cells(&[106, 113, 114, 115, 125]), value: 0, possible_values: digits(&[1, 2, 4]), position: (12, 13) });
+    assert_eq!(children[117], Children { parents: runs(64, 70), siblings: cells(&[118, 126]), value: 0, possible_values: digits(&[1]), position: (13, 1) });
+    assert_eq!(children[118], Children { parents: runs(61, 70), siblings: cells(&[107, 117, 127, 135, 146, 156, 166]), value: 0, possible_values: digits(&[1, 2]), position: (13, 2) });
+    assert_eq!(children[119], Children { parents: runs(26, 72), siblings: cells(&[41, 50, 60, 70, 80, 89, 98, 109, 120]), value: 0, possible_values: digits(&[8, 9]), position: (13, 4) });
+    assert_eq!(children[120], Children { parents: runs(66, 72), siblings: cells(&[119, 129]), value: 0, possible_values: digits(&[9]), position: (13, 5) });
+    assert_eq!(children[121], Children { parents: runs(45, 75), siblings: cells(&[73, 84, 92, 101, 112, 122, 123]), value: 0, possible_values: digits(&[3, 5, 6, 7, 8, 9]), position: (13, 8) });
+    assert_eq!(children[122], Children { parents: runs(68, 75), siblings: cells(&[121, 123, 132, 141, 152]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (13, 9) });
+    assert_eq!(children[123], Children { parents: runs(39, 75), siblings: cells(&[64, 74, 85, 94, 103, 113, 121, 122, 133, 142]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), position: (13, 10) });
+    assert_eq!(children[124], Children { parents: runs(56, 77), siblings: cells(&[96, 105, 115, 125]), value: 0, possible_values: digits(&[1, 3]), position: (13, 12) });
+    assert_eq!(children[125], Children { parents: runs(60, 77), siblings: cells(&[106, 116, 124]), value: 0, possible_values: digits(&[1]), position: (13, 13) });
+    assert_eq!(children[126], Children { parents: runs(64, 78), siblings: cells(&[117, 127, 128]), value: 0, possible_values: digits(&[1, 3]), position: (14, 1) });
+    assert_eq!(children[127], Children { parents: runs(61, 78), siblings: cells(&[107, 118, 126, 128, 135, 146, 156, 166]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (14, 2) });
+    assert_eq!(children[128], Children { parents: runs(71, 78), siblings: cells(&[126, 127, 136]), value: 0, possible_values: digits(&[4, 5]), position: (14, 3) });
+    assert_eq!(children[129], Children { parents: runs(66, 80), siblings: cells(&[120, 130, 131]), value: 0, possible_values: digits(&[7]), position: (14, 5) });
+    assert_eq!(children[130], Children { parents: runs(73, 80), siblings: cells(&[129, 131, 138, 149, 159]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (14, 6) });
+    assert_eq!(children[131], Children { parents: runs(74, 80), siblings: cells(&[129, 130, 139, 150, 160, 170]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (14, 7) });
+    assert_eq!(children[132], Children { parents: runs(68, 82), siblings: cells(&[122, 133, 134, 141, 152]), value: 0, possible_values: digits(&[6]), position: (14, 9) });
+    assert_eq!(children[133], Children { parents: runs(39, 82), siblings: cells(&[64, 74, 85, 94, 103, 113, 123, 132, 134, 142]), value: 0, possible_values: digits(&[6, 8, 9]), position: (14, 10) });
+    assert_eq!(children[134], Children { parents: runs(76, 82), siblings: cells(&[132, 133, 143]), value: 0, possible_values: digits(&[9]), position: (14, 11) });
+    assert_eq!(children[135], Children { parents: runs(61, 85), siblings: cells(&[107, 118, 127, 136, 137, 146, 156, 166]), value: 0, possible_values: digits(&[7, 8, 9]), position: (15, 2) });
+    assert_eq!(children[136], Children { parents: runs(71, 85), siblings: cells(&[128, 135, 137]), value: 0, possible_values: digits(&[7, 8, 9]), position: (15, 3) });
+    assert_eq!(children[137], Children { parents: runs(79, 85), siblings: cells(&[135, 136, 147]), value: 0, possible_values: digits(&[7, 9]), position: (15, 4) });
+    assert_eq!(children[138], Children { parents: runs(73, 87), siblings: cells(&[130, 139, 140, 141, 142, 143, 144, 149, 159]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (15, 6) });
+    assert_eq!(children[139], Children { parents: runs(74, 87), siblings: cells(&[131, 138, 140, 141, 142, 143, 144, 150, 160, 170]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (15, 7) });
+    assert_eq!(children[140], Children { parents: runs(81, 87), siblings: cells(&[138, 139, 141, 142, 143, 144, 151]), value: 0, possible_values: digits(&[3, 4, 5, 7]), position: (15, 8) });
+    assert_eq!(children[141], Children { parents: runs(68, 87), siblings: cells(&[122, 132, 138, 139, 140, 142, 143, 144, 152]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (15, 9) });
+    assert_eq!(children[142], Children { parents: runs(39, 87), siblings: cells(&[64, 74, 85, 94, 103, 113, 123, 133, 138, 139, 140, 141, 143, 144]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (15, 10) });
+    assert_eq!(children[143], Children { parents: runs(76, 87), siblings: cells(&[134, 138, 139, 140, 141, 142, 144]), value: 0, possible_values: digits(&[7]), position: (15, 11) });
+    assert_eq!(children[144], Children { parents: runs(83, 87), siblings: cells(&[138, 139, 140, 141, 142, 143, 153, 163, 175, 183]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (15, 12) });
+    assert_eq!(children[145], Children { parents: runs(84, 89), siblings: cells(&[146, 155, 165]), value: 0, possible_values: digits(&[8, 9]), position: (16, 1) });
+    assert_eq!(children[146], Children { parents: runs(61, 89), siblings: cells(&[107, 118, 127, 135, 145, 156, 166]), value: 0, possible_values: digits(&[8, 9]), position: (16, 2) });
+    assert_eq!(children[147], Children { parents: runs(79, 91), siblings: cells(&[137, 148, 149, 150, 151, 152]), value: 0, possible_values: digits(&[7, 9]), position: (16, 4) });
+    assert_eq!(children[148], Children { parents: runs(86, 91), siblings: cells(&[147, 149, 150, 151, 152, 158, 169, 179]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (16, 5) });
+    assert_eq!(children[149], Children { parents: runs(73, 91), siblings: cells(&[130, 138, 147, 148, 150, 151, 152, 159]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (16, 6) });
+    assert_eq!(children[150], Children { parents: runs(74, 91), siblings: cells(&[131, 139, 147, 148, 149, 151, 152, 160, 170]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (16, 7) });
+    assert_eq!(children[151], Children { parents: runs(81, 91), siblings: cells(&[140, 147, 148, 149, 150, 152]), value: 0, possible_values: digits(&[3, 4, 5, 7, 8, 9]), position: (16, 8) });
+    assert_eq!(children[152], Children { parents: runs(68, 91), siblings: cells(&[122, 132, 141, 147, 148, 149, 150, 151]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (16, 9) });
+    assert_eq!(children[153], Children { parents: runs(83, 94), siblings: cells(&[144, 154, 163, 175, 183]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (16, 12) });
+    assert_eq!(children[154], Children { parents: runs(88, 94), siblings: cells(&[153, 164, 176]), value: 0, possible_values: digits(&[1, 2, 3]), position: (16, 13) });
+    assert_eq!(children[155], Children { parents: runs(84, 95), siblings: cells(&[145, 156, 157, 165]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (17, 1) });
+    assert_eq!(children[156], Children { parents: runs(61, 95), siblings: cells(&[107, 118, 127, 135, 146, 155, 157, 166]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (17, 2) });
+    assert_eq!(children[157], Children { parents: runs(90, 95), siblings: cells(&[155, 156, 167, 177]), value: 0, possible_values: digits(&[1, 2, 4]), position: (17, 3) });
+    assert_eq!(children[158], Children { parents: runs(86, 97), siblings: cells(&[148, 159, 160, 169, 179]), value: 0, possible_values: digits(&[1, 2]), position: (17, 5) });
+    assert_eq!(children[159], Children { parents: runs(73, 97), siblings: cells(&[130, 138, 149, 158, 160]), value: 0, possible_values: digits(&[1, 2, 4]), position: (17, 6) });
+    assert_eq!(children[160], Children { parents: runs(74, 97), siblings: cells(&[131, 139, 150, 158, 159, 170]), value: 0, possible_values: digits(&[1, 2, 4]), position: (17, 7) });
+    assert_eq!(children[161], Children { parents: runs(92, 100), siblings: cells(&[162, 163, 164, 173]), value: 0, possible_values: digits(&[1, 3]), position: (17, 10) });
+    assert_eq!(children[162], Children { parents: runs(93, 100), siblings: cells(&[161, 163, 164, 174, 182]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (17, 11) });
+    assert_eq!(children[163], Children { parents: runs(83, 100), siblings: cells(&[144, 153, 161, 162, 164, 175, 183]), value: 0, possible_values: digits(&[1, 2, 3, 4]), position: (17, 12) });
+    assert_eq!(children[164], Children { parents: runs(88, 100), siblings: cells(&[154, 161, 162, 163, 176]), value: 0, possible_values: digits(&[1, 2, 3]), position: (17, 13) });
+    assert_eq!(children[165], Children { parents: runs(84, 101), siblings: cells(&[145, 155, 166, 167, 168, 169]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (18, 1) });
+    assert_eq!(children[166], Children { parents: runs(61, 101), siblings: cells(&[107, 118, 127, 135, 146, 156, 165, 167, 168, 169]), value: 0, possible_values: digits(&[1, 2, 3, 4, 6]), position: (18, 2) });
+    assert_eq!(children[167], Children { parents: runs(90, 101), siblings: cells(&[157, 165, 166, 168, 169, 177]), value: 0, possible_values: digits(&[1, 2, 4]), position: (18, 3) });
+    assert_eq!(children[168], Children { parents: runs(96, 101), siblings: cells(&[165, 166, 167, 169, 178]), value: 0, possible_values: digits(&[1, 3]), position: (18, 4) });
+    assert_eq!(children[169], Children { parents: runs(86, 101), siblings: cells(&[148, 158, 165, 166, 167, 168, 179]), value: 0, possible_values: digits(&[1, 2, 3]), position: (18, 5) });
+    assert_eq!(children[170], Children { parents: runs(74, 102), siblings: cells(&[131, 139, 150, 160, 171, 172, 173, 174, 175, 176]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5]), position: (18, 7) });
+    assert_eq!(children[171], Children { parents: runs(98, 102), siblings: cells(&[170, 172, 173, 174, 175, 176, 180]), value: 0, possible_values: digits(&[7]), position: (18, 8) });
+    assert_eq!(children[172], Children { parents: runs(99, 102), siblings: cells(&[170, 171, 173, 174, 175, 176, 181]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (18, 9) });
+    assert_eq!(children[173], Children { parents: runs(92, 102), siblings: cells(&[161, 170, 171, 172, 174, 175, 176]), value: 0, possible_values: digits(&[1, 3]), position: (18, 10) });
+    assert_eq!(children[174], Children { parents: runs(93, 102), siblings: cells(&[162, 170, 171, 172, 173, 175, 176, 182]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6]), position: (18, 11) });
+    assert_eq!(children[175], Children { parents: runs(83, 102), siblings: cells(&[144, 153, 163, 170, 171, 172, 173, 174, 176, 183]), value: 0, possible_values: digits(&[1, 2, 3, 4, 5, 6, 7]), position: (18, 12) });
+    assert_eq!(children[176], Children { parents: runs(88, 102), siblings: cells(&[154, 164, 170, 171, 172, 173, 174, 175]), value: 0, possible_values: digits(&[1, 2, 3]), position: (18, 13) });
+    assert_eq!(children[177], Children { parents: runs(90, 103), siblings: cells(&[157, 167, 178, 179]), value: 0, possible_values: digits(&[1, 2, 4]), position: (19, 3) });
+    assert_eq!(children[178], Children { parents: runs(96, 103), siblings: cells(&[168, 177, 179]), value: 0, possible_values: digits(&[1, 3]), position: (19, 4) });
+    assert_eq!(children[179], Children { parents: runs(86, 103), siblings: cells(&[148, 158, 169, 177, 178]), value: 0, possible_values: digits(&[1, 2, 3, 5]), position: (19, 5) });
+    assert_eq!(children[180], Children { parents: runs(98, 104), siblings: cells(&[171, 181]), value: 0, possible_values: digits(&[9]), position: (19, 8) });
+    assert_eq!(children[181], Children { parents: runs(99, 104), siblings: cells(&[172, 180]), value: 0, possible_values: digits(&[8]), position: (19, 9) });
+    assert_eq!(children[182], Children { parents: runs(93, 105), siblings: cells(&[162, 174, 183]), value: 0, possible_values: digits(&[1, 3]), position: (19, 11) });
+    assert_eq!(children[183], Children { parents: runs(83, 105), siblings: cells(&[144, 153, 163, 175, 182]), value: 0, possible_values: digits(&[1, 3]), position: (19, 12) });
+  }
+
+  #[test]
+  fn test_solve_all_input_files() {
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"] {
+      let mut puzzle = Puzzle::load(&puzzle_path(file_name)).unwrap();
+
+      assert!(solve(&mut puzzle), "{} was not solved", file_name);
+      assert!(is_solved(&puzzle), "{} was solved incorrectly", file_name);
+    }
+  }
+
+  #[test]
+  fn test_solve_with_input_file_1() {
+    let puzzle_file = puzzle_path("Kakuro_input.txt");
+
+    let mut puzzle = Puzzle::load(&puzzle_file).unwrap();
+
+    assert!(solve(&mut puzzle));
+
+    let values: Vec<u8> = puzzle.children.iter().map(|child| child.value).collect();
+
+    assert_eq!(&values[0..15], &[1, 2, 4, 1, 2, 4, 5, 3, 1, 2, 3, 9, 7, 8, 9]);
+  }
+
+  #[test]
+  fn test_solve_rejects_contradiction() {
+    let puzzle_file = puzzle_path("Kakuro_input.txt");
+
+    let mut puzzle = Puzzle::load(&puzzle_file).unwrap();
+
+    // two siblings are given the same value so no solution can exist
+    puzzle[CellId(0)].value = 1;
+    puzzle[CellId(1)].value = 1;
+
+    let before = puzzle.clone();
+
+    assert!(!solve(&mut puzzle));
+    assert_eq!(puzzle, before);
+  }
+
+  #[test]
+  fn test_count_solutions() {
+    let unique = Puzzle::load(&write_puzzle("count_unique.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n")).unwrap();
+    let multiple = Puzzle::load(&write_puzzle("count_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n")).unwrap();
+    let unsolvable = Puzzle::load(&write_puzzle("count_unsolvable.txt", "-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n")).unwrap();
+
+    let count = unique.count_solutions(2);
+    assert!(count.is_unique());
+    assert_eq!(count.to_string(), "1");
+    assert_eq!(count.examples.len(), 1);
+    assert!(count.differences().is_empty());
+
+    let count = unsolvable.count_solutions(2);
+    assert_eq!((count.count, count.to_string()), (0, "0".to_string()));
+    assert!(count.examples.is_empty());
+
+    // the limit is never lower than 2, so a grid with several solutions is always reported as such
+    let count = multiple.count_solutions(1);
+    assert_eq!((count.count, count.limit, count.to_string()), (2, 2, "2 or more".to_string()));
+    assert!(count.examples.iter().all(|example| example.is_solved()));
+    assert_eq!(count.differences(), multiple.cell_ids().collect::<Vec<CellId>>());
+
+    // with a higher limit every solution is counted
+    let count = multiple.count_solutions(10);
+    assert_eq!((count.count, count.to_string()), (2, "2".to_string()));
+    assert_eq!(count.examples.len(), 2);
+  }
+
+  #[test]
+  fn test_solve_with_techniques() {
+    let mut puzzle = Puzzle::load(&write_puzzle("techniques.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n")).unwrap();
+    let steps = puzzle.solve_with_techniques();
+
+    assert!(puzzle.is_solved());
+    assert_eq!(steps.iter().map(|step| step.technique).collect::<Vec<Technique>>(), vec![
+      Technique::NakedSingle,
+      Technique::NakedSingle,
+      Technique::NakedSingle,
+      Technique::PlacedDigit,
+      Technique::PlacedDigit,
+      Technique::UniqueCombination
+    ]);
+
+    assert_eq!(steps[0], Step {
+      technique: Technique::NakedSingle,
+      runs: Vec::new(),
+      cells: vec![CellId(0)],
+      digits: digits(&[1]),
+      removed_candidates: vec![(CellId(1), digits(&[1])), (CellId(2), digits(&[1]))],
+      removed_combinations: Vec::new(),
+      placed: vec![(CellId(0), 1)]
+    });
+
+    assert_eq!(steps[3].removed_combinations, vec![(RunId(1), digits(&[1, 5]))]);
+    assert_eq!(steps[4].describe(&puzzle), "placed digit: row clue 7 over 2 cells at row 3, column 1 already has {3}, so it cannot be {1,6} or {2,5}");
+    assert_eq!(steps[5].describe(&puzzle), "unique combination: column clue 6 over 2 cells at row 1, column 3 must be {2,4}, removing {1,5} from row 3, column 3, placing 4 in row 3, column 3");
+
+    // the techniques only make deductions, so a puzzle with two solutions gets stuck
+    let mut multiple = Puzzle::load(&write_puzzle("techniques_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n")).unwrap();
+    assert_eq!(multiple.solve_with_techniques().len(), 2);
+    assert!(!multiple.is_solved());
+  }
+
+  #[test]
+  fn test_solve_with_techniques_matches_solver() {
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"] {
+      let puzzle = Puzzle::load(&puzzle_path(file_name)).unwrap();
+
+      let mut solved = puzzle.clone();
+      assert!(solved.solve());
+
+      let mut by_techniques = puzzle.clone();
+      let steps = by_techniques.solve_with_techniques();
+
+      assert!(by_techniques.is_solved(), "{} was not solved by techniques", file_name);
+      assert!(by_techniques.children.iter().zip(&solved.children).all(|(a, b)| a.value == b.value));
+
+      // every step has to make progress
+      assert!(steps.iter().all(|step| !step.removed_candidates.is_empty() || !step.removed_combinations.is_empty() || !step.placed.is_empty()));
+    }
+  }
+
+  #[test]
+  fn test_techniques_never_remove_the_solution() {
+    let mut techniques_used = Vec::new();
+
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"] {
+      let mut puzzle = Puzzle::load(&puzzle_path(file_name)).unwrap();
+
+      let mut solved = puzzle.clone();
+      assert!(solved.solve());
+
+      for step in puzzle.solve_with_techniques() {
+        techniques_used.push(step.technique);
+
+        for (cell_id, removed) in &step.removed_candidates {
+          assert!(!removed.contains(solved[*cell_id].value), "{} removed the solution from {}", step.technique, file_name);
+        }
+
+        for (run_id, combo) in &step.removed_combinations {
+          let solution: DigitSet = solved[*run_id].children.iter().map(|&id| solved[id].value).collect();
+          assert_ne!(*combo, solution, "{} removed the solution from {}", step.technique, file_name);
+        }
+      }
+    }
+
+    // every technique is needed by at least one of the input files
+    assert!(TECHNIQUES.iter().all(|technique| techniques_used.contains(technique)));
+  }
+
+  #[test]
+  fn test_rate() {
+    let grades: Vec<Grade> = ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"].iter()
+      .map(|file_name| Puzzle::load(&puzzle_path(file_name)).unwrap().rate().unwrap().grade)
+      .collect();
+
+    assert_eq!(grades, vec![Grade::Hard, Grade::Easy, Grade::Hard, Grade::Hard, Grade::Hard]);
+
+    let unique = Puzzle::load(&write_puzzle("rate_unique.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n")).unwrap();
+    assert_eq!(unique.rate(), Some(Difficulty { score: 3006, grade: Grade::Easy, hardest: Some(Technique::UniqueCombination), steps: 6, guesses: 0 }));
+    assert_eq!(rate_with(&unique, &unique.find_solutions(1)[0]), unique.rate());
+
+    // the grade follows the hardest technique rather than the size, 25 blocks of 4 cells that only need easy techniques stay easy
+    let mut large = String::new();
+
+    for _ in 0..5 {
+      large.push_str(&format!("{} {}\n", "-\\\\".repeat(5), ["4\\- 3\\-"; 5].join(" ")));
+      large.push_str(&format!("{} {}\n", "\\xx".repeat(5), ["-\\3"; 5].join(" ")));
+      large.push_str(&format!("{} {}\n", "\\xx".repeat(5), ["-\\4"; 5].join(" ")));
+    }
+
+    let large = Puzzle::load(&write_puzzle("rate_large.txt", &large)).unwrap();
+    let difficulty = large.rate().unwrap();
+
+    assert_eq!(large.children.len(), 100);
+    assert!(difficulty.hardest.unwrap() <= Technique::DigitIntersection);
+    assert_eq!(difficulty.grade, Grade::Easy);
+    assert!(difficulty.steps > unique.rate().unwrap().steps);
+
+    // the techniques get stuck on a puzzle with two solutions, so a cell has to be guessed
+    let multiple = Puzzle::load(&write_puzzle("rate_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n")).unwrap();
+    let difficulty = multiple.rate().unwrap();
+    assert_eq!((difficulty.grade, difficulty.guesses), (Grade::Expert, 1));
+
+    let unsolvable = Puzzle::load(&write_puzzle("rate_unsolvable.txt", "-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n")).unwrap();
+    assert_eq!(unsolvable.rate(), None);
+
+    assert_eq!(grade_steps(&[], 0), Difficulty { score: 0, grade: Grade::Easy, hardest: None, steps: 0, guesses: 0 });
+  }
+
+  #[test]
+  fn test_next_hint() {
+    let mut puzzle = Puzzle::load(&puzzle_path("Kakuro_input.txt")).unwrap();
+    let mut solved = puzzle.clone();
+    assert!(solved.solve());
+
+    let hint = puzzle.next_hint().unwrap();
+    assert_eq!(hint.technique, Technique::NakedSingle);
+    assert_eq!(hint.action, HintAction::Place { cell: CellId(5), digit: 4 });
+    assert_eq!(hint.explanation, "row 2, column 9 has {4} as its only candidate, so it must be 4");
+
+    // following every hint the way a player would solves the puzzle
+    let mut explanations = Vec::new();
+
+    while let Some(hint) = puzzle.next_hint() {
+      match hint.action {
+        HintAction::Place { cell, digit } => {
+          assert_eq!(digit, solved[cell].value);
+          puzzle[cell].value = digit;
+        },
+        HintAction::RemoveCandidates { cell, digits } => puzzle[cell].possible_values -= digits,
+        HintAction::RemoveCombinations { run, combinations } => puzzle[run].combinations.retain(|combo| !combinations.contains(combo))
+      }
+
+      explanations.push(hint.explanation);
+    }
+
+    assert!(puzzle.is_solved());
+    assert!(explanations.contains(&"column clue 13 over 2 cells at row 4, column 8 must be {4,9}, leaving 9 for row 6, column 8".to_string()));
+    assert!(explanations.contains(&"row 6, column 9 can only be {1,2,3} where the column clue 14 over 3 cells at row 5, column 9 crosses the row clue 21 over 4 cells at row 6, column 6, so row 6, column 9 cannot be {4,6,8}".to_string()));
+  }
+
+  #[test]
+  fn test_next_hint_with_player_values() {
+    let mut puzzle = Puzzle::load(&write_puzzle("hint.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n")).unwrap();
+
+    // only the value is set, the hint works out the candidates it leaves for the other cells
+    puzzle[CellId(0)].value = 1;
+    puzzle[CellId(1)].value = 2;
+    puzzle[CellId(2)].value = 3;
+
+    let hint = puzzle.next_hint().unwrap();
+    assert_eq!(hint.action, HintAction::RemoveCombinations { run: RunId(1), combinations: vec![digits(&[1, 5])] });
+    assert_eq!(hint.explanation, "column clue 6 over 2 cells at row 1, column 3 already has {2}, so it cannot be {1,5}");
+
+    // two equal digits in a run break the rules, so there is nothing to deduce
+    puzzle[CellId(1)].value = 1;
+    assert_eq!(puzzle.next_hint(), None);
+
+    puzzle[CellId(1)].value = 2;
+    puzzle[CellId(3)].value = 4;
+    assert_eq!(puzzle.next_hint(), None);
+  }
+
+  #[test]
+  fn test_propagate_solves_easy_input_files() {
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt"] {
+      let mut puzzle = Puzzle::load(&puzzle_path(file_name)).unwrap();
+
+      assert!(propagate(&mut puzzle), "{} hit a contradiction", file_name);
+      assert!(is_solved(&puzzle), "{} was not solved by propagation", file_name);
+      assert!(puzzle.parents.iter().all(|parent| parent.combinations.len() == 1));
+    }
+  }
+
+  #[test]
+  fn test_propagate_keeps_solution_with_input_file_4() {
+    let puzzle_file = puzzle_path("Kakuro_input4.txt");
+
+    let mut puzzle = Puzzle::load(&puzzle_file).unwrap();
+
+    let mut solved = puzzle.clone();
+    assert!(solve(&mut solved));
+
+    let combinations_before: usize = puzzle.parents.iter().map(|parent| parent.combinations.len()).sum();
+    assert!(propagate(&mut puzzle));
+    let combinations_after: usize = puzzle.parents.iter().map(|parent| parent.combinations.len()).sum();
+
+    assert!(combinations_after < combinations_before);
+
+    // propagation may only remove values that are not part of the solution
+    for (child, solved_child) in puzzle.children.iter().zip(solved.children.iter()) {
+      assert!(child.possible_values.contains(solved_child.value));
+      assert!(child.value == 0 || child.value == solved_child.value);
+    }
+
+    for (parent, solved_parent) in puzzle.parents.iter().zip(solved.parents.iter()) {
+      let values: Vec<u8> = solved_parent.children.iter().map(|&id| solved[id].value).collect();
+      assert!(parent.combinations.iter().any(|combo| values.iter().all(|&value| combo.contains(value))));
+    }
+  }
+
+  #[test]
+  fn test_combinations_match_combinations_file() {
+    let file = include_str!("../combinations/Kakuro_combinations.txt");
+    let mut count = 0;
+
+    for line in file.lines().filter(|line| !line.trim().is_empty()) {
+      let mut elements = line.split(' ');
+      let mut value_size = elements.next().unwrap().split('-');
+      let sum: u8 = value_size.next().unwrap().parse().unwrap();
+      let length: usize = value_size.next().unwrap().parse().unwrap();
+      let values: Vec<u8> = elements.next().unwrap()
+        .trim_matches(&['[', ']'][..])
+        .split(',')
+        .map(|value| value.trim().parse().unwrap())
+        .collect();
+
+      assert!(combinations(sum, length).contains(&digits(&values)), "{} is missing from the table", line);
+      count += 1;
+    }
+
+    // the file and the table have to hold exactly the same combinations
+    let table_count: usize = (2..=9).flat_map(|length| (1..=45).map(move |sum| combinations(sum, length).len())).sum();
+    assert_eq!(table_count, count);
+  }
+
+  #[test]
+  fn test_combinations() {
+    assert_eq!(combinations(3, 2), &[digits(&[1, 2])]);
+    assert_eq!(combinations(17, 2), &[digits(&[8, 9])]);
+    assert_eq!(combinations(10, 3), &[digits(&[1, 2, 7]), digits(&[1, 3, 6]), digits(&[1, 4, 5]), digits(&[2, 3, 5])]);
+    assert_eq!(combinations(45, 9), &[digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9])]);
+    assert!(combinations(3, 3).is_empty());
+    assert!(combinations(17, 0).is_empty());
+    assert!(combinations(5, 1).is_empty());
+  }
+
+  /// Sets up a puzzle from the given contents and returns the line, column, token and reason of the parse error
+  fn parse_error(file_name: &str, contents: &str) -> (usize, usize, String, ParseReason) {
+    match Puzzle::load(&write_puzzle(file_name, contents)) {
+      Err(ParseError::Invalid { line, column, token, reason }) => (line, column, token, reason),
+      other => panic!("expected a parse error, got {:?}", other)
+    }
+  }
+
+  #[test]
+  fn test_digit_set() {
+    let low = digits(&[1, 2, 3, 4]);
+    let even = digits(&[2, 4, 6, 8]);
+
+    assert_eq!(low | even, digits(&[1, 2, 3, 4, 6, 8]));
+    assert_eq!(low & even, digits(&[2, 4]));
+    assert_eq!(low - even, digits(&[1, 3]));
+    assert_eq!((low | even).len(), 6);
+    assert_eq!(low.sum(), 10);
+    assert_eq!(low.iter().collect::<Vec<u8>>(), vec![1, 2, 3, 4]);
+    assert_eq!(digits(&[7]).single_value(), Some(7));
+    assert_eq!(low.single_value(), None);
+    assert!(DigitSet::EMPTY.is_empty() && DigitSet::ALL.len() == 9);
+    assert!(!low.contains(0) && !DigitSet::ALL.contains(10));
+    assert!(digits(&[2, 4]).is_subset(low));
+    assert_eq!(digits(&[7, 9]).to_string(), "{7,9}");
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert_eq!(parse_error("unknown_character.txt", "-\\\\ 4\\- 6\\-\n\\xy -\\3\n\\xx -\\7\n"), (2, 3, "y".to_string(), ParseReason::UnknownCellCharacter));
+    assert_eq!(parse_error("missing_clue.txt", "-\\\\ 4\\-\n\\xx -\\3\n\\xx -\\7\n"), (1, 3, "\\".to_string(), ParseReason::MissingClue));
+    assert_eq!(parse_error("non_numeric_clue.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\three\n\\xx -\\7\n"), (2, 7, "three".to_string(), ParseReason::NonNumericClue));
+    assert_eq!(parse_error("clue_out_of_range.txt", "-\\\\ 400\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n"), (1, 5, "400".to_string(), ParseReason::ClueOutOfRange));
+    assert_eq!(parse_error("clue_count_mismatch.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3 -\\5\n\\xx -\\7\n"), (2, 9, "-\\5".to_string(), ParseReason::ClueCountMismatch { cells: 1, clues: 2 }));
+    assert_eq!(parse_error("ragged_row.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\x -\\7\n"), (3, 1, "\\x".to_string(), ParseReason::RaggedRow { expected: 3, found: 2 }));
+  }
+
+  #[test]
+  fn test_parse_error_message() {
+    let error = Puzzle::load(&write_puzzle("message.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3a\n")).unwrap_err();
+
+    assert_eq!(error.to_string(), "line 2, column 7: clue is not a number or - at '3a'");
+  }
+
+  /// Sets up a puzzle from the given contents and returns the validation errors it has
+  fn validation_errors(file_name: &str, contents: &str) -> Vec<ValidationError> {
+    match Puzzle::load(&write_puzzle(file_name, contents)) {
+      Err(ParseError::Validation(errors)) => errors,
+      other => panic!("expected validation errors, got {:?}", other)
+    }
+  }
+
+  #[test]
+  fn test_validation_errors() {
+    assert_eq!(validation_errors("empty_run.txt", "-\\\\\\ 4\\- 6\\- 17\\-\n\\xx- -\\3\n\\xx- -\\7\n"), vec![
+      ValidationError::EmptyRun { row: 0, column: 3, direction: Direction::Down, sum: 17 }
+    ]);
+
+    assert_eq!(validation_errors("impossible_sum.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\2\n\\xx -\\7\n"), vec![
+      ValidationError::ImpossibleSum { row: 1, column: 0, direction: Direction::Across, sum: 2, length: 2 }
+    ]);
+
+    assert_eq!(validation_errors("uncovered_cell.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\nxxx\n"), vec![
+      ValidationError::UncoveredCell { row: 2, column: 0, direction: Direction::Down },
+      ValidationError::UncluedEdgeCell { row: 2, column: 0, direction: Direction::Across },
+      ValidationError::UncoveredCell { row: 2, column: 1, direction: Direction::Across },
+      ValidationError::UncoveredCell { row: 2, column: 2, direction: Direction::Across }
+    ]);
+
+    let run_too_long = validation_errors("run_too_long.txt", "-\\\\\\\\\\\\\\\\\\\\ 1\\- 2\\- 3\\- 4\\- 5\\- 6\\- 7\\- 8\\- 9\\- 10\\-\n\\xxxxxxxxxx -\\45\n");
+    assert!(run_too_long.contains(&ValidationError::RunTooLong { row: 1, column: 0, direction: Direction::Across, sum: 45, length: 10 }));
+  }
+
+  #[test]
+  fn test_validation_no_white_cells() {
+    assert_eq!(validation_errors("no_rows.txt", ""), vec![ValidationError::NoWhiteCells]);
+    assert_eq!(validation_errors("all_black.txt", "---\n---\n"), vec![ValidationError::NoWhiteCells]);
+  }
+
+  #[test]
+  fn test_validation_error_message() {
+    let error = Puzzle::load(&write_puzzle("validation_message.txt", "x\\ 3\\-\nxx\n")).unwrap_err();
+
+    assert_eq!(error.to_string(), "puzzle is not valid:\n  row 1, column 2: down clue 3 cannot be made from 1 unique digits\n  row 1, column 1: white cell on the edge of the grid has no down clue\n  row 1, column 1: white cell on the edge of the grid has no across clue\n  row 2, column 1: white cell is not part of any down run\n  row 2, column 1: white cell on the edge of the grid has no across clue\n  row 2, column 2: white cell is not part of any across run");
+  }
+
+  #[test]
+  fn test_render() {
+    let mut puzzle = Puzzle::load(&write_puzzle("render.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n")).unwrap();
+
+    assert_eq!(render(&puzzle, false), "\
++-----+-----+-----+
+|#####| 4\\  | 6\\  |
++-----+-----+-----+
+|  \\3 |     |     |
++-----+-----+-----+
+|  \\7 |     |     |
++-----+-----+-----+
+");
+
+    assert_eq!(render(&puzzle, true), "\
++-----+-----+-----+
+|#####|     |     |
+|#####| 4\\  | 6\\  |
+|#####|     |     |
++-----+-----+-----+
+|     |1    |1 2  |
+|  \\3 |     |     |
+|     |     |     |
++-----+-----+-----+
+|     |1   3|1 2  |
+|  \\7 |     |4 5  |
+|     |     |     |
++-----+-----+-----+
+");
+
+    assert!(solve(&mut puzzle));
+
+    assert_eq!(render(&puzzle, true), "\
++-----+-----+-----+
+|#####|     |     |
+|#####| 4\\  | 6\\  |
+|#####|     |     |
++-----+-----+-----+
+|     |     |     |
+|  \\3 |  1  |  2  |
+|     |     |     |
++-----+-----+-----+
+|     |     |     |
+|  \\7 |  3  |  4  |
+|     |     |     |
++-----+-----+-----+
+");
+
+    // a front-end can list its own candidates and mark white cells, the marks are drawn inside the cell's borders
+    puzzle[CellId(3)].value = 0;
+    let marks = |id: CellId| if id == CellId(3) { digits(&[9]) } else { DigitSet::EMPTY };
+    let drawn = render_with(&puzzle, Some(&marks), &|id, text| if id == CellId(0) { format!("[{}]", text.trim()) } else { text });
+
+    assert!(drawn.contains("|  \\3 |[1]|  2  |\n"));
+    assert!(drawn.contains("|     |     |    9|\n"));
+  }
+
+  #[test]
+  fn test_puzzle_geometry() {
+    let puzzle = Puzzle::load(&puzzle_path("Kakuro_input.txt")).unwrap();
+
+    assert_eq!((puzzle.width, puzzle.height), (10, 12));
+    assert_eq!(puzzle.grid.len(), puzzle.height);
+    assert!(puzzle.grid.iter().all(|row| row.len() == puzzle.width));
+
+    // every child sits in the grid cell that points back to it
+    for id in puzzle.cell_ids() {
+      let (row, column) = puzzle[id].position;
+      assert_eq!(puzzle.grid[row][column].child, Some(id));
+    }
+
+    // every run starts right after its clue and heads off in its direction
+    for id in puzzle.run_ids() {
+      let parent = &puzzle[id];
+      let (row, column) = parent.head;
+      let cell = &puzzle.grid[row][column];
+      let first = puzzle[parent.children[0]].position;
+
+      match parent.direction {
+        Direction::Down => {
+          assert_eq!(cell.vert, Some(id));
+          assert_eq!(first, (row + 1, column));
+        },
+        Direction::Across => {
+          assert_eq!(cell.horz, Some(id));
+          assert_eq!(first, (row, column + 1));
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn test_puzzle_api() {
+    let mut puzzle = Puzzle::load(&puzzle_path("Kakuro_input2.txt")).unwrap();
+
+    assert!(puzzle.validate().is_empty());
+    assert!(puzzle.render(false).contains("|     |"));
+    assert!(puzzle.solve());
+    assert!(puzzle.is_solved());
+    assert_eq!(puzzle.find_solutions(2).len(), 1);
+    assert!(!puzzle.render(false).contains("|     |"));
+  }
+
+  #[test]
+  fn test_parse_matches_load() {
+    let puzzle_file = puzzle_path("Kakuro_input3.txt");
+    let text = fs::read_to_string(&puzzle_file).unwrap();
+
+    assert_eq!(Puzzle::parse(&text).unwrap(), Puzzle::load(&puzzle_file).unwrap());
+    assert!(matches!(Puzzle::parse("-\\\\ 4\\- 6\\-\n\\xq -\\3\n"), Err(ParseError::Invalid { line: 2, .. })));
+  }
+
+  #[test]
+  fn test_generate() {
+    for seed in 0..5 {
+      let options = GenerateOptions { width: 8, height: 7, seed, symmetric: seed % 2 == 0 };
+      let text = generate(&options).unwrap();
+      let puzzle = Puzzle::parse(&text).unwrap();
+
+      // the same options always give the same puzzle
+      assert_eq!(generate(&options).unwrap(), text);
+
+      assert_eq!((puzzle.width, puzzle.height), (8, 7));
+      assert!(puzzle.count_solutions(2).is_unique());
+      assert!(puzzle.parents.iter().all(|parent| parent.children.len() >= 2));
+
+      if options.symmetric {
+        for row in 1..puzzle.height {
+          for column in 1..puzzle.width {
+            let partner = &puzzle.grid[puzzle.height - row][puzzle.width - column];
+            assert_eq!(puzzle.grid[row][column].child.is_some(), partner.child.is_some());
+          }
+        }
+      }
+    }
+
+    assert_eq!(generate(&GenerateOptions { width: 2, height: 10, seed: 0, symmetric: false }), Err(GenerateError::InvalidSize { width: 2, height: 10 }));
+  }
+
+  #[test]
+  fn test_to_text_round_trip() {
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"] {
+      let puzzle_file = puzzle_path(file_name);
+      let puzzle = Puzzle::load(&puzzle_file).unwrap();
+      let text = puzzle.to_text();
+
+      // the input files are written the same way, apart from trailing spaces
+      let original: Vec<String> = fs::read_to_string(&puzzle_file).unwrap().lines().map(|line| line.trim_end().to_string()).collect();
+      assert_eq!(text.lines().collect::<Vec<_>>(), original, "{}", file_name);
+
+      assert_eq!(Puzzle::parse(&text).unwrap(), puzzle);
+    }
+
+    // clue cells with a run in only one direction keep their - placeholder
+    let text = "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n";
+    let puzzle = Puzzle::parse(text).unwrap();
+    assert_eq!(puzzle.to_text(), text);
+
+    let puzzle_file = std::env::temp_dir().join("kakuro_saved.txt");
+    puzzle.save(&puzzle_file).unwrap();
+    assert_eq!(Puzzle::load(&puzzle_file).unwrap(), puzzle);
+  }
+
+  #[test]
+  fn test_json_round_trip() {
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"] {
+      let puzzle = Puzzle::load(&puzzle_path(file_name)).unwrap();
+      let mut solution = puzzle.clone();
+      assert!(solution.solve());
+
+      let read = read_json(&write_json(&puzzle, Some(&solution), false)).unwrap();
+      let values = |puzzle: &Puzzle| puzzle.children.iter().map(|child| child.value).collect::<Vec<_>>();
+
+      assert_eq!(read.puzzle, puzzle, "{}", file_name);
+      assert_eq!(read.solution.as_ref().map(values), Some(values(&solution)), "{}", file_name);
+    }
+  }
+
+  #[test]
+  fn test_json_state() {
+    let mut puzzle = Puzzle::parse("-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n").unwrap();
+    puzzle[CellId(0)].value = 1;
+    puzzle[CellId(3)].possible_values = digits(&[4]);
+
+    let text = write_json(&puzzle, None, true);
+
+    assert_eq!(text, r#"{
+  "width": 3,
+  "height": 3,
+  "clues": [
+    {"row": 0, "column": 1, "down": 4},
+    {"row": 0, "column": 2, "down": 6},
+    {"row": 1, "column": 0, "across": 3},
+    {"row": 2, "column": 0, "across": 7}
+  ],
+  "cells": [
+    {"row": 1, "column": 1},
+    {"row": 1, "column": 2},
+    {"row": 2, "column": 1},
+    {"row": 2, "column": 2}
+  ],
+  "state": {
+    "values": [1, 0, 0, 0],
+    "candidates": [[1], [1, 2], [1, 3], [4]]
+  }
+}
+"#);
+
+    let read = read_json(&text).unwrap();
+    assert_eq!(read.puzzle, puzzle);
+    assert_eq!(read.solution, None);
+
+    // the cells can be listed in any order, the digits follow the order of the cells
+    let shuffled = r#"{"width": 3, "height": 3, "clues": [{"row": 0, "column": 1, "down": 4, "across": null}, {"row": 0, "column": 2, "down": 6},
+      {"row": 1, "column": 0, "across": 3}, {"row": 2, "column": 0, "across": 7}],
+      "cells": [{"row": 2, "column": 2}, {"row": 1, "column": 1}, {"row": 1, "column": 2}, {"row": 2, "column": 1}],
+      "solution": [4, 1, 2, 3]}"#;
+    let solution = read_json(shuffled).unwrap().solution.unwrap();
+    assert_eq!(solution.children.iter().map(|child| child.value).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+  }
+
+  #[test]
+  fn test_json_errors() {
+    let error = |text: &str| read_json(text).unwrap_err().to_string();
+    let puzzle = |cells: &str, extra: &str| format!(r#"{{"width": 3, "height": 3, "clues": [{{"row": 0, "column": 1, "down": 4}}, {{"row": 0, "column": 2, "down": 6}}, {{"row": 1, "column": 0, "across": 3}}, {{"row": 2, "column": 0, "across": 7}}], "cells": [{}]{}}}"#, cells, extra);
+    let cells = r#"{"row": 1, "column": 1}, {"row": 1, "column": 2}, {"row": 2, "column": 1}, {"row": 2, "column": 2}"#;
+
+    assert!(matches!(read_json("{\"width\": 3,\n  \"height\": }"), Err(JsonError::Syntax { line: 2, column: 13, .. })));
+    assert_eq!(error("[1, 2"), "line 1, column 6: expected , or ]");
+    assert_eq!(error("{\"a\": 01}"), "line 1, column 7: invalid number");
+    assert_eq!(error("{} {}"), "line 1, column 4: unexpected text after the value");
+    assert_eq!(error("\"\\q\""), "line 1, column 3: invalid escape");
+
+    // deep nesting is rejected at the limit instead of overflowing the stack
+    assert_eq!(error(&"[".repeat(200_000)), "line 1, column 65: arrays and objects are nested too deeply");
+    assert_eq!(error(&format!("{}{}", "{\"a\": ".repeat(65), "1")), "line 1, column 385: arrays and objects are nested too deeply");
+    assert!(parse(&format!("{}{}", "[".repeat(64), "]".repeat(64))).is_ok());
+
+    assert_eq!(error("{}"), "width: missing");
+    assert_eq!(error(&puzzle(r#"{"row": 1, "column": 3}"#, "")), "cells[0].column: expected a whole number from 0 to 2");
+    assert_eq!(error(&puzzle(r#"{"row": 1, "column": 1.5}"#, "")), "cells[0].column: expected a whole number from 0 to 2");
+    assert_eq!(error(&puzzle(r#"{"row": 0, "column": 1}"#, "")), "cells[0]: another clue or cell is already at this position");
+    assert_eq!(error(&puzzle(cells, r#", "solution": [1, 2, 3]"#)), "solution: expected 4 digits, one for every white cell, found 3");
+    assert_eq!(error(&puzzle(cells, r#", "solution": [2, 1, 3, 4]"#)), "solution: the digits do not solve the puzzle");
+    assert_eq!(error(&puzzle(cells, r#", "state": {"candidates": [[1], [2], [0], [4]]}"#)), "state.candidates[2][0]: expected a digit from 1 to 9");
+    assert!(matches!(read_json(&puzzle(r#"{"row": 1, "column": 1}"#, "")), Err(JsonError::Validation(_))));
+
+    assert!(matches!(Puzzle::load_json(&puzzle_path("missing.json")), Err(JsonError::Io(_))));
+  }
+
+  #[test]
+  fn test_grid_format() {
+    let text = "##   4\\  6\\\n\\3   .    .\n\n\\7   .    4\n";
+    let puzzle = Puzzle::parse(text).unwrap();
+    let original = Puzzle::parse("-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n").unwrap();
+
+    // the same puzzle as the original format, apart from the filled in cell
+    assert_eq!(puzzle.parents, original.parents);
+    assert_eq!((puzzle.width, puzzle.height, &puzzle.grid), (original.width, original.height, &original.grid));
+    assert_eq!((puzzle[CellId(3)].value, puzzle[CellId(3)].possible_values), (4, digits(&[4])));
+    assert_eq!(puzzle.to_grid_text(), "## 4\\ 6\\\n\\3 .  .\n\\7 .  4\n");
+
+    let mut solution = puzzle.clone();
+    assert!(solution.solve());
+    assert_eq!(solution.children.iter().map(|child| child.value).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+    // every input file reads back the same from the grid format
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"] {
+      let puzzle = Puzzle::load(&puzzle_path(file_name)).unwrap();
+      assert_eq!(Puzzle::parse(&puzzle.to_grid_text()).unwrap(), puzzle, "{}", file_name);
+    }
+  }
+
+  #[test]
+  fn test_grid_format_errors() {
+    let error = |contents: &str| match Puzzle::parse(contents) {
+      Err(ParseError::Invalid { line, column, token, reason }) => (line, column, token, reason),
+      other => panic!("expected a parse error, got {:?}", other)
+    };
+
+    assert_eq!(error("##  4\\  6\\\n\\3  .   ?\n"), (2, 9, "?".to_string(), ParseReason::UnknownGridCell));
+    assert_eq!(error("##  4\\  6\\\n\\3  .   0\n"), (2, 9, "0".to_string(), ParseReason::UnknownGridCell));
+    assert_eq!(error("##  4\\  6\\\n\\three  .   .\n"), (2, 2, "three".to_string(), ParseReason::NonNumericClue));
+    assert_eq!(error("##  4\\  6\\400\n"), (1, 11, "400".to_string(), ParseReason::ClueOutOfRange));
+    assert_eq!(error("##  4\\  6\\\n\\3  .\n"), (2, 1, "\\3  .".to_string(), ParseReason::RaggedRow { expected: 3, found: 2 }));
+    assert!(matches!(Puzzle::parse("##  4\\  6\\\n\\3  .   .\n"), Err(ParseError::Validation(_))));
+  }
+
+  #[test]
+  fn test_svg() {
+    let puzzle = Puzzle::parse("##   4\\  6\\\n\\3   .    .\n\\7   .    4\n").unwrap();
+    let svg = puzzle.to_svg(&SvgOptions { cell_size: 10, show_values: true, show_candidates: true });
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"30\" viewBox=\"0 0 30 30\">\n"));
+    assert!(svg.ends_with("</svg>\n"));
+
+    // down sums go below the diagonal of their cell and across sums above it
+    assert!(svg.contains("<line x1=\"10\" y1=\"0\" x2=\"20\" y2=\"10\" stroke=\"#fff\"/>"));
+    assert!(svg.contains("<text x=\"13\" y=\"7.2\" font-size=\"3\" fill=\"#fff\">4</text>"));
+    assert!(svg.contains("<text x=\"7\" y=\"13\" font-size=\"3\" fill=\"#fff\">3</text>"));
+
+    // the filled in cell shows its value and the empty cells their candidates
+    assert!(svg.contains("<text x=\"25\" y=\"25\" font-size=\"6\" fill=\"#000\">4</text>"));
+    assert!(svg.contains("<text x=\"18.3\" y=\"21.7\" font-size=\"2.2\" fill=\"#555\">3</text>"));
+    assert_eq!(svg.matches("fill=\"#555\"").count(), 5);
+
+    let plain = puzzle.to_svg(&SvgOptions { cell_size: 10, show_values: false, show_candidates: false });
+    assert_eq!(plain.matches("<text").count(), 4);
+
+    // every input file is drawn with one square per cell at any size, along with its solution
+    for file_name in ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"] {
+      let mut puzzle = Puzzle::load(&puzzle_path(file_name)).unwrap();
+      assert!(puzzle.solve());
+
+      let svg = puzzle.to_svg(&SvgOptions { cell_size: 25, ..SvgOptions::default() });
+      let clues = puzzle.grid.iter().flatten().filter(|cell| cell.child.is_none() && !cell.is_blank()).count();
+
+      assert!(svg.contains(&format!("width=\"{}\" height=\"{}\"", puzzle.width * 25, puzzle.height * 25)), "{}", file_name);
+      assert_eq!(svg.matches("<rect").count(), puzzle.width * puzzle.height + 1, "{}", file_name);
+      assert_eq!(svg.matches("<text").count(), puzzle.parents.len() + puzzle.children.len(), "{}", file_name);
+      assert_eq!(svg.matches("<line").count(), clues, "{}", file_name);
+    }
+  }
+
+  #[test]
+  fn test_html() {
+    let puzzle = Puzzle::parse("##   4\\  6\\\n\\3   .    .\n\\7   .    4\n").unwrap();
+    let mut solution = puzzle.clone();
+    assert!(solution.solve());
+
+    let html = puzzle.to_html(&solution);
+    assert!(html.starts_with("<!DOCTYPE html>\n"));
+    assert!(html.ends_with("</html>\n"));
+
+    // the embedded data follows the runs and white cells of the puzzle
+    let start = html.find("<script type=\"application/json\" id=\"puzzle\">").unwrap();
+    let data = &html[start..];
+    let data = parse(&data[data.find('\n').unwrap()..data.find("</script>").unwrap()]).unwrap();
+    let numbers = |json: &Json| match json {
+      Json::Array(items) => items.iter().map(|item| match item { Json::Number(number) => *number as usize, _ => panic!("expected a number") }).collect::<Vec<_>>(),
+      _ => panic!("expected an array")
+    };
+
+    assert_eq!(data.get("solution").map(numbers), Some(vec![1, 2, 3, 4]));
+
+    let Some(Json::Array(runs)) = data.get("runs") else { panic!("expected the runs") };
+    assert_eq!(runs.len(), puzzle.parents.len());
+
+    for (run, parent) in runs.iter().zip(&puzzle.parents) {
+      assert_eq!(run.get("sum"), Some(&Json::Number(parent.sum as f64)));
+      assert_eq!(run.get("cells").map(numbers), Some(parent.children.iter().map(|id| id.0).collect()));
+    }
+
+    let Some(Json::Array(cells)) = data.get("cells") else { panic!("expected the cells") };
+    assert_eq!(cells.iter().map(|cell| cell.get("given").cloned()).collect::<Vec<_>>(), [0.0, 0.0, 0.0, 4.0].map(|given| Some(Json::Number(given))));
+    assert_eq!(cells[2].get("runs").map(numbers), Some(vec![0, 3]));
+
+    let Some(Json::Array(clues)) = data.get("clues") else { panic!("expected the clues") };
+    assert_eq!(clues.len(), 4);
+    assert_eq!(clues[2].get("across"), Some(&Json::Number(3.0)));
+    assert_eq!(clues[2].get("down"), None);
+  }
+
+  #[test]
+  fn test_game() {
+    let mut game = Game::new(Puzzle::parse("##   4\\  6\\\n\\3   .    .\n\\7   .    4\n").unwrap());
+    let values = |game: &Game| game.puzzle().children.iter().map(|child| child.value).collect::<Vec<_>>();
+
+    assert!(game.is_given(CellId(3)));
+    assert!(!game.set_value(CellId(3), 1));
+    assert!(game.set_value(CellId(0), 2));
+    assert!(game.set_value(CellId(1), 2));
+
+    // a repeated digit conflicts with its sibling, and a run that is full or over its sum without adding up to it
+    // conflicts with every filled in cell of the run
+    assert_eq!(game.conflicts(), cells(&[0, 1]));
+    assert!(game.set_value(CellId(1), 1));
+    assert_eq!(game.conflicts(), cells(&[1, 3]));
+    assert!(game.set_value(CellId(2), 3));
+    assert_eq!(game.conflicts(), cells(&[0, 1, 2, 3]));
+
+    // pencil marks are kept apart from the value and changes to them can be undone too
+    assert!(game.toggle_mark(CellId(2), 5));
+    assert!(game.toggle_mark(CellId(2), 7));
+    assert!(game.toggle_mark(CellId(2), 5));
+    assert_eq!(game.marks(CellId(2)), digits(&[7]));
+
+    assert_eq!(game.undo(), Some(CellId(2)));
+    assert_eq!(game.marks(CellId(2)), digits(&[5, 7]));
+    assert_eq!(game.undo(), Some(CellId(2)));
+    assert_eq!(game.undo(), Some(CellId(2)));
+    assert_eq!(game.undo(), Some(CellId(2)));
+    assert_eq!((values(&game), game.marks(CellId(2))), (vec![2, 1, 0, 4], DigitSet::EMPTY));
+    assert_eq!(game.redo(), Some(CellId(2)));
+    assert_eq!(values(&game), vec![2, 1, 3, 4]);
+
+    // a new change forgets what was undone
+    assert_eq!(game.undo(), Some(CellId(2)));
+    assert!(game.set_value(CellId(0), 0));
+    assert_eq!(game.redo(), None);
+
+    // the hint works from the player's values, and has nothing to say while they break the rules
+    assert_eq!(game.hint(), None);
+    assert!(game.set_value(CellId(1), 2));
+    assert_eq!(game.hint().map(|hint| hint.action), Some(HintAction::Place { cell: CellId(0), digit: 1 }));
+
+    assert!(game.reveal(CellId(0)) && game.reveal(CellId(2)));
+    assert!(!game.reveal(CellId(1)) && !game.reveal(CellId(3)));
+    assert_eq!(values(&game), vec![1, 2, 3, 4]);
+    assert!(game.is_solved() && game.conflicts().is_empty());
+
+    while game.undo().is_some() {}
+    assert_eq!(values(&game), vec![0, 0, 0, 4]);
+
+    let mut unsolvable = Game::new(Puzzle::parse("-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n").unwrap());
+    assert!(unsolvable.solution().is_none());
+    assert!(!unsolvable.reveal(CellId(0)));
+  }
+
+  #[test]
+  fn test_solve_traced() {
+    let mut puzzle = Puzzle::parse("-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n").unwrap();
+    let mut steps = Vec::new();
+
+    assert!(puzzle.solve_traced(&mut |step, _| steps.push(step.clone())));
+    assert_eq!(steps, vec![
+      TraceStep::Combinations { removed: vec![(RunId(3), vec![digits(&[1, 6]), digits(&[2, 5])])] },
+      TraceStep::PossibleValues { shrunk: vec![(CellId(1), digits(&[1, 2]), digits(&[2])), (CellId(2), digits(&[1, 3]), digits(&[3])), (CellId(3), digits(&[1, 2, 4, 5]), digits(&[4]))] },
+      TraceStep::Combinations { removed: vec![(RunId(1), vec![digits(&[1, 5])])] },
+      TraceStep::Solved
+    ]);
+    assert_eq!(steps[0].describe(&puzzle), "combinations: row clue 7 over 2 cells at row 3, column 1 cannot be {1,6} or {2,5}");
+    assert_eq!(steps[1].describe(&puzzle), "possible values: row 2, column 3 {1,2} to {2}; row 3, column 2 {1,3} to {3}; row 3, column 3 {1,2,4,5} to {4}");
+
+    // input file 4 needs a guess, every guess that is taken back is followed by the next value of the same cell
+    let original = Puzzle::load(&puzzle_path("Kakuro_input4.txt")).unwrap();
+    let mut traced = original.clone();
+    let mut steps = Vec::new();
+
+    assert!(traced.solve_traced(&mut |step, state| steps.push((step.clone(), state.clone()))));
+
+    let mut solved = original.clone();
+    assert!(solved.solve());
+    assert_eq!(traced, solved);
+
+    let guesses = steps.iter().filter(|(step, _)| matches!(step, TraceStep::Guess { .. })).count();
+    let backtracks = steps.iter().filter(|(step, _)| matches!(step, TraceStep::Backtrack { .. })).count();
+    assert!(guesses > 0 && guesses > backtracks);
+    assert_eq!(steps.last().map(|(step, state)| (step, state == &solved)), Some((&TraceStep::Solved, true)));
+
+    for (index, (step, state)) in steps.iter().enumerate() {
+      match step {
+        TraceStep::Guess { cell, value, options } => {
+          assert!(options.contains(*value));
+          assert_eq!(state[*cell].value, *value);
+        },
+        TraceStep::Backtrack { cell, value } => {
+          assert!(matches!(steps[index - 1].0, TraceStep::Contradiction | TraceStep::Backtrack { .. }));
+          assert_eq!(state[*cell].value, 0);
+          assert!(!matches!(steps.get(index + 1), Some((TraceStep::Guess { cell: next, value: next_value, .. }, _)) if next == cell && next_value <= value));
+        },
+        _ => {}
+      }
+    }
+  }
+
+  #[test]
+  fn test_solve_all() {
+    let mut files: Vec<PathBuf> = ["Kakuro_input.txt", "Kakuro_input2.txt", "Kakuro_input3.txt", "Kakuro_input4.txt", "Kakuro_input5.txt"].iter().map(|name| puzzle_path(name)).collect();
+    files.push(write_puzzle("batch_unsolvable.txt", "-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n"));
+    files.push(write_puzzle("batch_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n"));
+    files.push(write_puzzle("batch_invalid.txt", "-\\\\ 4\\-\n\\xx -\\3\n"));
+    files.push(puzzle_path("missing.txt"));
+
+    let results = solve_all(&files, 4);
+    let summary: Vec<_> = results.iter().map(|result| (result.file.clone(), result.status.to_string(), result.runs, result.cells)).collect();
+
+    assert_eq!(summary[0], (files[0].clone(), "solved".to_string(), 48, 72));
+    assert_eq!(summary[5..].iter().map(|(file, status, _, _)| (file, status.as_str())).collect::<Vec<_>>(), vec![
+      (&files[5], "unsolved"), (&files[6], "multiple"), (&files[7], "error"), (&files[8], "error")
+    ]);
+
+    for (result, file) in results.iter().zip(&files).take(5) {
+      let puzzle = Puzzle::load(file).unwrap();
+      assert_eq!((result.width, result.height, result.runs, result.cells), (puzzle.width, puzzle.height, puzzle.parents.len(), puzzle.children.len()));
+      assert_eq!(result.difficulty, puzzle.rate());
+    }
+
+    assert!(results[5].difficulty.is_none() && results[6].difficulty.is_none());
+
+    // the results do not depend on how many threads solve them
+    let one_thread: Vec<_> = solve_all(&files, 1).into_iter().map(|result| BatchResult { time: Duration::ZERO, ..result }).collect();
+    let many_threads: Vec<_> = results.into_iter().map(|result| BatchResult { time: Duration::ZERO, ..result }).collect();
+    assert_eq!(one_thread, many_threads);
+    assert!(solve_all(&[], 4).is_empty());
+
+    let error = BatchResult { file: PathBuf::from("a, \"b\".txt"), width: 0, height: 0, runs: 0, cells: 0, status: BatchStatus::Error("line 1: bad".to_string()), time: Duration::from_micros(1500), difficulty: None };
+    assert_eq!(write_csv(&[many_threads[0].clone(), error]), "\
+file,width,height,runs,cells,status,milliseconds,grade,score,error
+Kakuro_input.txt,10,12,48,72,solved,0,hard,7100,
+\"a, \"\"b\"\".txt\",,,,,error,1.5,,,line 1: bad
+");
+  }
+}
@@ -0,0 +1,300 @@
+//! The interactive terminal UI of the play command, drawn with ANSI escape codes
+//!
+//! The terminal is put into raw mode with stty so each key press is read as it happens, when the input is not
+//! a terminal the keys are read from it as they come, which is how the UI is tested.
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::iter::Peekable;
+use std::process::{Command, Stdio};
+
+use kakuro::hints::HintAction;
+use kakuro::play::Game;
+use kakuro::render::render_with;
+use kakuro::{CellId, Puzzle};
+
+/// The keys the UI is driven by, listed under the grid
+const HELP: &str = "arrows move  1-9 fill in  0 empty  p pencil marks  u undo  r redo  ? hint  v reveal  q quit";
+
+/// Clears the screen and moves to its top left corner
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// A key the player pressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+  Move(isize, isize),
+  Digit(u8),
+  Pencil,
+  Undo,
+  Redo,
+  Hint,
+  Reveal,
+  Quit,
+  Other
+}
+
+/// The settings of the terminal before it was put into raw mode, which are put back when this is dropped
+struct RawMode {
+  saved: String
+}
+
+impl RawMode {
+  /// Puts the terminal into raw mode, returning None if stty could not be run
+  fn enable() -> Option<RawMode> {
+    let output = Command::new("stty").arg("-g").stdin(Stdio::inherit()).output().ok().filter(|output| output.status.success())?;
+    let saved = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    let status = Command::new("stty").args(["raw", "-echo"]).stdin(Stdio::inherit()).status().ok()?;
+
+    status.success().then_some(RawMode { saved })
+  }
+}
+
+impl Drop for RawMode {
+  fn drop(&mut self) {
+    let _ = Command::new("stty").arg(&self.saved).stdin(Stdio::inherit()).status();
+  }
+}
+
+/// Parameters:
+/// - input: The bytes typed by the player
+///
+/// Description:
+/// - Reads the next key, turning the escape codes of the arrow keys and Delete into the keys they stand for
+/// - Returns None once the input has ended
+fn read_key(input: &mut Peekable<impl Iterator<Item = u8>>) -> Option<Key> {
+  let key = match input.next()? {
+    b'\x1b' => {
+      // arrow keys are sent as ESC [ A to ESC [ D and Delete as ESC [ 3 ~, any other byte after ESC is left
+      // to be read as a key of its own
+      if input.next_if_eq(&b'[').is_none() {
+        return Some(Key::Other);
+      }
+
+      match input.next()? {
+        b'A' => Key::Move(-1, 0),
+        b'B' => Key::Move(1, 0),
+        b'C' => Key::Move(0, 1),
+        b'D' => Key::Move(0, -1),
+        b'3' if input.next_if_eq(&b'~').is_some() => Key::Digit(0),
+        _ => Key::Other
+      }
+    },
+    byte @ b'0'..=b'9' => Key::Digit(byte - b'0'),
+    b' ' | b'\x7f' | b'\x08' => Key::Digit(0),
+    b'k' => Key::Move(-1, 0),
+    b'j' => Key::Move(1, 0),
+    b'l' => Key::Move(0, 1),
+    b'h' => Key::Move(0, -1),
+    b'p' => Key::Pencil,
+    b'u' => Key::Undo,
+    b'r' => Key::Redo,
+    b'?' => Key::Hint,
+    b'v' => Key::Reveal,
+    // ctrl-c is read as a byte in raw mode
+    b'q' | b'\x03' => Key::Quit,
+    _ => Key::Other
+  };
+
+  Some(key)
+}
+
+/// The game along with what the UI shows around it
+/// * cursor is the white cell keys are applied to
+/// * pencil is true while digits toggle pencil marks instead of filling in the cell
+/// * message is the line shown under the grid, i.e. the explanation of a hint
+struct Screen {
+  game: Game,
+  cursor: CellId,
+  pencil: bool,
+  message: String
+}
+
+impl Screen {
+  /// Parameters:
+  /// - row_step, column_step: The direction the cursor moves in, one of them is 0
+  ///
+  /// Description:
+  /// - Moves the cursor to the next white cell in the direction, jumping over black cells,
+  ///   and leaves it where it is if there is none
+  fn move_cursor(&mut self, row_step: isize, column_step: isize) {
+    let puzzle = self.game.puzzle();
+    let (mut row, mut column) = puzzle[self.cursor].position;
+
+    loop {
+      let (Some(next_row), Some(next_column)) = (row.checked_add_signed(row_step), column.checked_add_signed(column_step)) else {
+        return;
+      };
+
+      let Some(cell) = puzzle.grid.get(next_row).and_then(|cells| cells.get(next_column)) else {
+        return;
+      };
+
+      if let Some(child) = cell.child {
+        self.cursor = child;
+        return;
+      }
+
+      (row, column) = (next_row, next_column);
+    }
+  }
+
+  /// Shows the easiest deduction the player can make and moves the cursor to the cell it is about
+  fn hint(&mut self) {
+    let Some(hint) = self.game.hint() else {
+      self.message = "no technique can make any progress".to_string();
+      return;
+    };
+
+    self.cursor = match hint.action {
+      HintAction::Place { cell, .. } | HintAction::RemoveCandidates { cell, .. } => cell,
+      HintAction::RemoveCombinations { run, .. } => self.game.puzzle()[run].children[0]
+    };
+
+    self.message = format!("{}: {}", hint.technique, hint.explanation);
+  }
+
+  /// Fills in the cell under the cursor from the solution, explaining why when it cannot
+  fn reveal(&mut self) {
+    if self.game.reveal(self.cursor) {
+      return;
+    }
+
+    self.message = if self.game.solution().is_none() {
+      "the puzzle has no solution to reveal".to_string()
+    } else if self.game.is_given(self.cursor) {
+      "the cell is given".to_string()
+    } else {
+      "the cell is already right".to_string()
+    };
+  }
+
+  /// Parameters:
+  /// - key: The key the player pressed
+  ///
+  /// Description:
+  /// - Applies the key to the game, returning false once the player quits
+  fn handle(&mut self, key: Key) -> bool {
+    self.message.clear();
+
+    match key {
+      Key::Move(row_step, column_step) => self.move_cursor(row_step, column_step),
+      Key::Digit(digit) => {
+        let changed = if self.pencil && digit != 0 {
+          self.game.toggle_mark(self.cursor, digit)
+        } else {
+          self.game.set_value(self.cursor, digit)
+        };
+
+        if !changed && self.game.is_given(self.cursor) {
+          self.message = "the cell is given".to_string();
+        }
+      },
+      Key::Pencil => self.pencil = !self.pencil,
+      Key::Undo => match self.game.undo() {
+        Some(cell) => self.cursor = cell,
+        None => self.message = "nothing to undo".to_string()
+      },
+      Key::Redo => match self.game.redo() {
+        Some(cell) => self.cursor = cell,
+        None => self.message = "nothing to redo".to_string()
+      },
+      Key::Hint => self.hint(),
+      Key::Reveal => self.reveal(),
+      Key::Quit => return false,
+      Key::Other => {}
+    }
+
+    if self.game.is_solved() {
+      self.message = "solved, press q to quit".to_string();
+    }
+
+    true
+  }
+
+  /// Parameters:
+  /// - cell: The white cell being drawn
+  /// - text: One line of the cell, its value or its pencil marks
+  ///
+  /// Description:
+  /// - Returns the line styled to show the cursor, a given value and a value that breaks a rule
+  fn highlight(&self, cell: CellId, text: String) -> String {
+    let mut style = String::new();
+
+    if cell == self.cursor {
+      style.push_str("\x1b[7m");
+    }
+
+    if self.game.is_given(cell) {
+      style.push_str("\x1b[1m");
+    } else if self.game.is_conflicting(cell) {
+      style.push_str("\x1b[31m");
+    }
+
+    if style.is_empty() { text } else { format!("{}{}\x1b[0m", style, text) }
+  }
+
+  /// Returns the whole screen, the grid drawn like the show command with the pencil marks in place of the candidates,
+  /// followed by the mode, the message and the keys
+  /// - lines end in \r\n since raw mode does not move back to the start of the line
+  fn draw(&self) -> String {
+    let marks = |cell: CellId| self.game.marks(cell);
+    let grid = render_with(self.game.puzzle(), Some(&marks), &|cell, text| self.highlight(cell, text));
+    let mut output = format!("{}{}", CLEAR, grid.replace('\n', "\r\n"));
+
+    let mode = if self.pencil { "pencil marks" } else { "values" };
+    output.push_str(&format!("typing {}\r\n{}\r\n{}\r\n", mode, self.message, HELP));
+    output
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being played
+/// - input: Where the key presses are read from
+/// - out: Where the screen is drawn
+///
+/// Description:
+/// - Draws the puzzle and redraws it after every key until the player quits or the input ends
+pub fn play(puzzle: Puzzle, input: impl BufRead, out: &mut dyn Write) -> io::Result<()> {
+  let mut screen = Screen { game: Game::new(puzzle), cursor: CellId(0), pencil: false, message: String::new() };
+  let mut input = input.bytes().map_while(Result::ok).peekable();
+
+  write!(out, "{}", screen.draw())?;
+  out.flush()?;
+
+  while let Some(key) = read_key(&mut input) {
+    if !screen.handle(key) {
+      break;
+    }
+
+    write!(out, "{}", screen.draw())?;
+    out.flush()?;
+  }
+
+  Ok(())
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being played
+/// - out: Where the screen is drawn
+///
+/// Description:
+/// - Plays the puzzle with the keys typed into stdin, switching to the terminal's alternate screen and raw mode
+///   while it runs when stdin is a terminal, the terminal is put back the way it was when the player quits
+pub fn run(puzzle: Puzzle, out: &mut dyn Write) -> io::Result<()> {
+  let stdin = io::stdin();
+
+  if !stdin.is_terminal() {
+    return play(puzzle, stdin.lock(), out);
+  }
+
+  // without stty the keys are still read, just only once Enter is pressed
+  let raw_mode = RawMode::enable();
+
+  // the alternate screen keeps the player's scrollback as it was, and the cursor is hidden since the grid shows its own
+  write!(out, "\x1b[?1049h\x1b[?25l")?;
+  let result = play(puzzle, stdin.lock(), out);
+  write!(out, "\x1b[?25h\x1b[?1049l")?;
+  out.flush()?;
+
+  drop(raw_mode);
+  result
+}
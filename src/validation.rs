@@ -0,0 +1,111 @@
+//! Checks that a puzzle follows the rules of Kakuro
+
+use std::fmt;
+
+use crate::combinations::combinations;
+use crate::{Direction, Puzzle};
+
+/// A problem with a puzzle that reads in fine but cannot be a proper Kakuro
+/// - row and column are the position on the grid starting at 0, they are shown starting at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+  /// A clue is not followed by any white cells
+  EmptyRun { row: usize, column: usize, direction: Direction, sum: u8 },
+  /// A clue is followed by more white cells than there are digits
+  RunTooLong { row: usize, column: usize, direction: Direction, sum: u8, length: usize },
+  /// No combination of unique digits adds up to the clue in the number of white cells it has
+  ImpossibleSum { row: usize, column: usize, direction: Direction, sum: u8, length: usize },
+  /// A white cell on the top or left edge of the grid, which leaves no room for a clue in that direction
+  UncluedEdgeCell { row: usize, column: usize, direction: Direction },
+  /// A white cell that is not part of a run in that direction
+  UncoveredCell { row: usize, column: usize, direction: Direction },
+  /// The grid has no white cells to fill in, i.e. the file is empty or every cell is black
+  NoWhiteCells
+}
+
+impl fmt::Display for ValidationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ValidationError::EmptyRun { row, column, direction, sum } =>
+        write!(f, "row {}, column {}: {} clue {} has no cells", row + 1, column + 1, direction, sum),
+      ValidationError::RunTooLong { row, column, direction, sum, length } =>
+        write!(f, "row {}, column {}: {} clue {} has {} cells, at most 9 are allowed", row + 1, column + 1, direction, sum, length),
+      ValidationError::ImpossibleSum { row, column, direction, sum, length } =>
+        write!(f, "row {}, column {}: {} clue {} cannot be made from {} unique digits", row + 1, column + 1, direction, sum, length),
+      ValidationError::UncluedEdgeCell { row, column, direction } =>
+        write!(f, "row {}, column {}: white cell on the edge of the grid has no {} clue", row + 1, column + 1, direction),
+      ValidationError::UncoveredCell { row, column, direction } =>
+        write!(f, "row {}, column {}: white cell is not part of any {} run", row + 1, column + 1, direction),
+      ValidationError::NoWhiteCells =>
+        write!(f, "the grid has no white cells")
+    }
+  }
+}
+
+/// Parameters:
+/// - puzzle: The puzzle being checked, with every Parent already connected to its children
+///
+/// Description:
+/// - Checks every clue for a run that is empty, too long or has an impossible sum, then checks that every white cell
+///   is part of both a down and an across run, and that there is at least one white cell
+/// - Returns every problem found, in the order they appear on the grid
+pub fn validate(puzzle: &Puzzle) -> Vec<ValidationError> {
+  let mut errors = Vec::new();
+  let mut covered = vec![(false, false); puzzle.children.len()];
+
+  for (row, cells) in puzzle.grid.iter().enumerate() {
+    for (column, cell) in cells.iter().enumerate() {
+      for (direction, parent_id) in [(Direction::Down, cell.vert), (Direction::Across, cell.horz)] {
+        let Some(parent_id) = parent_id else {
+          continue;
+        };
+
+        let parent = &puzzle[parent_id];
+        let (sum, length) = (parent.sum, parent.children.len());
+
+        // mark the children of this run as covered in its direction
+        for &child_id in &parent.children {
+          if direction == Direction::Down { covered[child_id.0].0 = true; } else { covered[child_id.0].1 = true; }
+        }
+
+        if length == 0 {
+          errors.push(ValidationError::EmptyRun { row, column, direction, sum });
+        } else if length > 9 {
+          errors.push(ValidationError::RunTooLong { row, column, direction, sum, length });
+        } else if combinations(sum, length).is_empty() {
+          errors.push(ValidationError::ImpossibleSum { row, column, direction, sum, length });
+        }
+      }
+    }
+  }
+
+  for (row, cells) in puzzle.grid.iter().enumerate() {
+    for (column, cell) in cells.iter().enumerate() {
+      let Some(child_id) = cell.child else {
+        continue;
+      };
+
+      let (down, across) = covered[child_id.0];
+
+      // a cell on the top or left edge can never have a clue before it, so it gets its own error
+      for (direction, is_covered, on_edge) in [(Direction::Down, down, row == 0), (Direction::Across, across, column == 0)] {
+        if is_covered {
+          continue;
+        }
+
+        if on_edge {
+          errors.push(ValidationError::UncluedEdgeCell { row, column, direction });
+        } else {
+          errors.push(ValidationError::UncoveredCell { row, column, direction });
+        }
+      }
+    }
+  }
+
+  // a grid with no rows or only black and clue cells has nothing to solve
+  if puzzle.children.is_empty() {
+    errors.push(ValidationError::NoWhiteCells);
+  }
+
+  errors
+}
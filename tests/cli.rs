@@ -0,0 +1,338 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// The exit codes of the binary, as listed in the README
+const EXIT_SUCCESS: u8 = 0;
+const EXIT_ERROR: u8 = 1;
+const EXIT_PARSE_ERROR: u8 = 2;
+const EXIT_UNSOLVABLE: u8 = 3;
+const EXIT_MULTIPLE_SOLUTIONS: u8 = 4;
+
+/// Resolves a file in the puzzles folder relative to the crate root so the tests work from any directory
+fn puzzle_path(file_name: &str) -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).join("puzzles").join(file_name)
+}
+
+/// Writes a small puzzle to the temporary directory and returns its path
+fn write_puzzle(file_name: &str, contents: &str) -> PathBuf {
+  let puzzle_file = std::env::temp_dir().join(format!("kakuro_{}", file_name));
+  fs::write(&puzzle_file, contents).unwrap();
+  puzzle_file
+}
+
+/// Runs the binary and returns the exit code along with everything written to stdout
+fn run_cli(args: &[&str]) -> (u8, String) {
+  let output = Command::new(env!("CARGO_BIN_EXE_kakuro")).args(args).output().unwrap();
+  (output.status.code().unwrap() as u8, String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn test_cli_solve() {
+  let puzzle_file = puzzle_path("Kakuro_input.txt");
+  let (code, out) = run_cli(&["solve", puzzle_file.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.starts_with("solved\n1 2 4 1 2 4 5 3 1 2 3 9 7 8 9 "));
+}
+
+#[test]
+fn test_cli_solve_unsolvable_and_multiple_solutions() {
+  let unsolvable = write_puzzle("cli_unsolvable.txt", "-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n");
+  let multiple = write_puzzle("cli_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n");
+  let unique = write_puzzle("cli_unique.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n");
+
+  assert_eq!(run_cli(&["solve", unsolvable.to_str().unwrap()]), (EXIT_UNSOLVABLE, String::new()));
+  assert_eq!(run_cli(&["solve", multiple.to_str().unwrap()]), (EXIT_MULTIPLE_SOLUTIONS, String::new()));
+  assert_eq!(run_cli(&["solve", unique.to_str().unwrap()]), (EXIT_SUCCESS, "solved\n1 2 3 4\n".to_string()));
+}
+
+#[test]
+fn test_cli_validate_and_info() {
+  let puzzle_file = puzzle_path("Kakuro_input.txt");
+
+  assert_eq!(run_cli(&["validate", puzzle_file.to_str().unwrap()]), (EXIT_SUCCESS, "valid\n".to_string()));
+
+  let (code, out) = run_cli(&["info", puzzle_file.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.starts_with("size: 10x12\nruns: 48\ncells: 72\nrun lengths:\n  2: 20\n  3: 14\n  4: 8\n  5: 6\n"));
+  assert!(out.contains("  run 0: down 11 in 3 cells at row 1, column 3, 5 combinations\n"));
+}
+
+#[test]
+fn test_cli_errors() {
+  let missing_file = puzzle_path("missing.txt");
+
+  assert_eq!(run_cli(&["solve", missing_file.to_str().unwrap()]).0, EXIT_PARSE_ERROR);
+  assert_eq!(run_cli(&["solve"]).0, EXIT_ERROR);
+  assert_eq!(run_cli(&["unknown", "file.txt"]).0, EXIT_ERROR);
+}
+
+#[test]
+fn test_cli_show_and_solve_grid() {
+  let puzzle_file = puzzle_path("Kakuro_input.txt");
+  let (code, out) = run_cli(&["solve", puzzle_file.to_str().unwrap(), "--grid"]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.starts_with("solved\n+-----+"));
+  assert!(out.contains("|#####|14\\7 |  1  |  2  |  4  |  \\7 |  1  |  2  |  4  |15\\  |\n"));
+
+  let (code, out) = run_cli(&["show", puzzle_file.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.contains("|#####|14\\7 |     |     |     |  \\7 |     |     |     |15\\  |\n"));
+
+  assert_eq!(run_cli(&["show", puzzle_file.to_str().unwrap(), "--grid"]).0, EXIT_ERROR);
+}
+
+#[test]
+fn test_cli_parse_error() {
+  let puzzle_file = write_puzzle("cli_parse_error.txt", "-\\\\ 4\\- 6\\-\n\\xq -\\3\n");
+
+  assert_eq!(run_cli(&["validate", puzzle_file.to_str().unwrap()]).0, EXIT_PARSE_ERROR);
+
+  // a puzzle with nothing to fill in is rejected rather than solved
+  for (file_name, contents) in [("cli_empty.txt", ""), ("cli_all_black.txt", "---\n---\n")] {
+    let puzzle_file = write_puzzle(file_name, contents);
+
+    assert_eq!(run_cli(&["validate", puzzle_file.to_str().unwrap()]).0, EXIT_PARSE_ERROR);
+    assert_eq!(run_cli(&["solve", puzzle_file.to_str().unwrap()]).0, EXIT_PARSE_ERROR);
+  }
+}
+
+#[test]
+fn test_cli_count() {
+  let multiple = write_puzzle("cli_count_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n");
+  let unique = write_puzzle("cli_count_unique.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n");
+
+  assert_eq!(run_cli(&["count", multiple.to_str().unwrap()]), (EXIT_MULTIPLE_SOLUTIONS, "\
+solutions: 2 or more
+solution 1:
+1 3 4 2
+solution 2:
+3 1 2 4
+first differences:
+  row 2, column 2: 1 or 3
+  row 2, column 3: 3 or 1
+  row 3, column 2: 4 or 2
+  row 3, column 3: 2 or 4
+".to_string()));
+
+  assert_eq!(run_cli(&["count", unique.to_str().unwrap(), "--limit", "10"]), (EXIT_SUCCESS, "solutions: 1\nsolution 1:\n1 2 3 4\n".to_string()));
+  assert_eq!(run_cli(&["count", unique.to_str().unwrap(), "--limit"]).0, EXIT_ERROR);
+  assert_eq!(run_cli(&["count", unique.to_str().unwrap(), "--limit", "many"]).0, EXIT_ERROR);
+}
+
+#[test]
+fn test_cli_steps() {
+  let unique = write_puzzle("cli_steps_unique.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n");
+  let multiple = write_puzzle("cli_steps_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n");
+
+  let (code, out) = run_cli(&["steps", unique.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.starts_with("1. naked single: row 2, column 2 can only be {1}, "));
+  assert!(out.ends_with("\nsolved in 6 steps\n"));
+
+  let (code, out) = run_cli(&["steps", multiple.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.ends_with("\nstuck after 2 steps, no technique can make any more progress\n"));
+}
+
+#[test]
+fn test_cli_rate() {
+  let puzzle_folder = puzzle_path("");
+  let (code, out) = run_cli(&["rate", puzzle_folder.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert_eq!(out.lines().count(), 5);
+  assert!(out.starts_with("Kakuro_input.txt: hard (score 7100, 100 steps, hardest technique combination elimination, 0 guesses)\n"));
+  assert!(out.contains("\nKakuro_input2.txt: easy (score 4140, 140 steps, hardest technique digit intersection, 0 guesses)\n"));
+
+  let unsolvable = write_puzzle("cli_rate_unsolvable.txt", "-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n");
+
+  assert_eq!(run_cli(&["rate", unsolvable.to_str().unwrap()]), (EXIT_SUCCESS, "kakuro_cli_rate_unsolvable.txt: no solution\n".to_string()));
+}
+
+#[test]
+fn test_cli_hint() {
+  let puzzle_file = puzzle_path("Kakuro_input.txt");
+  let multiple = write_puzzle("cli_hint_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n");
+
+  assert_eq!(run_cli(&["hint", puzzle_file.to_str().unwrap()]), (EXIT_SUCCESS, "naked single: row 2, column 9 has {4} as its only candidate, so it must be 4\n".to_string()));
+  assert_eq!(run_cli(&["hint", multiple.to_str().unwrap()]), (EXIT_SUCCESS, "combination elimination: row clue 6 over 2 cells at row 3, column 1 cannot be {1,5}\n".to_string()));
+}
+
+#[test]
+fn test_cli_generate() {
+  let (code, out) = run_cli(&["generate", "--width", "7", "--height", "6", "--seed", "42", "--symmetric"]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert_eq!(out.lines().count(), 6);
+  assert_eq!(run_cli(&["generate", "--width", "7", "--height", "6", "--seed", "42", "--symmetric"]).1, out);
+
+  let puzzle_file = write_puzzle("cli_generated.txt", &out);
+  let (code, solved) = run_cli(&["solve", puzzle_file.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(solved.starts_with("solved\n"));
+
+  assert_eq!(run_cli(&["generate", "--width", "50"]).0, EXIT_ERROR);
+  assert_eq!(run_cli(&["generate", "--seed", "many"]).0, EXIT_ERROR);
+  assert_eq!(run_cli(&["generate", "file.txt"]).0, EXIT_ERROR);
+}
+
+#[test]
+fn test_cli_grid_format() {
+  let puzzle_file = write_puzzle("cli_grid_format.txt", "##   4\\  6\\\n\\3   .    .\n\\7   .    4\n");
+
+  assert_eq!(run_cli(&["solve", puzzle_file.to_str().unwrap()]), (EXIT_SUCCESS, "solved\n1 2 3 4\n".to_string()));
+}
+
+#[test]
+fn test_cli_svg() {
+  let puzzle_file = write_puzzle("cli_svg.txt", "##   4\\  6\\\n\\3   .    .\n\\7   .    .\n");
+  let (code, out) = run_cli(&["svg", puzzle_file.to_str().unwrap(), "--cell-size", "20", "--solution"]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"60\" height=\"60\""));
+  assert!(out.contains(">4</text>\n  </g>\n</svg>\n"));
+
+  let unsolvable = write_puzzle("cli_svg_unsolvable.txt", "-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n");
+  let multiple = write_puzzle("cli_svg_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n");
+
+  assert_eq!(run_cli(&["svg", unsolvable.to_str().unwrap(), "--solution"]).0, EXIT_UNSOLVABLE);
+  assert_eq!(run_cli(&["svg", multiple.to_str().unwrap(), "--solution"]), (EXIT_MULTIPLE_SOLUTIONS, String::new()));
+  assert_eq!(run_cli(&["svg", multiple.to_str().unwrap()]).0, EXIT_SUCCESS);
+  assert_eq!(run_cli(&["svg", unsolvable.to_str().unwrap()]).0, EXIT_SUCCESS);
+  assert_eq!(run_cli(&["svg", puzzle_file.to_str().unwrap(), "--cell-size", "0"]).0, EXIT_ERROR);
+}
+
+#[test]
+fn test_cli_export_html() {
+  let puzzle_file = puzzle_path("Kakuro_input.txt");
+  let (code, out) = run_cli(&["export-html", puzzle_file.to_str().unwrap()]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.starts_with("<!DOCTYPE html>\n"));
+  assert!(out.contains("\"solution\": [1, 2, 4, 1, 2, 4, 5, 3, 1, 2, 3, 9, 7, 8, 9, "));
+
+  let unsolvable = write_puzzle("cli_html_unsolvable.txt", "-\\\\ 4\\- 4\\-\n\\xx -\\3\n\\xx -\\3\n");
+  let multiple = write_puzzle("cli_html_multiple.txt", "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n");
+
+  assert_eq!(run_cli(&["export-html", unsolvable.to_str().unwrap()]), (EXIT_UNSOLVABLE, String::new()));
+  assert_eq!(run_cli(&["export-html", multiple.to_str().unwrap()]), (EXIT_MULTIPLE_SOLUTIONS, String::new()));
+}
+
+#[test]
+fn test_cli_play() {
+  let puzzle_file = write_puzzle("cli_play.txt", "##   4\\  6\\\n\\3   .    .\n\\7   .    4\n");
+
+  // the keys are read from stdin when it is not a terminal, the last screen is drawn after the keys run out
+  let play = |keys: &str| {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_kakuro")).args(["play", puzzle_file.to_str().unwrap()])
+      .stdin(Stdio::piped()).stdout(Stdio::piped()).spawn().unwrap();
+
+    child.stdin.take().unwrap().write_all(keys.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    let out = String::from_utf8(output.stdout).unwrap();
+    let last_screen = out.rsplit("\x1b[2J").next().unwrap().to_string();
+
+    (output.status.code().unwrap() as u8, last_screen)
+  };
+
+  // moving down from the top right lands on the given cell, which cannot be changed
+  let (code, screen) = play("1\x1b[C2\x1b[B3");
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(screen.contains("|  \\3 |  1  |  2  |\r\n"));
+  assert!(screen.contains("|  \\7 |     |\x1b[7m\x1b[1m  4  \x1b[0m|\r\n"));
+  assert!(screen.contains("typing values\r\nthe cell is given\r\n"));
+
+  // 7 breaks its row sum until it is undone
+  let (_, screen) = play("7\x1b[B2u");
+  assert!(screen.contains("|  \\3 |\x1b[31m  7  \x1b[0m|     |\r\n"));
+  assert!(screen.contains("|  \\7 |\x1b[7m     \x1b[0m|"));
+
+  let (_, screen) = play("p13\x1b[A\x1b[B");
+  assert!(screen.contains("|     |1   3|     |\r\n|  \\3 |     |     |\r\n"));
+  assert!(screen.contains("typing pencil marks\r\n"));
+
+  let (_, screen) = play("vlvjhv");
+  assert!(screen.contains("typing values\r\nsolved, press q to quit\r\n"));
+
+  // the hint moves to the cell it is about and quitting ignores the keys after it
+  let (_, screen) = play("l?q1");
+  assert!(screen.contains("|  \\3 |\x1b[7m     \x1b[0m|     |\r\n"));
+  assert!(screen.contains("typing values\r\nnaked single: "));
+
+  // a lone ESC is a key of its own, so the q after it still quits before the 1 is typed
+  let (code, screen) = play("\x1bq1");
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(screen.contains("|  \\3 |\x1b[7m     \x1b[0m|     |\r\n"));
+}
+
+#[test]
+fn test_cli_solve_trace() {
+  let puzzle_file = write_puzzle("cli_trace.txt", "-\\\\ 4\\- 6\\-\n\\xx -\\3\n\\xx -\\7\n");
+  let (code, out) = run_cli(&["solve", puzzle_file.to_str().unwrap(), "--trace"]);
+
+  assert_eq!(code, EXIT_SUCCESS);
+  assert_eq!(out, "\
+1. combinations: row clue 7 over 2 cells at row 3, column 1 cannot be {1,6} or {2,5}
+2. possible values: row 2, column 3 {1,2} to {2}; row 3, column 2 {1,3} to {3}; row 3, column 3 {1,2,4,5} to {4}
+3. combinations: column clue 6 over 2 cells at row 1, column 3 cannot be {1,5}
+4. solved
+solved
+1 2 3 4
+");
+
+  // with --grid the grid is drawn after every step
+  let (_, out) = run_cli(&["solve", puzzle_file.to_str().unwrap(), "--trace", "--grid"]);
+  assert_eq!(out.matches("+-----+-----+-----+\n|#####|").count(), 5);
+
+  // the guesses of a harder puzzle are indented under them
+  let (code, out) = run_cli(&["solve", puzzle_path("Kakuro_input4.txt").to_str().unwrap(), "--trace"]);
+  assert_eq!(code, EXIT_SUCCESS);
+  assert!(out.contains(". backtrack: 1 in row 3, column 10 leads to a contradiction\n"));
+  assert!(out.lines().any(|line| line.starts_with("  ") && line.contains(". contradiction: ")));
+}
+
+#[test]
+fn test_cli_solve_all() {
+  let folder = std::env::temp_dir().join("kakuro_cli_solve_all");
+  let _ = fs::remove_dir_all(&folder);
+  fs::create_dir_all(&folder).unwrap();
+  fs::copy(puzzle_path("Kakuro_input.txt"), folder.join("a.txt")).unwrap();
+  fs::write(folder.join("b.txt"), "-\\\\ 5\\- 5\\-\n\\xx -\\4\n\\xx -\\6\n").unwrap();
+  fs::write(folder.join("c.txt"), "-\\\\ 4\\-\n\\xx -\\3\n").unwrap();
+
+  let (csv_file, json_file) = (std::env::temp_dir().join("kakuro_cli_solve_all.csv"), std::env::temp_dir().join("kakuro_cli_solve_all.json"));
+  let (code, out) = run_cli(&["solve-all", folder.to_str().unwrap(), "--threads", "2", "--csv", csv_file.to_str().unwrap(), "--json", json_file.to_str().unwrap()]);
+  let lines: Vec<&str> = out.lines().collect();
+
+  assert_eq!(code, EXIT_PARSE_ERROR);
+  assert_eq!(lines.len(), 5);
+  assert!(lines[0].starts_with("file   size   runs  cells  status    time "));
+  assert!(lines[0].ends_with("  difficulty"));
+  assert!(lines[1].starts_with("a.txt  10x12  48    72     solved    "));
+  assert!(lines[1].ends_with(" ms  hard (7100)"));
+  assert!(lines[2].starts_with("b.txt  3x3    4     4      multiple  "));
+  assert!(lines[3].starts_with("c.txt  -      -     -      error     "));
+  assert!(lines.last().unwrap().starts_with("3 files: 1 solved, 0 unsolved, 1 multiple, 1 errors in "));
+
+  let csv = fs::read_to_string(&csv_file).unwrap();
+  assert!(csv.starts_with("file,width,height,runs,cells,status,milliseconds,grade,score,error\na.txt,10,12,48,72,solved,"));
+  assert!(csv.lines().nth(3).unwrap().starts_with("c.txt,,,,,error,"));
+
+  let json = fs::read_to_string(&json_file).unwrap();
+  assert!(json.contains("{\"file\": \"b.txt\", \"width\": 3, \"height\": 3, \"runs\": 4, \"cells\": 4, \"status\": \"multiple\", \"milliseconds\": "));
+
+  assert_eq!(run_cli(&["solve-all", folder.to_str().unwrap(), "--threads", "many"]).0, EXIT_ERROR);
+
+  // a path that does not exist fails before anything is solved or printed
+  let missing = folder.join("missing");
+  assert_eq!(run_cli(&["solve-all", missing.to_str().unwrap(), "--csv", csv_file.to_str().unwrap()]), (EXIT_PARSE_ERROR, String::new()));
+}